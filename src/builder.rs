@@ -0,0 +1,479 @@
+use crate::{
+    format::{unescape_literal, Format, FormatToken},
+    scheme::{Cal, CalSem, Scheme, Sem},
+    specifier::{
+        is_valid_metadata, CalSemLevel, Level, PreReleaseLabels, PreReleaseValue, SemLevel,
+        SpecValue, SpecValueResult, Specifier, CAL_EPOCH,
+    },
+    version::{Version, VersionError, VersionToken},
+};
+use alloc::{
+    borrow::Cow,
+    borrow::ToOwned,
+    string::{String, ToString},
+    vec::Vec,
+};
+use chrono::NaiveDateTime;
+use core::fmt::Display;
+
+/// Returns the value of `spec` in `date`, or `None` if it is not a calendar specifier.
+pub(crate) type DateValueFn<S> =
+    fn(&<S as Scheme>::Specifier, NaiveDateTime) -> Option<SpecValueResult>;
+
+/// A builder of a [`Version`] from values, instead of from a version string. This is useful to
+/// make the first version of a project.
+///
+/// Create one with the `builder` method of a [`Format`], like
+/// [`Format::builder`](struct.Format.html#method.builder). Values that are not given are zero,
+/// and the values of calendar specifiers come from the date given to that method.
+///
+/// # Example
+///
+/// ```
+/// use nextver::prelude::*;
+///
+/// let format = Sem::new_format("<MAJOR>.<MINOR>.<PATCH><PRE>")?;
+/// let version = format
+///     .builder()
+///     .value(SemLevel::Minor, 4)
+///     .pre_release("beta", 1)
+///     .build()?;
+/// assert_eq!("0.4.0-beta.1", version.to_string());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct VersionBuilder<'f, 'fs, S: Scheme> {
+    format: &'f Format<'fs, S>,
+    values: Vec<(&'static S::Specifier, SpecValue)>,
+    build_number: Option<SpecValue>,
+    pre_release_label: Option<String>,
+    pre_release_number: Option<SpecValue>,
+    metadata: Option<String>,
+    date: Option<NaiveDateTime>,
+    date_value: DateValueFn<S>,
+    has_level_without_value: bool,
+}
+
+impl<'f, 'fs, S: Scheme> VersionBuilder<'f, 'fs, S> {
+    pub(crate) fn new(
+        format: &'f Format<'fs, S>,
+        date: Option<NaiveDateTime>,
+        date_value: DateValueFn<S>,
+    ) -> Self {
+        Self {
+            format,
+            values: Vec::new(),
+            build_number: None,
+            pre_release_label: None,
+            pre_release_number: None,
+            metadata: None,
+            date,
+            date_value,
+            has_level_without_value: false,
+        }
+    }
+
+    /// Sets the value of `spec`, where a pre-release specifier sets the pre-release number.
+    pub(crate) fn set(mut self, spec: &'static S::Specifier, value: SpecValue) -> Self {
+        if spec.is_pre_release() {
+            self.pre_release_number = Some(value);
+        } else if let Some(entry) = self.values.iter_mut().find(|(this, _)| *this == spec) {
+            entry.1 = value;
+        } else {
+            self.values.push((spec, value));
+        }
+        self
+    }
+
+    /// Sets the value of the specifier of a level, or, if the level has no value (as when it
+    /// promotes or finalizes a pre-release), makes [`Self::build`] return an error.
+    fn set_level(mut self, level: &impl Level<Specifier = S::Specifier>, value: SpecValue) -> Self {
+        if level.has_value() {
+            self.set(level.spec(), value)
+        } else {
+            self.has_level_without_value = true;
+            self
+        }
+    }
+
+    /// Sets the value of the build number, `<BUILD>`.
+    #[must_use]
+    pub fn build_number(mut self, value: SpecValue) -> Self {
+        self.build_number = Some(value);
+        self
+    }
+
+    /// Sets the pre-release label and number, making the version a pre-release. The label must be
+    /// one of those of the `<PRE>` specifier in the format.
+    #[must_use]
+    pub fn pre_release(mut self, label: &str, number: SpecValue) -> Self {
+        self.pre_release_label = Some(label.to_owned());
+        self.pre_release_number = Some(number);
+        self
+    }
+
+    /// Sets the build metadata, `<META>`.
+    #[must_use]
+    pub fn metadata(mut self, metadata: &str) -> Self {
+        self.metadata = Some(metadata.to_owned());
+        self
+    }
+
+    /// Returns the built version.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result::Err`] of...
+    ///
+    /// - [`VersionError::LevelHasNoValue`] if a value was given for a level that has no value.
+    /// - [`VersionError::SpecifierNotInFormat`] if a value was given for a specifier that is not in
+    ///   the format.
+    /// - [`VersionError::ZeroValue`] if a value is zero for a specifier that cannot be zero.
+    /// - [`VersionError::ValueOverflowsWidth`] if a value has more digits than its specifier's
+    ///   [fixed width](crate#fixed-widths).
+    /// - [`VersionError::NegativeYearValue`] if the year of the date would be negative. (Year
+    ///   specifiers have lower bounds. See the [table](crate#table) for more information.)
+    /// - [`VersionError::UnknownPreReleaseLabel`] if the pre-release label is not one of those of
+    ///   the format.
+    /// - [`VersionError::InvalidMetadata`] if the build metadata is not valid.
+    pub fn build(&self) -> Result<Version<'static, S>, VersionError> {
+        if self.has_level_without_value {
+            return Err(VersionError::LevelHasNoValue);
+        }
+        let mut tokens = Vec::with_capacity(S::MAX_TOKENS);
+        self.push_tokens(&self.format.tokens, &mut tokens)?;
+        self.check_in_format(&tokens)?;
+
+        let mut version = Version::new(tokens);
+        version.update_optional_presence();
+        Ok(version)
+    }
+
+    /// Pushes the version tokens for `format_tokens` onto `tokens`.
+    fn push_tokens(
+        &self,
+        format_tokens: &[FormatToken<'_, S>],
+        tokens: &mut Vec<VersionToken<'static, S>>,
+    ) -> Result<(), VersionError> {
+        for format_token in format_tokens {
+            let token = match format_token {
+                FormatToken::Specifier(spec) if spec.is_metadata() => {
+                    if let Some(metadata) = &self.metadata {
+                        if !is_valid_metadata(metadata.as_bytes()) {
+                            return Err(VersionError::InvalidMetadata {
+                                metadata: metadata.clone(),
+                            });
+                        }
+                    }
+                    VersionToken::Metadata {
+                        value: self.metadata.clone(),
+                        spec: *spec,
+                    }
+                }
+                FormatToken::Specifier(spec) => VersionToken::Value {
+                    value: self.value_of(spec)?,
+                    spec: *spec,
+                    width: None,
+                },
+                FormatToken::FixedWidth { spec, width } => {
+                    let value = self.value_of(spec)?;
+                    if value.to_string().len() > *width {
+                        return Err(VersionError::ValueOverflowsWidth {
+                            spec: spec.to_string(),
+                            value,
+                            width: *width,
+                        });
+                    }
+                    VersionToken::Value {
+                        value,
+                        spec: *spec,
+                        width: Some(*width),
+                    }
+                }
+                FormatToken::PreRelease { spec, labels } => VersionToken::PreRelease {
+                    value: self.pre_release_value(labels)?,
+                    spec: *spec,
+                    labels: labels.clone(),
+                },
+                FormatToken::OptionalStart => VersionToken::OptionalStart { present: false },
+                FormatToken::OptionalEnd => VersionToken::OptionalEnd,
+                FormatToken::Literal(text) => {
+                    VersionToken::Literal(Cow::Owned(unescape_literal(text).into_bytes()))
+                }
+            };
+            tokens.push(token);
+        }
+        Ok(())
+    }
+
+    /// Returns the value of `spec`: the given one, the one from the date for calendar specifiers,
+    /// or zero.
+    fn value_of(&self, spec: &'static S::Specifier) -> Result<SpecValue, VersionError> {
+        let given = if spec.is_build() {
+            self.build_number
+        } else {
+            self.values
+                .iter()
+                .find(|(this, _)| *this == spec)
+                .map(|(_, value)| *value)
+        };
+        let from_date = self.date.and_then(|date| (self.date_value)(spec, date));
+
+        let value = match (given, from_date) {
+            (Some(value), _) => value,
+            (None, Some(result)) => result?,
+            (None, None) => 0,
+        };
+
+        if value == 0 && !spec.can_be_zero() {
+            return Err(VersionError::ZeroValue {
+                spec: spec.to_string(),
+            });
+        }
+        Ok(value)
+    }
+
+    fn pre_release_value(
+        &self,
+        labels: &PreReleaseLabels,
+    ) -> Result<Option<PreReleaseValue>, VersionError> {
+        if self.pre_release_label.is_none() && self.pre_release_number.is_none() {
+            return Ok(None);
+        }
+
+        let label = match &self.pre_release_label {
+            Some(label) => {
+                labels
+                    .position(label)
+                    .ok_or_else(|| VersionError::UnknownPreReleaseLabel {
+                        label: label.clone(),
+                    })?
+            }
+            // the first label, like a reset pre-release
+            None => 0,
+        };
+        Ok(Some(PreReleaseValue {
+            label,
+            number: self.pre_release_number.unwrap_or(0),
+        }))
+    }
+
+    /// Returns an error if something was given that is not in `tokens`.
+    fn check_in_format(&self, tokens: &[VersionToken<'_, S>]) -> Result<(), VersionError> {
+        let has_value = |f: &dyn Fn(&S::Specifier) -> bool| {
+            tokens
+                .iter()
+                .any(|token| matches!(token, VersionToken::Value { spec, .. } if f(spec)))
+        };
+
+        for (spec, _) in &self.values {
+            if !has_value(&|this| this == *spec) {
+                return Err(not_in_format(spec));
+            }
+        }
+        if self.build_number.is_some() && !has_value(&Specifier::is_build) {
+            return Err(not_in_format(&"<BUILD>"));
+        }
+        if (self.pre_release_label.is_some() || self.pre_release_number.is_some())
+            && !tokens
+                .iter()
+                .any(|token| matches!(token, VersionToken::PreRelease { .. }))
+        {
+            return Err(not_in_format(&"<PRE>"));
+        }
+        if self.metadata.is_some()
+            && !tokens
+                .iter()
+                .any(|token| matches!(token, VersionToken::Metadata { .. }))
+        {
+            return Err(not_in_format(&"<META>"));
+        }
+        Ok(())
+    }
+}
+
+fn not_in_format(spec: &impl Display) -> VersionError {
+    VersionError::SpecifierNotInFormat {
+        spec: spec.to_string(),
+    }
+}
+
+impl VersionBuilder<'_, '_, Sem> {
+    /// Sets the value of the specifier of `level`. [`SemLevel::PreRelease`] sets the pre-release
+    /// number, with the first label unless another is given with [`Self::pre_release`]. The levels
+    /// that have no value, [`SemLevel::PreReleaseLabel`] and [`SemLevel::FinalRelease`], make
+    /// [`Self::build`] return [`VersionError::LevelHasNoValue`].
+    #[must_use]
+    pub fn value(self, level: SemLevel, value: SpecValue) -> Self {
+        self.set_level(&level, value)
+    }
+}
+
+impl VersionBuilder<'_, '_, CalSem> {
+    /// Sets the value of the specifier of `level`. [`CalSemLevel::PreRelease`] sets the pre-release
+    /// number, with the first label unless another is given with [`Self::pre_release`]. The levels
+    /// that have no value, [`CalSemLevel::PreReleaseLabel`] and [`CalSemLevel::FinalRelease`], make
+    /// [`Self::build`] return [`VersionError::LevelHasNoValue`].
+    #[must_use]
+    pub fn value(self, level: CalSemLevel, value: SpecValue) -> Self {
+        self.set_level(&level, value)
+    }
+}
+
+impl VersionBuilder<'_, '_, Cal> {
+    /// Sets the value of the epoch, `<EPOCH>`.
+    #[must_use]
+    pub fn epoch(self, value: SpecValue) -> Self {
+        self.set(&CAL_EPOCH, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Date, DateTime};
+
+    #[test]
+    fn test_sem_build() {
+        let format = Sem::new_format("<MAJOR>.<MINOR>[.<PATCH>]<PRE><META>").unwrap();
+        let build = |builder: VersionBuilder<'_, '_, Sem>| builder.build().map(|v| v.to_string());
+
+        assert_eq!(Ok("0.0".to_string()), build(format.builder()));
+        assert_eq!(
+            Ok("1.2.3-rc.4+ci.5".to_string()),
+            build(
+                format
+                    .builder()
+                    .value(SemLevel::Major, 1)
+                    .value(SemLevel::Minor, 2)
+                    .value(SemLevel::Patch, 3)
+                    .pre_release("rc", 4)
+                    .metadata("ci.5")
+            )
+        );
+        // a pre-release number alone has the first label
+        assert_eq!(
+            Ok("0.0-alpha.2".to_string()),
+            build(format.builder().value(SemLevel::PreRelease, 2))
+        );
+        // only the pre-release level sets the pre-release number
+        for level in [SemLevel::PreReleaseLabel, SemLevel::FinalRelease] {
+            assert_eq!(
+                Err(VersionError::LevelHasNoValue),
+                build(format.builder().value(level, 3))
+            );
+        }
+        assert_eq!(
+            Err(VersionError::SpecifierNotInFormat {
+                spec: "<REVISION>".to_string()
+            }),
+            build(format.builder().value(SemLevel::Revision, 1))
+        );
+        assert_eq!(
+            Err(VersionError::SpecifierNotInFormat {
+                spec: "<BUILD>".to_string()
+            }),
+            build(format.builder().build_number(1))
+        );
+        assert_eq!(
+            Err(VersionError::UnknownPreReleaseLabel {
+                label: "gamma".to_string()
+            }),
+            build(format.builder().pre_release("gamma", 0))
+        );
+        assert_eq!(
+            Err(VersionError::InvalidMetadata {
+                metadata: "ci_5".to_string()
+            }),
+            build(format.builder().metadata("ci_5"))
+        );
+
+        // built versions are the same as parsed ones
+        let version = format.builder().value(SemLevel::Minor, 2).build().unwrap();
+        assert_eq!(format.new_version("0.2").unwrap(), version);
+        assert_eq!(
+            "0.3-alpha.0",
+            version
+                .next_pre_release(SemLevel::Minor)
+                .unwrap()
+                .to_string()
+        );
+
+        let format = Sem::new_format("<MAJOR>.<MINOR:2>").unwrap();
+        assert_eq!(
+            Err(VersionError::ValueOverflowsWidth {
+                spec: "<MINOR>".to_string(),
+                value: 100,
+                width: 2
+            }),
+            build(format.builder().value(SemLevel::Minor, 100))
+        );
+    }
+
+    #[test]
+    fn test_cal_build() {
+        let date = Date::explicit(2024, 10, 18).unwrap();
+
+        let format = Cal::new_format("[<EPOCH>!]<YYYY>.<0M>.<0D>+<BUILD>").unwrap();
+        let build = |builder: VersionBuilder<'_, '_, Cal>| builder.build().map(|v| v.to_string());
+        assert_eq!(Ok("2024.10.18+0".to_string()), build(format.builder(date)));
+        assert_eq!(
+            Ok("2!2024.10.18+7".to_string()),
+            build(format.builder(date).epoch(2).build_number(7))
+        );
+
+        let format = CalSem::new_format("<YYYY>.<MM>.<PATCH><PRE>").unwrap();
+        let build =
+            |builder: VersionBuilder<'_, '_, CalSem>| builder.build().map(|v| v.to_string());
+        assert_eq!(
+            Ok("2024.10.0-alpha.3".to_string()),
+            build(format.builder(date).value(CalSemLevel::PreRelease, 3))
+        );
+        for level in [CalSemLevel::PreReleaseLabel, CalSemLevel::FinalRelease] {
+            assert_eq!(
+                Err(VersionError::LevelHasNoValue),
+                build(format.builder(date).value(level, 3))
+            );
+        }
+
+        let format = Cal::new_format("<YY>.<MM>").unwrap();
+        let build = |builder: VersionBuilder<'_, '_, Cal>| builder.build().map(|v| v.to_string());
+        let date = DateTime::explicit(1999, 1, 1, 0, 0).unwrap();
+        assert_eq!(
+            Err(VersionError::NegativeYearValue { year: 1999 }),
+            build(format.builder(date))
+        );
+    }
+
+    #[test]
+    fn test_initial_version() {
+        let date = Date::explicit(2024, 10, 18).unwrap();
+        let sem = |format_str| {
+            Sem::new_format(format_str)
+                .unwrap()
+                .initial_version()
+                .unwrap()
+                .to_string()
+        };
+
+        assert_eq!("0.1.0", sem("<MAJOR>.<MINOR>.<PATCH>"));
+        assert_eq!("0.1", sem("<MAJOR>[.<MINOR>]"));
+        assert_eq!("1", sem("<MAJOR>"));
+        assert_eq!(
+            "2024.10.0",
+            CalSem::new_format("<YYYY>.<MM>.<PATCH>")
+                .unwrap()
+                .initial_version(date)
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            "24.41",
+            Cal::new_format("<YY>.<WW>")
+                .unwrap()
+                .initial_version(date)
+                .unwrap()
+                .to_string()
+        );
+    }
+}
@@ -0,0 +1,754 @@
+use crate::{
+    builder::VersionBuilder,
+    format::{Format, FormatToken},
+    scheme::{Cal, CalSem, Scheme, Sem},
+    specifier::{CalSemSpecifier, CalSpecifier, SemLevel, SpecValue, Specifier},
+    version::{DateTime, Version, VersionError, VersionToken},
+};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use chrono::{NaiveDate, NaiveTime};
+
+/// An error that occurred while converting a version to another format.
+#[allow(clippy::module_name_repetitions)]
+#[non_exhaustive]
+#[derive(thiserror::Error, Debug, PartialEq)]
+pub enum ConvertError {
+    /// The value of a specifier in the new format cannot be determined from the version, such as a
+    /// day from a version with only a week.
+    #[error("value of `{spec}` should be determinable from the version being converted")]
+    MissingValue {
+        /// The specifier
+        spec: String,
+    },
+
+    /// A non-zero value of the version would be lost, because its specifier is not in the new
+    /// format. Only calendar values, which are found from the same date, can be dropped.
+    #[error("value `{value}` of `{spec}` should not be dropped, use a format with `{spec}`")]
+    DroppedValue {
+        /// The specifier
+        spec: String,
+        /// The value
+        value: SpecValue,
+    },
+
+    /// The converted version could not be made. See [`VersionError`] for more details.
+    #[error(transparent)]
+    Version(#[from] VersionError),
+}
+
+/// An error that occurred while migrating a version to another scheme.
+#[allow(clippy::module_name_repetitions)]
+#[non_exhaustive]
+#[derive(thiserror::Error, Debug, PartialEq)]
+pub enum MigrateError {
+    /// The migrated version would not be greater than the current one, and the new format has no
+    /// `<EPOCH>` specifier that could be incremented to make it so.
+    #[error(
+        "migrated version `{next}` should be greater than `{current}`, use a format with `<EPOCH>`"
+    )]
+    NotGreater {
+        /// The current version
+        current: String,
+        /// The migrated version
+        next: String,
+    },
+
+    /// The migrated version would not be greater than the current one, and its epoch cannot be
+    /// incremented to make it so, because it is already [`SpecValue::MAX`].
+    #[error("epoch `{epoch}` should be less than {max} to increment it", max = SpecValue::MAX)]
+    EpochOverflow {
+        /// The epoch value
+        epoch: SpecValue,
+    },
+
+    /// The migrated version could not be made. See [`VersionError`] for more details.
+    #[error(transparent)]
+    Version(#[from] VersionError),
+}
+
+/// Returns the specifiers of `tokens`, including those in optional segments.
+fn specs<S: Scheme>(tokens: &[FormatToken<'_, S>]) -> Vec<&'static S::Specifier> {
+    tokens
+        .iter()
+        .filter_map(|token| match token {
+            FormatToken::Specifier(spec)
+            | FormatToken::FixedWidth { spec, .. }
+            | FormatToken::PreRelease { spec, .. } => Some(*spec),
+            FormatToken::OptionalStart | FormatToken::OptionalEnd | FormatToken::Literal(_) => None,
+        })
+        .collect()
+}
+
+impl<S: Scheme> Version<'_, S> {
+    /// Converts this version to `format`, where `value_of` gives the value of a specifier that is
+    /// not in this version, or `None` for it to be zero, and `can_drop` is true for specifiers
+    /// whose values may be dropped when not in `format`.
+    fn convert_with<F, D>(
+        &self,
+        format: &Format<'_, S>,
+        value_of: F,
+        can_drop: D,
+    ) -> Result<Version<'static, S>, ConvertError>
+    where
+        F: Fn(&'static S::Specifier) -> Result<Option<SpecValue>, ConvertError>,
+        D: Fn(&S::Specifier) -> bool,
+    {
+        let format_specs = specs(&format.tokens);
+        for token in &self.tokens {
+            if let VersionToken::Value { value, spec, .. } = token {
+                if *value != 0 && !format_specs.contains(spec) && !can_drop(spec) {
+                    return Err(ConvertError::DroppedValue {
+                        spec: spec.to_string(),
+                        value: *value,
+                    });
+                }
+            }
+        }
+
+        let mut builder = VersionBuilder::new(format, None, |_, _| None);
+        for spec in format_specs {
+            if spec.is_metadata() {
+                if let Some(metadata) = self.metadata() {
+                    builder = builder.metadata(metadata);
+                }
+                continue;
+            }
+            if spec.is_pre_release() {
+                continue;
+            }
+
+            let value = match self.find_value(|this, value| (this == spec).then_some(value)) {
+                Some(value) => value,
+                None => match value_of(spec)? {
+                    Some(value) => value,
+                    None => continue,
+                },
+            };
+            builder = if spec.is_build() {
+                builder.build_number(value)
+            } else {
+                builder.set(spec, value)
+            };
+        }
+        // a pre-release can't be dropped, so this errors if it is not in the format
+        if let Some((label, number)) = self.pre_release() {
+            builder = builder.pre_release(label, number);
+        }
+        Ok(builder.build()?)
+    }
+}
+
+/// Returns the epoch of `version` and its other values in order, except build numbers. Versions of
+/// different schemes are ordered by comparing these, as tools that sort versions by their numbers
+/// would.
+fn numeric_key<S: Scheme>(version: &Version<'_, S>) -> (SpecValue, Vec<SpecValue>) {
+    let epoch = version
+        .find_value(|spec, value| spec.is_epoch().then_some(value))
+        .unwrap_or(0);
+    let values = version
+        .tokens
+        .iter()
+        .filter_map(|token| match token {
+            VersionToken::Value { value, spec, .. } if !spec.is_epoch() && !spec.is_build() => {
+                Some(*value)
+            }
+            _ => None,
+        })
+        .collect();
+    (epoch, values)
+}
+
+/// Migrates `current` to `format` of another scheme, where `builder` makes a builder of the first
+/// version in `format`. The epoch and build number of `current` are kept, and if the migrated
+/// version would not be greater than `current`, its epoch is incremented.
+fn migrate<'f, 'fs, S, T, F>(
+    current: &Version<'_, S>,
+    format: &'f Format<'fs, T>,
+    builder: F,
+) -> Result<Version<'static, T>, MigrateError>
+where
+    S: Scheme,
+    T: Scheme,
+    F: Fn() -> VersionBuilder<'f, 'fs, T>,
+{
+    let format_specs = specs(&format.tokens);
+    let epoch_spec = format_specs.iter().copied().find(|spec| spec.is_epoch());
+    let has_build = format_specs.iter().any(|spec| spec.is_build());
+    let epoch = current
+        .find_value(|spec, value| spec.is_epoch().then_some(value))
+        .unwrap_or(0);
+    let build_number = current.find_value(|spec, value| spec.is_build().then_some(value));
+
+    let build_with_epoch = |epoch| -> Result<Version<'static, T>, MigrateError> {
+        let mut builder = builder();
+        if let Some(epoch_spec) = epoch_spec {
+            builder = builder.set(epoch_spec, epoch);
+        }
+        if let (true, Some(build_number)) = (has_build, build_number) {
+            builder = builder.build_number(build_number);
+        }
+        Ok(builder.build()?)
+    };
+
+    let current_key = numeric_key(current);
+    let mut next = build_with_epoch(epoch)?;
+    if numeric_key(&next) <= current_key && epoch_spec.is_some() {
+        let next_epoch = epoch
+            .checked_add(1)
+            .ok_or(MigrateError::EpochOverflow { epoch })?;
+        next = build_with_epoch(next_epoch)?;
+    }
+    if numeric_key(&next) <= current_key {
+        return Err(MigrateError::NotGreater {
+            current: current.to_string(),
+            next: next.to_string(),
+        });
+    }
+    Ok(next)
+}
+
+/// The calendar values of a version, from which the values of other calendar specifiers can be
+/// determined.
+struct CalValues {
+    year: Option<SpecValue>,
+    iso_year: Option<SpecValue>,
+    quarter: Option<SpecValue>,
+    month: Option<SpecValue>,
+    week: Option<SpecValue>,
+    iso_week: Option<SpecValue>,
+    day: Option<SpecValue>,
+    day_of_year: Option<SpecValue>,
+    hour: Option<SpecValue>,
+    minute: Option<SpecValue>,
+}
+
+impl CalValues {
+    /// Returns the date of these values, if they have a year and either a month and day or a day of
+    /// the year.
+    fn date(&self) -> Option<NaiveDate> {
+        let year = i32::try_from(self.year?).ok()?;
+        match (self.month, self.day, self.day_of_year) {
+            (Some(month), Some(day), _) => NaiveDate::from_ymd_opt(year, month, day),
+            (_, _, Some(day_of_year)) => NaiveDate::from_yo_opt(year, day_of_year),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of the calendar specifier `spec`, or `None` if it is not a calendar
+    /// specifier.
+    fn value_of(&self, spec: &CalSpecifier) -> Result<Option<SpecValue>, ConvertError> {
+        // the value of `spec` on `date`, which converts years to short years
+        let on_date = |date: NaiveDate| {
+            spec.next_value(0, date.and_time(NaiveTime::MIN))
+                .map_err(VersionError::from)
+        };
+        let value = match spec {
+            CalSpecifier::Epoch | CalSpecifier::Build | CalSpecifier::Metadata => return Ok(None),
+            CalSpecifier::Year(_) => self
+                .year
+                .and_then(|year| NaiveDate::from_yo_opt(i32::try_from(year).ok()?, 1))
+                .map(on_date)
+                .transpose()?,
+            CalSpecifier::IsoYear => self.iso_year,
+            CalSpecifier::Quarter => self.quarter.or(self.month.map(|month| month.div_ceil(3))),
+            CalSpecifier::Month(_) | CalSpecifier::MonthName(_) => self.month,
+            CalSpecifier::Week(_) => self.week,
+            CalSpecifier::IsoWeek(_) => self.iso_week,
+            CalSpecifier::Day(_) => self.day,
+            CalSpecifier::DayOfYear(_) => self.day_of_year,
+            CalSpecifier::Hour(_) => self.hour,
+            CalSpecifier::Minute(_) => self.minute,
+        };
+        let value = match value {
+            Some(value) => value,
+            // times are not part of the date
+            None if matches!(spec, CalSpecifier::Hour(_) | CalSpecifier::Minute(_)) => {
+                return Err(missing_value(spec));
+            }
+            None => on_date(self.date().ok_or_else(|| missing_value(spec))?)?,
+        };
+        Ok(Some(value))
+    }
+}
+
+fn missing_value(spec: &CalSpecifier) -> ConvertError {
+    ConvertError::MissingValue {
+        spec: spec.to_string(),
+    }
+}
+
+impl Version<'_, Sem> {
+    /// Converts this version to another format, keeping the values of the specifiers in both. The
+    /// values of specifiers only in `format` are zero, and those of specifiers only in this
+    /// version must be zero, as they are dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nextver::prelude::*;
+    ///
+    /// let version = Sem::new_version("<MAJOR>.<MINOR>.<PATCH><PRE>", "1.2.3-rc.4")?;
+    /// let format = Sem::new_format("v<MAJOR>.<MINOR>.<PATCH>.<REVISION><PRE>")?;
+    /// assert_eq!("v1.2.3.0-rc.4", version.convert(&format)?.to_string());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result::Err`] of...
+    ///
+    /// - [`ConvertError::DroppedValue`] if a specifier with a non-zero value is not in `format`.
+    /// - [`ConvertError::Version`] if this version is a pre-release and `format` has no `<PRE>`
+    ///   specifier or none of its labels are the pre-release's, or if a value has more digits than
+    ///   its specifier's [fixed width](crate#fixed-widths).
+    pub fn convert(&self, format: &Format<'_, Sem>) -> Result<Version<'static, Sem>, ConvertError> {
+        self.convert_with(format, |_| Ok(None), |_| false)
+    }
+
+    /// Migrates this version to a calendar-semantic format, returning the first version of
+    /// `format` on `date`. Its semantic values are zero and it is not a pre-release.
+    ///
+    /// The migrated version supersedes this one: its epoch and build number are kept, if `format`
+    /// has them, and it is greater when the values of both are compared in order. If it would not
+    /// be, the epoch is incremented.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nextver::prelude::*;
+    ///
+    /// let version = Sem::new_version("<MAJOR>.<MINOR>.<PATCH>", "3.8.2")?;
+    /// let date = Date::explicit(2024, 10, 18)?;
+    ///
+    /// let format = CalSem::new_format("<YYYY>.<MM>.<PATCH>")?;
+    /// assert_eq!("2024.10.0", version.migrate(&format, date)?.to_string());
+    ///
+    /// // `24` is not greater than `30`, so the epoch is incremented
+    /// let version = Sem::new_version("<MAJOR>.<MINOR>.<PATCH>", "30.1.0")?;
+    /// let format = CalSem::new_format("[<EPOCH>!]<YY>.<MM>.<PATCH>")?;
+    /// assert_eq!("1!24.10.0", version.migrate(&format, date)?.to_string());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result::Err`] of...
+    ///
+    /// - [`MigrateError::NotGreater`] if the migrated version would not be greater than this one
+    ///   and `format` has no `<EPOCH>` specifier.
+    /// - [`MigrateError::EpochOverflow`] if the migrated version would not be greater than this
+    ///   one and its epoch is already [`SpecValue::MAX`].
+    /// - [`MigrateError::Version`] if the year of `date` would be negative for a short year
+    ///   specifier, or if a value has more digits than its specifier's
+    ///   [fixed width](crate#fixed-widths).
+    pub fn migrate(
+        &self,
+        format: &Format<'_, CalSem>,
+        date: impl Into<DateTime>,
+    ) -> Result<Version<'static, CalSem>, MigrateError> {
+        let date = date.into();
+        migrate(self, format, || format.builder(date))
+    }
+}
+
+impl Version<'_, Cal> {
+    /// Converts this version to another format, keeping the values of the specifiers in both.
+    ///
+    /// Calendar values are converted to other specifiers of the same unit, as in `<YY>` to `<YYYY>`
+    /// (where short years are years since 2000) or `<MM>` to `<0M>`. Other calendar values are
+    /// found from the date of this version, when it has a year and either a month and day or a day
+    /// of the year. The quarter can also be found from the month.
+    ///
+    /// The values of other specifiers only in `format`, like `<EPOCH>`, are zero. Calendar values
+    /// of specifiers only in this version are dropped, and others must be zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nextver::prelude::*;
+    ///
+    /// let version = Cal::new_version("<YY>.<MM>.<DD>", "24.9.3")?;
+    /// let format = Cal::new_format("<YYYY>.<0W>")?;
+    /// assert_eq!("2024.35", version.convert(&format)?.to_string());
+    ///
+    /// // the month and day can't be found from a week
+    /// let version = Cal::new_version("<YYYY>.<0W>", "2024.35")?;
+    /// let format = Cal::new_format("<YYYY>.<MM>.<DD>")?;
+    /// assert!(version.convert(&format).is_err());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result::Err`] of...
+    ///
+    /// - [`ConvertError::MissingValue`] if the value of a calendar specifier in `format` cannot be
+    ///   found from this version.
+    /// - [`ConvertError::DroppedValue`] if an epoch or build number with a non-zero value is not
+    ///   in `format`.
+    /// - [`ConvertError::Version`] if the year would be negative for a short year specifier, or if
+    ///   a value has more digits than its specifier's [fixed width](crate#fixed-widths).
+    pub fn convert(&self, format: &Format<'_, Cal>) -> Result<Version<'static, Cal>, ConvertError> {
+        let values = CalValues {
+            year: self.year(),
+            iso_year: self.iso_year(),
+            quarter: self.quarter(),
+            month: self.month(),
+            week: self.week(),
+            iso_week: self.iso_week(),
+            day: self.day(),
+            day_of_year: self.day_of_year(),
+            hour: self.hour(),
+            minute: self.minute(),
+        };
+        self.convert_with(
+            format,
+            |spec| values.value_of(spec),
+            |spec| !spec.is_epoch() && !spec.is_build(),
+        )
+    }
+}
+
+impl Version<'_, CalSem> {
+    /// Converts this version to another format, keeping the values of the specifiers in both.
+    ///
+    /// Calendar values are converted as they are for `Version<Cal>`. The values of semantic
+    /// specifiers only in `format` are zero, and those of semantic specifiers only in this version
+    /// must be zero, as they are dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nextver::prelude::*;
+    ///
+    /// let version = CalSem::new_version("<YY>.<MM>.<PATCH>", "24.9.3")?;
+    /// let format = CalSem::new_format("<YYYY>.<0M>.<PATCH>")?;
+    /// assert_eq!("2024.09.3", version.convert(&format)?.to_string());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result::Err`] of...
+    ///
+    /// - [`ConvertError::MissingValue`] if the value of a calendar specifier in `format` cannot be
+    ///   found from this version.
+    /// - [`ConvertError::DroppedValue`] if a semantic specifier with a non-zero value is not in
+    ///   `format`.
+    /// - [`ConvertError::Version`] if the year would be negative for a short year specifier, if
+    ///   this version is a pre-release and `format` has no `<PRE>` specifier or none of its labels
+    ///   are the pre-release's, or if a value has more digits than its specifier's
+    ///   [fixed width](crate#fixed-widths).
+    pub fn convert(
+        &self,
+        format: &Format<'_, CalSem>,
+    ) -> Result<Version<'static, CalSem>, ConvertError> {
+        let values = CalValues {
+            year: self.year(),
+            iso_year: self.iso_year(),
+            quarter: self.quarter(),
+            month: self.month(),
+            week: self.week(),
+            iso_week: self.iso_week(),
+            day: self.day(),
+            day_of_year: self.day_of_year(),
+            hour: self.hour(),
+            minute: self.minute(),
+        };
+        self.convert_with(
+            format,
+            |spec| match spec {
+                CalSemSpecifier::Cal(cal_spec) => values.value_of(&cal_spec.to_cal()),
+                CalSemSpecifier::Sem(_) => Ok(None),
+            },
+            |spec| matches!(spec, CalSemSpecifier::Cal(_)),
+        )
+    }
+
+    /// Migrates this version to a semantic format, returning the first version of `format` with
+    /// the major value `major`. Its other semantic values are zero and it is not a pre-release.
+    ///
+    /// The migrated version supersedes this one as it does for `Version<Sem>::migrate`, so a major
+    /// value less than the year of this version needs an `<EPOCH>` in `format`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nextver::prelude::*;
+    ///
+    /// let version = CalSem::new_version("<YYYY>.<MM>.<PATCH>", "2024.10.3")?;
+    ///
+    /// let format = Sem::new_format("<MAJOR>.<MINOR>.<PATCH>")?;
+    /// assert_eq!("2025.0.0", version.migrate(&format, 2025)?.to_string());
+    /// assert!(version.migrate(&format, 4).is_err());
+    ///
+    /// let format = Sem::new_format("[<EPOCH>!]<MAJOR>.<MINOR>.<PATCH>")?;
+    /// assert_eq!("1!4.0.0", version.migrate(&format, 4)?.to_string());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result::Err`] of...
+    ///
+    /// - [`MigrateError::NotGreater`] if the migrated version would not be greater than this one
+    ///   and `format` has no `<EPOCH>` specifier.
+    /// - [`MigrateError::EpochOverflow`] if the migrated version would not be greater than this
+    ///   one and its epoch is already [`SpecValue::MAX`].
+    /// - [`MigrateError::Version`] if a value has more digits than its specifier's
+    ///   [fixed width](crate#fixed-widths).
+    pub fn migrate(
+        &self,
+        format: &Format<'_, Sem>,
+        major: SpecValue,
+    ) -> Result<Version<'static, Sem>, MigrateError> {
+        migrate(self, format, || {
+            format.builder().value(SemLevel::Major, major)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Date;
+
+    #[test]
+    fn test_sem_convert() {
+        let version = Sem::new_version(
+            "<MAJOR>.<MINOR>.<PATCH><PRE>+<BUILD><META>",
+            "1.2.3-rc.4+5+ci",
+        )
+        .unwrap();
+        let convert = |format_str| {
+            version
+                .convert(&Sem::new_format(format_str).unwrap())
+                .map(|version| version.to_string())
+        };
+
+        assert_eq!(
+            Ok("0!1.2.3.0-rc.4+5+ci".to_string()),
+            convert("<EPOCH>!<MAJOR>.<MINOR>.<PATCH>.<REVISION><PRE>+<BUILD><META>")
+        );
+        assert_eq!(
+            Ok("1.2.003-rc.4+5".to_string()),
+            convert("<MAJOR>.<MINOR>.<PATCH:3><PRE:rc|pre>+<BUILD>")
+        );
+        assert_eq!(
+            Err(ConvertError::DroppedValue {
+                spec: "<PATCH>".to_string(),
+                value: 3
+            }),
+            convert("<MAJOR>.<MINOR><PRE>+<BUILD>")
+        );
+        assert_eq!(
+            Err(ConvertError::DroppedValue {
+                spec: "<BUILD>".to_string(),
+                value: 5
+            }),
+            convert("<MAJOR>.<MINOR>.<PATCH><PRE>")
+        );
+        assert_eq!(
+            Err(ConvertError::Version(VersionError::SpecifierNotInFormat {
+                spec: "<PRE>".to_string()
+            })),
+            convert("<MAJOR>.<MINOR>.<PATCH>+<BUILD>")
+        );
+        assert_eq!(
+            Err(ConvertError::Version(
+                VersionError::UnknownPreReleaseLabel {
+                    label: "rc".to_string()
+                }
+            )),
+            convert("<MAJOR>.<MINOR>.<PATCH><PRE:a|b>+<BUILD>")
+        );
+
+        // zero values can be dropped
+        let version = Sem::new_version("<MAJOR>.<MINOR>.<PATCH>", "1.2.0").unwrap();
+        assert_eq!(
+            "1.2",
+            version
+                .convert(&Sem::new_format("<MAJOR>.<MINOR>").unwrap())
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_cal_convert() {
+        let version = Cal::new_version("<YY>.<MM>.<DD>", "24.12.30").unwrap();
+        let convert_cal = |format_str| {
+            version
+                .convert(&Cal::new_format(format_str).unwrap())
+                .map(|version| version.to_string())
+        };
+
+        assert_eq!(Ok("2024.12".to_string()), convert_cal("<YYYY>.<0M>"));
+        assert_eq!(
+            Ok("0!24.Dec".to_string()),
+            convert_cal("<EPOCH>!<0Y>.<MON>")
+        );
+        assert_eq!(Ok("2024.4".to_string()), convert_cal("<YYYY>.<Q>"));
+        assert_eq!(Ok("2024.365".to_string()), convert_cal("<YYYY>.<DOY>"));
+        assert_eq!(Ok("2025.01".to_string()), convert_cal("<GGGG>.<0IW>"));
+        assert_eq!(Ok("2024.52".to_string()), convert_cal("<YYYY>.<WW>"));
+        assert_eq!(
+            Err(ConvertError::MissingValue {
+                spec: "<HH>".to_string()
+            }),
+            convert_cal("<YYYY>.<MM>.<DD>.<HH>")
+        );
+
+        let version = Cal::new_version("<YYYY>.<0W>", "1999.35").unwrap();
+        let convert_cal = |format_str| {
+            version
+                .convert(&Cal::new_format(format_str).unwrap())
+                .map(|version| version.to_string())
+        };
+        assert_eq!(
+            Err(ConvertError::MissingValue {
+                spec: "<MM>".to_string()
+            }),
+            convert_cal("<YYYY>.<MM>.<DD>")
+        );
+        assert_eq!(
+            Err(ConvertError::MissingValue {
+                spec: "<Q>".to_string()
+            }),
+            convert_cal("<YYYY>.<Q>")
+        );
+        assert_eq!(
+            Err(ConvertError::Version(VersionError::NegativeYearValue {
+                year: 1999
+            })),
+            convert_cal("<YY>.<WW>")
+        );
+    }
+
+    #[test]
+    fn test_cal_sem_convert() {
+        let version = CalSem::new_version("<YYYY>.<DOY>.<PATCH>", "2024.60.3").unwrap();
+        let convert_cal_sem = |format_str| {
+            version
+                .convert(&CalSem::new_format(format_str).unwrap())
+                .map(|version| version.to_string())
+        };
+
+        assert_eq!(
+            Ok("24.02.29.3.0".to_string()),
+            convert_cal_sem("<YY>.<0M>.<0D>.<PATCH>.<REVISION>")
+        );
+        assert_eq!(
+            Ok("2024.1.0.3".to_string()),
+            convert_cal_sem("<YYYY>.<Q>.<MINOR>.<PATCH>")
+        );
+        assert_eq!(
+            Err(ConvertError::DroppedValue {
+                spec: "<MINOR>".to_string(),
+                value: 2
+            }),
+            CalSem::new_version("<YYYY>.<MINOR>.<PATCH>", "2024.2.3")
+                .unwrap()
+                .convert(&CalSem::new_format("<YYYY>.<PATCH>").unwrap())
+        );
+
+        // the converted version is the same as one made from the date
+        let format = CalSem::new_format("<YYYY>.<0M>.<0D>.<PATCH>").unwrap();
+        let date = Date::explicit(2024, 2, 29).unwrap();
+        assert_eq!(
+            format
+                .builder(date)
+                .value(crate::CalSemLevel::Patch, 3)
+                .build()
+                .unwrap(),
+            version.convert(&format).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_migrate_to_cal_sem() {
+        let date = Date::explicit(2024, 10, 18).unwrap();
+        let migrate = |version_str, format_str| {
+            Sem::new_version("[<EPOCH>!]<MAJOR>.<MINOR>.<PATCH>[+<BUILD>]", version_str)
+                .unwrap()
+                .migrate(&CalSem::new_format(format_str).unwrap(), date)
+                .map(|version| version.to_string())
+        };
+
+        assert_eq!(
+            Ok("2024.10.0".to_string()),
+            migrate("3.8.2", "<YYYY>.<0M>.<PATCH>")
+        );
+        assert_eq!(
+            Ok("2024.10.18.0.0".to_string()),
+            migrate("3.8.2", "<YYYY>.<MM>.<DD>.<MINOR>.<PATCH>")
+        );
+        assert_eq!(
+            Ok("2!2024.10.0+41".to_string()),
+            migrate("2!3.8.2+41", "[<EPOCH>!]<YYYY>.<MM>.<PATCH>[+<BUILD>]")
+        );
+        assert_eq!(
+            Ok("1!24.10.0".to_string()),
+            migrate("30.1.0", "[<EPOCH>!]<YY>.<MM>.<PATCH>")
+        );
+        assert_eq!(
+            Ok("3!24.10.0".to_string()),
+            migrate("2!30.1.0", "<EPOCH>!<YY>.<MM>.<PATCH>")
+        );
+        assert_eq!(
+            Err(MigrateError::NotGreater {
+                current: "30.1.0".to_string(),
+                next: "24.10.0".to_string()
+            }),
+            migrate("30.1.0", "<YY>.<MM>.<PATCH>")
+        );
+        // the epoch can't be dropped
+        assert!(matches!(
+            migrate("1!3.8.2", "<YYYY>.<MM>.<PATCH>"),
+            Err(MigrateError::NotGreater { .. })
+        ));
+        assert_eq!(
+            Err(MigrateError::EpochOverflow {
+                epoch: SpecValue::MAX
+            }),
+            migrate("4294967295!30.1.0", "<EPOCH>!<YY>.<MM>.<PATCH>")
+        );
+    }
+
+    #[test]
+    fn test_migrate_to_sem() {
+        let version = CalSem::new_version("<YYYY>.<0M>.<PATCH>", "2024.10.3").unwrap();
+        let migrate = |format_str, major| {
+            version
+                .migrate(&Sem::new_format(format_str).unwrap(), major)
+                .map(|version| version.to_string())
+        };
+
+        assert_eq!(
+            Ok("2025.0.0".to_string()),
+            migrate("<MAJOR>.<MINOR>.<PATCH>", 2025)
+        );
+        assert_eq!(
+            Ok("1!5.0.0".to_string()),
+            migrate("[<EPOCH>!]<MAJOR>.<MINOR>.<PATCH>", 5)
+        );
+        assert_eq!(
+            Err(MigrateError::NotGreater {
+                current: "2024.10.3".to_string(),
+                next: "2024.0.0".to_string()
+            }),
+            migrate("<MAJOR>.<MINOR>.<PATCH>", 2024)
+        );
+        assert_eq!(
+            Err(MigrateError::Version(VersionError::ValueOverflowsWidth {
+                spec: "<MAJOR>".to_string(),
+                value: 2025,
+                width: 2
+            })),
+            migrate("<MAJOR:2>.<MINOR>.<PATCH>", 2025)
+        );
+    }
+}
@@ -1,7 +1,18 @@
 use crate::{
-    scheme::Scheme,
-    specifier::Specifier,
-    version::{Version, VersionError},
+    builder::VersionBuilder,
+    requirement::{Requirement, RequirementError},
+    scheme::{Cal, CalSem, Scheme, SchemeRules, Sem},
+    specifier::{
+        are_valid_labels, CalSemSpecifier, CalSpecifier, PreReleaseLabels, Specifier,
+        SpecifierRules, MAX_SPECIFIER_RULES, PRE_RELEASE_DEFAULT_LABELS, SEM_MINOR,
+    },
+    version::{DateTime, Version, VersionError},
+    SemLevel,
+};
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+    vec::Vec,
 };
 use core::{
     fmt::{self, Display},
@@ -12,26 +23,86 @@ use core::{
 pub(crate) enum FormatToken<'fs, S: Scheme> {
     Specifier(&'static S::Specifier),
 
+    /// A specifier given an explicit width, as in `<PATCH:3>`. Its values are zero-padded to, and
+    /// must parse to, exactly that many digits.
+    FixedWidth {
+        spec: &'static S::Specifier,
+        width: usize,
+    },
+
+    /// A pre-release specifier, along with the labels it accepts.
+    PreRelease {
+        spec: &'static S::Specifier,
+        labels: PreReleaseLabels,
+    },
+
+    /// The start of an optional segment, as in `[.<PATCH>]`. The tokens up to the next
+    /// [`Self::OptionalEnd`] are inside the square brackets, since segments are not nested.
+    OptionalStart,
+
+    /// The end of an optional segment.
+    OptionalEnd,
+
     /// A literal holds an array of bytes from the format string. Note that this make contained
     /// escaped brackets, so these bytes are not necessarily what will match the version string.
-    Literal(&'fs [u8]),
+    Literal(Cow<'fs, [u8]>),
 }
 
-impl<'fs, S: Scheme> Clone for FormatToken<'fs, S> {
+impl<S: Scheme> Clone for FormatToken<'_, S> {
     // manually implemented because the derive macro would want Scheme to be Clone, which really
     // feels unnecessary.
     fn clone(&self) -> Self {
         match self {
             FormatToken::Specifier(spec) => FormatToken::Specifier(*spec),
-            FormatToken::Literal(text) => FormatToken::Literal(text),
+            FormatToken::FixedWidth { spec, width } => FormatToken::FixedWidth {
+                spec: *spec,
+                width: *width,
+            },
+            FormatToken::PreRelease { spec, labels } => FormatToken::PreRelease {
+                spec: *spec,
+                labels: labels.clone(),
+            },
+            FormatToken::OptionalStart => FormatToken::OptionalStart,
+            FormatToken::OptionalEnd => FormatToken::OptionalEnd,
+            FormatToken::Literal(text) => FormatToken::Literal(text.clone()),
+        }
+    }
+}
+
+impl<S: Scheme> FormatToken<'_, S> {
+    fn into_owned(self) -> FormatToken<'static, S> {
+        match self {
+            FormatToken::Specifier(spec) => FormatToken::Specifier(spec),
+            FormatToken::FixedWidth { spec, width } => FormatToken::FixedWidth { spec, width },
+            FormatToken::PreRelease { spec, labels } => FormatToken::PreRelease { spec, labels },
+            FormatToken::OptionalStart => FormatToken::OptionalStart,
+            FormatToken::OptionalEnd => FormatToken::OptionalEnd,
+            FormatToken::Literal(text) => FormatToken::Literal(Cow::Owned(text.into_owned())),
         }
     }
 }
 
-impl<'fs, S: Scheme> Display for FormatToken<'fs, S> {
+impl<S: Scheme> Display for FormatToken<'_, S> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             FormatToken::Specifier(spec) => write!(f, "{spec}"),
+            FormatToken::FixedWidth { spec, width } => {
+                // write the width as an argument inside the brackets, e.g. `<PATCH:3>`
+                let name = String::from_utf8_lossy(pattern_name(*spec));
+                write!(f, "{name}:{width}>")
+            }
+            FormatToken::PreRelease { spec, labels } => {
+                if labels.is_default() {
+                    write!(f, "{spec}")
+                } else {
+                    // write the labels as an argument inside the brackets, e.g. `<PRE:dev|rc>`
+                    let name = String::from_utf8_lossy(pattern_name(*spec));
+                    let labels = labels.iter().collect::<Vec<_>>().join("|");
+                    write!(f, "{name}:{labels}>")
+                }
+            }
+            FormatToken::OptionalStart => f.write_str("["),
+            FormatToken::OptionalEnd => f.write_str("]"),
             FormatToken::Literal(text) => {
                 let text_str = unsafe { str::from_utf8_unchecked(text) };
                 f.write_str(text_str)
@@ -46,16 +117,12 @@ impl<'fs, S: Scheme> Display for FormatToken<'fs, S> {
 #[derive(thiserror::Error, Debug, PartialEq)]
 pub enum FormatError {
     /// The specifier is not terminated with a closing bracket.
-    #[error(
-        "specifier in format should be terminated with a closing square bracket (`>`), got `{pattern}`"
-    )]
     UnterminatedSpecifier {
         /// The unterminated specifier string
         pattern: String,
     },
 
     /// The specifier is not a valid specifier for the scheme
-    #[error("specifier `{spec}` is not valid in {scheme_name} format")]
     UnacceptableSpecifier {
         /// The specifier
         spec: String,
@@ -71,7 +138,6 @@ pub enum FormatError {
     /// - `<MAJOR><MAJOR>`: not decreasing
     /// - `<MAJOR><MINOR><PATCH><MINOR>`: last minor does not decrease     
     /// - `<YYYY><DD>`: decreasing by more than one step (days are only relative to months)
-    #[error("specifiers must step decrease by their significance, got `{next}` after `{prev}`")]
     SpecifiersMustStepDecrease {
         /// The first specifier
         prev: String,
@@ -80,9 +146,6 @@ pub enum FormatError {
     },
 
     /// The first specifier in a format is not allowed to be there
-    #[error(
-        "in {scheme_name} format, first specifier should be {expected_first}, got `{first_spec}`"
-    )]
     WrongFirstSpecifier {
         /// The specifier
         first_spec: String,
@@ -93,9 +156,6 @@ pub enum FormatError {
     },
 
     /// The last specifier in a format does not complete the format
-    #[error(
-        "in {scheme_name} format, last specifier should be {expected_last}, got `{last_spec}`"
-    )]
     Incomplete {
         /// The last specifier
         last_spec: String,
@@ -106,124 +166,1035 @@ pub enum FormatError {
     },
 
     /// The format string should contain at least one specifier
-    #[error("format should contain at least one specifier")]
     NoSpecifiersInFormat,
+
+    /// The labels given to a pre-release specifier (as in `<PRE:alpha|beta|rc>`) are invalid.
+    InvalidPreReleaseLabels {
+        /// The labels argument text
+        labels: String,
+    },
+
+    /// An optional segment is not terminated with a closing square bracket.
+    UnterminatedOptional {
+        /// The unterminated optional segment string
+        pattern: String,
+    },
+
+    /// An optional segment was opened inside of another one.
+    NestedOptional {
+        /// The format string from the start of the outer optional segment
+        pattern: String,
+    },
+
+    /// An optional segment contains no specifiers.
+    NoSpecifiersInOptional {
+        /// The optional segment string
+        segment: String,
+    },
+
+    /// A specifier in an optional segment cannot be absent, because it cannot be zero.
+    UnacceptableOptionalSpecifier {
+        /// The specifier
+        spec: String,
+    },
+
+    /// The width given to a specifier (as in `<PATCH:3>`) is invalid, or less than the specifier's
+    /// [`min_width`](crate::Specifier::min_width), as in `<YYYY:2>`.
+    InvalidWidth {
+        /// The width argument text
+        width: String,
+        /// The least width the specifier may be given
+        min_width: usize,
+    },
+
+    /// The [`format_pattern`](crate::Specifier::format_pattern) of a specifier in a custom scheme
+    /// is invalid.
+    InvalidSpecifierPattern {
+        /// The specifier pattern
+        pattern: String,
+    },
+
+    /// A custom scheme has more than 128 specifiers, which is more than formats can be checked
+    /// with.
+    TooManySpecifiers {
+        /// The number of specifiers
+        count: usize,
+    },
 }
 
-/// A Format describes the structure of a version, comprised of *specifiers* and *literal text*.
-///
-/// Later, the `Format` can be used to parse a version string into a
-/// [`Version`](crate::version::Version) struct.
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Format<'fs, S: Scheme> {
-    pub(crate) tokens: Vec<FormatToken<'fs, S>>,
+// The texts of the message of each `FormatError`, which its fields are written between. Both its
+// `Display` and the compile-time errors of `format_str!` are written from these.
+const UNTERMINATED_SPECIFIER: &[&str] = &[
+    "specifier in format should be terminated with a closing square bracket (`>`), got `",
+    "`",
+];
+const UNACCEPTABLE_SPECIFIER: &[&str] = &["specifier `", "` is not valid in ", " format"];
+const SPECIFIERS_MUST_STEP_DECREASE: &[&str] = &[
+    "specifiers must step decrease by their significance, got `",
+    "` after `",
+    "`",
+];
+const WRONG_FIRST_SPECIFIER: &[&str] =
+    &["in ", " format, first specifier should be ", ", got `", "`"];
+const INCOMPLETE: &[&str] = &["in ", " format, last specifier should be ", ", got `", "`"];
+const NO_SPECIFIERS_IN_FORMAT: &[&str] = &["format should contain at least one specifier"];
+const INVALID_PRE_RELEASE_LABELS: &[&str] = &[
+    "pre-release labels should be unique, non-empty, `|`-separated, and contain only ASCII alphanumerics and hyphens, got `",
+    "`",
+];
+const UNTERMINATED_OPTIONAL: &[&str] = &[
+    "optional segment in format should be terminated with a closing square bracket (`]`), got `",
+    "`",
+];
+const NESTED_OPTIONAL: &[&str] = &[
+    "optional segments in format should not be nested, got `",
+    "`",
+];
+const NO_SPECIFIERS_IN_OPTIONAL: &[&str] = &[
+    "optional segment should contain at least one specifier, got `",
+    "`",
+];
+const UNACCEPTABLE_OPTIONAL_SPECIFIER: &[&str] = &[
+    "specifier `",
+    "` should be able to be zero to be in an optional segment",
+];
+const INVALID_WIDTH: &[&str] = &[
+    "specifier width should be an integer of at least ",
+    ", got `",
+    "`",
+];
+const INVALID_SPECIFIER_PATTERN: &[&str] = &[
+    "specifier pattern should be non-empty, printable ASCII within `<` and `>`, without `<`, `>`, `[`, `]`, or `:` inside, got `",
+    "`",
+];
+const TOO_MANY_SPECIFIERS: &[&str] = &["scheme should have at most 128 specifiers, got ", ""];
+
+impl Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (template, fields): (&[&str], &[&dyn Display]) = match self {
+            FormatError::UnterminatedSpecifier { pattern } => (UNTERMINATED_SPECIFIER, &[pattern]),
+            FormatError::UnacceptableSpecifier { spec, scheme_name } => {
+                (UNACCEPTABLE_SPECIFIER, &[spec, scheme_name])
+            }
+            FormatError::SpecifiersMustStepDecrease { prev, next } => {
+                (SPECIFIERS_MUST_STEP_DECREASE, &[next, prev])
+            }
+            FormatError::WrongFirstSpecifier {
+                first_spec,
+                scheme_name,
+                expected_first,
+            } => (
+                WRONG_FIRST_SPECIFIER,
+                &[scheme_name, expected_first, first_spec],
+            ),
+            FormatError::Incomplete {
+                last_spec,
+                scheme_name,
+                expected_last,
+            } => (INCOMPLETE, &[scheme_name, expected_last, last_spec]),
+            FormatError::NoSpecifiersInFormat => (NO_SPECIFIERS_IN_FORMAT, &[]),
+            FormatError::InvalidPreReleaseLabels { labels } => {
+                (INVALID_PRE_RELEASE_LABELS, &[labels])
+            }
+            FormatError::UnterminatedOptional { pattern } => (UNTERMINATED_OPTIONAL, &[pattern]),
+            FormatError::NestedOptional { pattern } => (NESTED_OPTIONAL, &[pattern]),
+            FormatError::NoSpecifiersInOptional { segment } => {
+                (NO_SPECIFIERS_IN_OPTIONAL, &[segment])
+            }
+            FormatError::UnacceptableOptionalSpecifier { spec } => {
+                (UNACCEPTABLE_OPTIONAL_SPECIFIER, &[spec])
+            }
+            FormatError::InvalidWidth { width, min_width } => (INVALID_WIDTH, &[min_width, width]),
+            FormatError::InvalidSpecifierPattern { pattern } => {
+                (INVALID_SPECIFIER_PATTERN, &[pattern])
+            }
+            FormatError::TooManySpecifiers { count } => (TOO_MANY_SPECIFIERS, &[count]),
+        };
+        for (index, text) in template.iter().enumerate() {
+            f.write_str(text)?;
+            if let Some(field) = fields.get(index) {
+                field.fmt(f)?;
+            }
+        }
+        Ok(())
+    }
 }
 
-impl<'fs, S: Scheme> Format<'fs, S> {
-    pub(crate) fn parse(format_str: &'fs str) -> Result<Self, FormatError> {
-        let mut format = format_str.as_bytes();
-        let mut tokens = Vec::with_capacity(S::MAX_TOKENS);
-        let mut last_spec: Option<&'static S::Specifier> = None;
+/// Returns the text before the item at `index` in a list of `count` items in an error message, as
+/// in `` `a` ``, `` `a` or `b` ``, or `` `a`, `b`, or `c` ``.
+const fn list_separator(index: usize, count: usize) -> &'static str {
+    match (index, count) {
+        (0, _) => "",
+        (_, 2) => " or ",
+        _ if index + 1 == count => ", or ",
+        _ => ", ",
+    }
+}
+
+/// Returns true if the specifier of `rules` is listed in the error message of a format whose last
+/// specifier can't be last, if `last`, or else whose first specifier can't be first.
+const fn is_listed<Sp>(rules: &SpecifierRules<Sp>, last: bool) -> bool {
+    if last {
+        rules.last
+    } else {
+        rules.first
+    }
+}
+
+/// A [`FormatError`] found by a [`Parser`], which can be made in `const` contexts. Specifiers are
+/// given by their index in the scheme's rules, and text by its byte range in the format string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ParseError {
+    UnterminatedSpecifier {
+        start: usize,
+    },
+    UnacceptableSpecifier {
+        start: usize,
+        end: usize,
+    },
+    SpecifiersMustStepDecrease {
+        prev: usize,
+        next: usize,
+    },
+    WrongFirstSpecifier {
+        spec: usize,
+    },
+    Incomplete {
+        spec: usize,
+    },
+    NoSpecifiersInFormat,
+    InvalidPreReleaseLabels {
+        start: usize,
+        end: usize,
+    },
+    UnterminatedOptional {
+        start: usize,
+    },
+    NestedOptional {
+        start: usize,
+    },
+    NoSpecifiersInOptional {
+        start: usize,
+        end: usize,
+    },
+    UnacceptableOptionalSpecifier {
+        spec: usize,
+    },
+    InvalidWidth {
+        start: usize,
+        end: usize,
+        min_width: usize,
+    },
+    InvalidSpecifierPattern {
+        spec: usize,
+    },
+    TooManySpecifiers {
+        count: usize,
+    },
+}
 
-        while !format.is_empty() {
-            let matched_spec = S::Specifier::all()
+impl ParseError {
+    /// Returns the [`FormatError`] of this error in `format_str`, which was parsed with `rules`.
+    fn into_format_error<Sp: Specifier>(
+        self,
+        rules: SchemeRules<'_, Sp>,
+        format_str: &str,
+    ) -> FormatError {
+        let text = |start: usize, end: usize| format_str[start..end].to_string();
+        let spec = |index: usize| rules.specifiers[index].spec.to_string();
+        let list = |last: bool| {
+            let listed = rules
+                .specifiers
                 .iter()
-                .find(|spec| format.starts_with(spec.format_pattern()));
-
-            let consume_len = if let Some(&spec) = matched_spec {
-                // check that specifiers are in order
-                if let Some(last_spec) = last_spec {
-                    if !last_spec.can_be_left_adjacent_to(spec) {
-                        return Err(FormatError::SpecifiersMustStepDecrease {
-                            prev: last_spec.to_string(),
-                            next: spec.to_string(),
-                        });
-                    }
-                } else {
-                    // check that this is an ok first spec
-                    if !spec.can_be_first() {
-                        return Err(FormatError::WrongFirstSpecifier {
-                            first_spec: spec.to_string(),
-                            scheme_name: S::name(),
-                            expected_first: S::first_variants_string(),
-                        });
-                    }
+                .filter(|spec_rules| is_listed(spec_rules, last))
+                .collect::<Vec<_>>();
+            let mut list = String::new();
+            for (index, spec_rules) in listed.iter().enumerate() {
+                list.push_str(list_separator(index, listed.len()));
+                list.push('`');
+                list.push_str(&spec_rules.spec.to_string());
+                list.push('`');
+            }
+            list
+        };
+        match self {
+            ParseError::UnterminatedSpecifier { start } => FormatError::UnterminatedSpecifier {
+                pattern: text(start, format_str.len()),
+            },
+            ParseError::UnacceptableSpecifier { start, end } => {
+                FormatError::UnacceptableSpecifier {
+                    spec: text(start, end),
+                    scheme_name: rules.name,
                 }
-                last_spec = Some(spec);
-                tokens.push(FormatToken::Specifier(spec));
-                spec.format_pattern().len()
-            } else {
-                // check if its escaped brackets, an unknown/unterminated specifier, or finally,
-                // just a literal.
-                let (literal, consume_len) = if format.starts_with(b"<<") {
-                    // escaped opening bracket
-                    (&format[0..2], 2)
-                } else if format.starts_with(&[b'<']) {
-                    // determine if unknown or unterminated specifier. we technically don't need to
-                    // error here: could just parse this as a literal because, above, we've already
-                    // exhausted all known specifiers, but this helps the user.
-                    let closing_index = format[1..]
-                        .iter()
-                        .position(|c| *c == b'>')
-                        .map(|index| {
-                            // 1 for opening bracket that we skipped
-                            index + 1
-                        })
-                        .ok_or_else(|| {
-                            // didn't find closing bracket
-                            FormatError::UnterminatedSpecifier {
-                                pattern: unsafe { std::str::from_utf8_unchecked(format) }
-                                    .to_string(),
-                            }
-                        })?;
-                    // found closing, but unknown for this scheme
-                    return Err(FormatError::UnacceptableSpecifier {
-                        spec: unsafe { std::str::from_utf8_unchecked(&format[..=closing_index]) }
-                            .to_string(),
-                        scheme_name: S::name(),
-                    });
-                } else {
-                    // any other literal.
-                    (&format[0..1], 1)
-                };
-
-                // we can add this literal to the last token if it was also a literal.
-                // this will help us cut down on the total number of tokens and therefore, regex
-                // groups later.
-                if let Some(FormatToken::Literal(last_literal)) = tokens.last_mut() {
-                    // fast str "concat": we just increase the length of the last literal by the
-                    // size of the new literal. this works because the additional char is in
-                    // contiguous memory and we know that the length of the underlying string is at
-                    // least this long.
-                    *last_literal = unsafe {
-                        core::slice::from_raw_parts(
-                            last_literal.as_ptr(),              //same ptr
-                            last_literal.len() + literal.len(), // new len
-                        )
-                    };
-                } else {
-                    tokens.push(FormatToken::Literal(literal));
+            }
+            ParseError::SpecifiersMustStepDecrease { prev, next } => {
+                FormatError::SpecifiersMustStepDecrease {
+                    prev: spec(prev),
+                    next: spec(next),
+                }
+            }
+            ParseError::WrongFirstSpecifier { spec: index } => FormatError::WrongFirstSpecifier {
+                first_spec: spec(index),
+                scheme_name: rules.name,
+                expected_first: list(false),
+            },
+            ParseError::Incomplete { spec: index } => FormatError::Incomplete {
+                last_spec: spec(index),
+                scheme_name: rules.name,
+                expected_last: list(true),
+            },
+            ParseError::NoSpecifiersInFormat => FormatError::NoSpecifiersInFormat,
+            ParseError::InvalidPreReleaseLabels { start, end } => {
+                FormatError::InvalidPreReleaseLabels {
+                    labels: text(start, end),
                 }
+            }
+            ParseError::UnterminatedOptional { start } => FormatError::UnterminatedOptional {
+                pattern: text(start, format_str.len()),
+            },
+            ParseError::NestedOptional { start } => FormatError::NestedOptional {
+                pattern: text(start, format_str.len()),
+            },
+            ParseError::NoSpecifiersInOptional { start, end } => {
+                FormatError::NoSpecifiersInOptional {
+                    segment: text(start, end),
+                }
+            }
+            ParseError::UnacceptableOptionalSpecifier { spec: index } => {
+                FormatError::UnacceptableOptionalSpecifier { spec: spec(index) }
+            }
+            ParseError::InvalidWidth {
+                start,
+                end,
+                min_width,
+            } => FormatError::InvalidWidth {
+                width: text(start, end),
+                min_width,
+            },
+            ParseError::InvalidSpecifierPattern { spec: index } => {
+                FormatError::InvalidSpecifierPattern {
+                    pattern: String::from_utf8_lossy(rules.specifiers[index].pattern).into_owned(),
+                }
+            }
+            ParseError::TooManySpecifiers { count } => FormatError::TooManySpecifiers { count },
+        }
+    }
 
-                consume_len
-            };
+    /// Returns the texts of the message of this error, like [`FormatError`]'s.
+    const fn template(self) -> &'static [&'static str] {
+        match self {
+            ParseError::UnterminatedSpecifier { .. } => UNTERMINATED_SPECIFIER,
+            ParseError::UnacceptableSpecifier { .. } => UNACCEPTABLE_SPECIFIER,
+            ParseError::SpecifiersMustStepDecrease { .. } => SPECIFIERS_MUST_STEP_DECREASE,
+            ParseError::WrongFirstSpecifier { .. } => WRONG_FIRST_SPECIFIER,
+            ParseError::Incomplete { .. } => INCOMPLETE,
+            ParseError::NoSpecifiersInFormat => NO_SPECIFIERS_IN_FORMAT,
+            ParseError::InvalidPreReleaseLabels { .. } => INVALID_PRE_RELEASE_LABELS,
+            ParseError::UnterminatedOptional { .. } => UNTERMINATED_OPTIONAL,
+            ParseError::NestedOptional { .. } => NESTED_OPTIONAL,
+            ParseError::NoSpecifiersInOptional { .. } => NO_SPECIFIERS_IN_OPTIONAL,
+            ParseError::UnacceptableOptionalSpecifier { .. } => UNACCEPTABLE_OPTIONAL_SPECIFIER,
+            ParseError::InvalidWidth { .. } => INVALID_WIDTH,
+            ParseError::InvalidSpecifierPattern { .. } => INVALID_SPECIFIER_PATTERN,
+            ParseError::TooManySpecifiers { .. } => TOO_MANY_SPECIFIERS,
+        }
+    }
+}
+
+/// The longest error message that [`format_str!`](crate::format_str) reports. Longer ones are
+/// truncated.
+const MESSAGE_CAPACITY: usize = 512;
+
+/// The message of a [`ParseError`], written in a `const` context where it can't be a `String`.
+/// Specifiers are written as their patterns.
+struct Message {
+    buf: [u8; MESSAGE_CAPACITY],
+    len: usize,
+}
+
+impl Message {
+    const fn new<Sp>(err: ParseError, rules: SchemeRules<'_, Sp>, format: &[u8]) -> Self {
+        let mut message = Self {
+            buf: [0; MESSAGE_CAPACITY],
+            len: 0,
+        };
+        let template = err.template();
+        let mut index = 0;
+        while index < template.len() {
+            message.push(template[index].as_bytes());
+            message.push_field(err, index, rules, format);
+            index += 1;
+        }
+        message
+    }
+
+    /// Pushes the field of `err` at `index`, in the order of its [`FormatError`]'s message, if it
+    /// has one.
+    const fn push_field<Sp>(
+        &mut self,
+        err: ParseError,
+        index: usize,
+        rules: SchemeRules<'_, Sp>,
+        format: &[u8],
+    ) {
+        let specs = rules.specifiers;
+        match (err, index) {
+            (
+                ParseError::UnterminatedSpecifier { start }
+                | ParseError::UnterminatedOptional { start }
+                | ParseError::NestedOptional { start },
+                0,
+            ) => self.push(byte_range(format, start, format.len())),
+            (
+                ParseError::UnacceptableSpecifier { start, end }
+                | ParseError::InvalidPreReleaseLabels { start, end }
+                | ParseError::NoSpecifiersInOptional { start, end },
+                0,
+            )
+            | (ParseError::InvalidWidth { start, end, .. }, 1) => {
+                self.push(byte_range(format, start, end));
+            }
+            (ParseError::UnacceptableSpecifier { .. }, 1)
+            | (ParseError::WrongFirstSpecifier { .. } | ParseError::Incomplete { .. }, 0) => {
+                self.push(rules.name.as_bytes());
+            }
+            (ParseError::SpecifiersMustStepDecrease { next, .. }, 0) => {
+                self.push(specs[next].pattern);
+            }
+            (ParseError::SpecifiersMustStepDecrease { prev, .. }, 1) => {
+                self.push(specs[prev].pattern);
+            }
+            (ParseError::WrongFirstSpecifier { spec } | ParseError::Incomplete { spec }, 2)
+            | (
+                ParseError::UnacceptableOptionalSpecifier { spec }
+                | ParseError::InvalidSpecifierPattern { spec },
+                0,
+            ) => self.push(specs[spec].pattern),
+            (ParseError::WrongFirstSpecifier { .. }, 1) => self.push_list(specs, false),
+            (ParseError::Incomplete { .. }, 1) => self.push_list(specs, true),
+            (
+                ParseError::InvalidWidth {
+                    min_width: number, ..
+                }
+                | ParseError::TooManySpecifiers { count: number },
+                0,
+            ) => self.push_usize(number),
+            _ => {}
+        }
+    }
+
+    const fn push(&mut self, bytes: &[u8]) {
+        let mut index = 0;
+        while index < bytes.len() && self.len < MESSAGE_CAPACITY {
+            self.buf[self.len] = bytes[index];
+            self.len += 1;
+            index += 1;
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)] // digits fit in a byte
+    const fn push_usize(&mut self, value: usize) {
+        let mut digits = [0; 20];
+        let mut len = 0;
+        let mut rest = value;
+        loop {
+            digits[digits.len() - 1 - len] = b'0' + (rest % 10) as u8;
+            len += 1;
+            rest /= 10;
+            if rest == 0 {
+                break;
+            }
+        }
+        let (_, digits) = digits.split_at(digits.len() - len);
+        self.push(digits);
+    }
+
+    /// Pushes the patterns of the specifiers that may be last, if `last`, or else first, as a list.
+    const fn push_list<Sp>(&mut self, specs: &[SpecifierRules<Sp>], last: bool) {
+        let mut count = 0;
+        let mut index = 0;
+        while index < specs.len() {
+            if is_listed(&specs[index], last) {
+                count += 1;
+            }
+            index += 1;
+        }
+        let mut listed = 0;
+        let mut index = 0;
+        while index < specs.len() {
+            if is_listed(&specs[index], last) {
+                self.push(list_separator(listed, count).as_bytes());
+                self.push(b"`");
+                self.push(specs[index].pattern);
+                self.push(b"`");
+                listed += 1;
+            }
+            index += 1;
+        }
+    }
+
+    const fn as_str(&self) -> &str {
+        let (bytes, _) = self.buf.split_at(self.len);
+        match str::from_utf8(bytes) {
+            Ok(message) => message,
+            // truncated in the middle of a character
+            Err(err) => match str::from_utf8(bytes.split_at(err.valid_up_to()).0) {
+                Ok(message) => message,
+                Err(_) => "",
+            },
+        }
+    }
+}
 
-            format = &format[consume_len..];
+/// Returns true if `a` and `b` are the same bytes, which can't be compared with `==` in `const`
+/// contexts.
+pub(crate) const fn bytes_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && has_at(a, 0, b)
+}
+
+/// Returns true if `bytes` has `prefix` at `at`.
+const fn has_at(bytes: &[u8], at: usize, prefix: &[u8]) -> bool {
+    if at + prefix.len() > bytes.len() {
+        return false;
+    }
+    let mut index = 0;
+    while index < prefix.len() {
+        if bytes[at + index] != prefix[index] {
+            return false;
         }
+        index += 1;
+    }
+    true
+}
 
-        if let Some(last_spec) = last_spec {
-            if !last_spec.can_be_last() {
-                return Err(FormatError::Incomplete {
-                    last_spec: last_spec.to_string(),
-                    scheme_name: S::name(),
-                    expected_last: S::last_variants_string(),
+/// Returns the index of the first `byte` in `bytes` at or after `from`.
+const fn position(bytes: &[u8], from: usize, byte: u8) -> Option<usize> {
+    let mut index = from;
+    while index < bytes.len() {
+        if bytes[index] == byte {
+            return Some(index);
+        }
+        index += 1;
+    }
+    None
+}
+
+/// Returns `bytes[start..end]`, which can't be indexed like that in `const` contexts.
+const fn byte_range(bytes: &[u8], start: usize, end: usize) -> &[u8] {
+    let (_, rest) = bytes.split_at(start);
+    let (range, _) = rest.split_at(end - start);
+    range
+}
+
+/// Returns true if `pattern` is a valid specifier pattern, such as `<MAJOR>`: printable ASCII
+/// within angle brackets, with at least one character and no brackets or colons inside. Parsing
+/// relies on this to only split format strings between characters.
+const fn is_valid_pattern(pattern: &[u8]) -> bool {
+    let [b'<', name @ .., b'>'] = pattern else {
+        return false;
+    };
+    let mut index = 0;
+    while index < name.len() {
+        if !name[index].is_ascii_graphic()
+            || matches!(name[index], b'<' | b'>' | b'[' | b']' | b':')
+        {
+            return false;
+        }
+        index += 1;
+    }
+    !name.is_empty()
+}
+
+/// Parses an explicit width like `str::parse::<usize>` does, which accepts a leading `+`.
+const fn parse_width(width: &[u8]) -> Option<usize> {
+    let digits = match width {
+        [b'+', digits @ ..] => digits,
+        _ => width,
+    };
+    if digits.is_empty() {
+        return None;
+    }
+    let mut value: usize = 0;
+    let mut index = 0;
+    while index < digits.len() {
+        if !digits[index].is_ascii_digit() {
+            return None;
+        }
+        value = match value.checked_mul(10) {
+            Some(value) => match value.checked_add((digits[index] - b'0') as usize) {
+                Some(value) => value,
+                None => return None,
+            },
+            None => return None,
+        };
+        index += 1;
+    }
+    Some(value)
+}
+
+/// Returns the pattern of `spec` without its closing bracket, such as `<PATCH`, which an argument
+/// is written after.
+fn pattern_name(spec: &impl Specifier) -> &'static [u8] {
+    let pattern = spec.format_pattern();
+    pattern.strip_suffix(b">").unwrap_or(pattern)
+}
+
+/// A token read by a [`Parser`]: a [`FormatToken`] whose specifier is given by its index in the
+/// scheme's rules, and whose text is given by its byte range in the format string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RawToken {
+    Specifier(usize),
+    FixedWidth {
+        spec: usize,
+        width: usize,
+    },
+    /// The labels are `None` if the specifier has no argument, so it has the default ones.
+    PreRelease {
+        spec: usize,
+        labels: Option<(usize, usize)>,
+    },
+    OptionalStart,
+    OptionalEnd,
+    Literal {
+        start: usize,
+        end: usize,
+    },
+}
+
+impl RawToken {
+    /// Returns the token this is in `format_str`, which was parsed with `rules`.
+    fn into_token<'fs, S: Scheme>(
+        self,
+        rules: SchemeRules<'_, S::Specifier>,
+        format_str: &'fs str,
+    ) -> FormatToken<'fs, S> {
+        let specs = rules.specifiers;
+        match self {
+            RawToken::Specifier(spec) => FormatToken::Specifier(specs[spec].spec),
+            RawToken::FixedWidth { spec, width } => FormatToken::FixedWidth {
+                spec: specs[spec].spec,
+                width,
+            },
+            RawToken::PreRelease { spec, labels } => FormatToken::PreRelease {
+                spec: specs[spec].spec,
+                labels: match labels {
+                    Some((start, end)) => PreReleaseLabels::Shared(format_str[start..end].into()),
+                    None => PreReleaseLabels::default(),
+                },
+            },
+            RawToken::OptionalStart => FormatToken::OptionalStart,
+            RawToken::OptionalEnd => FormatToken::OptionalEnd,
+            RawToken::Literal { start, end } => {
+                FormatToken::Literal(Cow::Borrowed(&format_str.as_bytes()[start..end]))
+            }
+        }
+    }
+
+    /// The `const` version of [`Self::into_token`], for a `'static` format string whose
+    /// pre-release labels can be borrowed.
+    const fn into_static_token<S: Scheme>(
+        self,
+        rules: SchemeRules<'_, S::Specifier>,
+        format_str: &'static str,
+    ) -> FormatToken<'static, S> {
+        let specs = rules.specifiers;
+        let format = format_str.as_bytes();
+        match self {
+            RawToken::Specifier(spec) => FormatToken::Specifier(specs[spec].spec),
+            RawToken::FixedWidth { spec, width } => FormatToken::FixedWidth {
+                spec: specs[spec].spec,
+                width,
+            },
+            RawToken::PreRelease { spec, labels } => FormatToken::PreRelease {
+                spec: specs[spec].spec,
+                labels: match labels {
+                    Some((start, end)) => match str::from_utf8(byte_range(format, start, end)) {
+                        Ok(labels) => PreReleaseLabels::Static(labels),
+                        Err(_) => panic!("labels should be ASCII"),
+                    },
+                    None => PreReleaseLabels::Static(PRE_RELEASE_DEFAULT_LABELS),
+                },
+            },
+            RawToken::OptionalStart => FormatToken::OptionalStart,
+            RawToken::OptionalEnd => FormatToken::OptionalEnd,
+            RawToken::Literal { start, end } => {
+                FormatToken::Literal(Cow::Borrowed(byte_range(format, start, end)))
+            }
+        }
+    }
+}
+
+/// Reads the tokens of a format string one at a time, checking them with the rules of its scheme.
+///
+/// It only reads data, so it runs in `const` contexts: [`format_str!`](crate::format_str) uses it
+/// to make formats at compile time, and [`Format::parse`] to make them at runtime.
+struct Parser<'r, 'fs, Sp: 'static> {
+    rules: SchemeRules<'r, Sp>,
+    format: &'fs [u8],
+    at: usize,
+    last_spec: Option<usize>,
+    /// Where the optional segment being read starts, and whether it has a specifier yet.
+    optional: Option<(usize, bool)>,
+}
+
+impl<'r, 'fs, Sp> Parser<'r, 'fs, Sp> {
+    const fn new(rules: SchemeRules<'r, Sp>, format: &'fs [u8]) -> Result<Self, ParseError> {
+        let specs = rules.specifiers;
+        if specs.len() > MAX_SPECIFIER_RULES {
+            return Err(ParseError::TooManySpecifiers { count: specs.len() });
+        }
+        // custom schemes define their own patterns, so make sure they can be matched safely
+        let mut spec = 0;
+        while spec < specs.len() {
+            if !is_valid_pattern(specs[spec].pattern) {
+                return Err(ParseError::InvalidSpecifierPattern { spec });
+            }
+            spec += 1;
+        }
+        Ok(Self {
+            rules,
+            format,
+            at: 0,
+            last_spec: None,
+            optional: None,
+        })
+    }
+
+    /// Returns the next token, or `None` at the end of the format string if it is complete.
+    const fn next_token(&mut self) -> Result<Option<RawToken>, ParseError> {
+        let start = self.at;
+        if start == self.format.len() {
+            return match self.finish() {
+                Ok(()) => Ok(None),
+                Err(err) => Err(err),
+            };
+        }
+
+        let token = if let Some((spec, arg, len)) = self.match_specifier(start) {
+            self.at += len;
+            match self.specifier_token(spec, arg) {
+                Ok(token) => token,
+                Err(err) => return Err(err),
+            }
+        } else if self.is_optional_start(start) {
+            if let Some((outer_start, _)) = self.optional {
+                return Err(ParseError::NestedOptional { start: outer_start });
+            }
+            self.optional = Some((start, false));
+            self.at += 1;
+            RawToken::OptionalStart
+        } else if self.is_optional_end(start) {
+            if let Some((optional_start, false)) = self.optional {
+                return Err(ParseError::NoSpecifiersInOptional {
+                    start: optional_start,
+                    end: start + 1,
                 });
             }
+            self.optional = None;
+            self.at += 1;
+            RawToken::OptionalEnd
+        } else if self.literal_len(start) > 0 {
+            // take all the literal text up to the next token, which cuts down on the number of
+            // tokens and therefore, regex groups later.
+            let mut len = self.literal_len(start);
+            while len > 0 {
+                self.at += len;
+                len = self.literal_len(self.at);
+            }
+            RawToken::Literal {
+                start,
+                end: self.at,
+            }
         } else {
-            return Err(FormatError::NoSpecifiersInFormat);
+            // an opening bracket, but no known specifier. we technically don't need to error
+            // here: could just parse this as a literal, but this helps the user.
+            return Err(match position(self.format, start + 1, b'>') {
+                Some(closing_index) => ParseError::UnacceptableSpecifier {
+                    start,
+                    end: closing_index + 1,
+                },
+                None => ParseError::UnterminatedSpecifier { start },
+            });
+        };
+        Ok(Some(token))
+    }
+
+    /// Checks that the format string is complete after its last token.
+    const fn finish(&self) -> Result<(), ParseError> {
+        if let Some((start, _)) = self.optional {
+            return Err(ParseError::UnterminatedOptional { start });
+        }
+        match self.last_spec {
+            Some(spec) if !self.rules.specifiers[spec].last => Err(ParseError::Incomplete { spec }),
+            Some(_) => Ok(()),
+            None => Err(ParseError::NoSpecifiersInFormat),
         }
+    }
 
-        Ok(Self { tokens })
+    /// Finds the specifier at `at`, written with or without an argument, such as `<PATCH>`,
+    /// `<PATCH:3>` or `<PRE:alpha|beta|rc>`. Returns its index, the byte range of its argument if
+    /// it has one, and the number of bytes it takes up.
+    #[allow(clippy::type_complexity)]
+    const fn match_specifier(&self, at: usize) -> Option<(usize, Option<(usize, usize)>, usize)> {
+        let specs = self.rules.specifiers;
+        let mut spec = 0;
+        while spec < specs.len() {
+            if has_at(self.format, at, specs[spec].pattern) {
+                return Some((spec, None, specs[spec].pattern.len()));
+            }
+            spec += 1;
+        }
+
+        let mut spec = 0;
+        while spec < specs.len() {
+            let rules = &specs[spec];
+            // only pre-release specifiers (labels) and digit specifiers (widths) take an argument
+            if rules.pre_release || rules.min_width.is_some() {
+                // the pattern without its closing bracket, then a colon
+                let (name, _) = rules.pattern.split_at(rules.pattern.len() - 1);
+                if has_at(self.format, at, name) && has_at(self.format, at + name.len(), b":") {
+                    let arg_start = at + name.len() + 1;
+                    return match position(self.format, arg_start, b'>') {
+                        Some(closing_index) => Some((
+                            spec,
+                            Some((arg_start, closing_index)),
+                            closing_index + 1 - at,
+                        )),
+                        None => None,
+                    };
+                }
+            }
+            spec += 1;
+        }
+        None
+    }
+
+    /// Checks the specifier at index `spec`, with the byte range of its argument if it has one,
+    /// where it is in the format string, and returns its token.
+    const fn specifier_token(
+        &mut self,
+        spec: usize,
+        arg: Option<(usize, usize)>,
+    ) -> Result<RawToken, ParseError> {
+        let specs = self.rules.specifiers;
+        let rules = &specs[spec];
+        match self.last_spec {
+            Some(prev) if !specs[prev].is_followed_by(spec) => {
+                return Err(ParseError::SpecifiersMustStepDecrease { prev, next: spec });
+            }
+            None if !rules.first => return Err(ParseError::WrongFirstSpecifier { spec }),
+            _ => {}
+        }
+        self.last_spec = Some(spec);
+
+        let token = if rules.pre_release {
+            if let Some((start, end)) = arg {
+                if !are_valid_labels(byte_range(self.format, start, end)) {
+                    return Err(ParseError::InvalidPreReleaseLabels { start, end });
+                }
+            }
+            RawToken::PreRelease { spec, labels: arg }
+        } else if let (Some((start, end)), Some(min_width)) = (arg, rules.min_width) {
+            match parse_width(byte_range(self.format, start, end)) {
+                Some(width) if width >= min_width => RawToken::FixedWidth { spec, width },
+                _ => {
+                    return Err(ParseError::InvalidWidth {
+                        start,
+                        end,
+                        min_width,
+                    })
+                }
+            }
+        } else {
+            RawToken::Specifier(spec)
+        };
+
+        if let Some((start, _)) = self.optional {
+            if !rules.optional {
+                return Err(ParseError::UnacceptableOptionalSpecifier { spec });
+            }
+            self.optional = Some((start, true));
+        }
+        Ok(token)
+    }
+
+    const fn is_optional_start(&self, at: usize) -> bool {
+        has_at(self.format, at, b"[") && !has_at(self.format, at, b"[[")
+    }
+
+    const fn is_optional_end(&self, at: usize) -> bool {
+        self.optional.is_some() && has_at(self.format, at, b"]") && !has_at(self.format, at, b"]]")
+    }
+
+    /// Returns the number of bytes of literal text at `at`: two for an escaped bracket, one for any
+    /// other text, or zero if there is none.
+    const fn literal_len(&self, at: usize) -> usize {
+        if at == self.format.len()
+            || self.match_specifier(at).is_some()
+            || self.is_optional_start(at)
+            || self.is_optional_end(at)
+        {
+            0
+        } else if has_at(self.format, at, b"<<")
+            || has_at(self.format, at, b"[[")
+            || has_at(self.format, at, b"]]")
+        {
+            2
+        } else if has_at(self.format, at, b"<") {
+            0
+        } else {
+            1
+        }
+    }
+}
+
+/// Returns the rules that [`format_str!`](crate::format_str) checks formats of `S` with.
+const fn static_rules<S: Scheme>() -> SchemeRules<'static, S::Specifier> {
+    match S::RULES {
+        Some(rules) => rules,
+        None => panic!("scheme should have `Scheme::RULES` to make formats at compile time"),
+    }
+}
+
+/// Panics with the message of `err` in `format`. In a `const` context, this is a compile error.
+const fn fail<Sp>(err: ParseError, rules: SchemeRules<'_, Sp>, format: &[u8]) -> ! {
+    let message = Message::new(err, rules, format);
+    panic!("{}", message.as_str())
+}
+
+/// Returns the number of tokens of `format_str` in the scheme `S`, for
+/// [`format_str!`](crate::format_str).
+///
+/// # Panics
+///
+/// Panics with the message of the [`FormatError`] that parsing it would return, if it is invalid.
+#[doc(hidden)]
+#[must_use]
+pub const fn token_count<S: Scheme>(format_str: &str) -> usize {
+    let rules = static_rules::<S>();
+    let format = format_str.as_bytes();
+    let mut parser = match Parser::new(rules, format) {
+        Ok(parser) => parser,
+        Err(err) => fail(err, rules, format),
+    };
+    let mut count = 0;
+    loop {
+        match parser.next_token() {
+            Ok(Some(_)) => count += 1,
+            Ok(None) => return count,
+            Err(err) => fail(err, rules, format),
+        }
+    }
+}
+
+/// The tokens of a format string, made at compile time by [`format_str!`](crate::format_str).
+#[doc(hidden)]
+pub struct StaticTokens<S: Scheme, const N: usize>([FormatToken<'static, S>; N]);
+
+impl<S: Scheme, const N: usize> StaticTokens<S, N> {
+    /// Returns the tokens of `format_str`, which has `N` of them as counted by [`token_count`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `format_str` is invalid or doesn't have `N` tokens.
+    #[must_use]
+    pub const fn new(format_str: &'static str) -> Self {
+        let rules = static_rules::<S>();
+        let format = format_str.as_bytes();
+        let mut tokens = [const { FormatToken::OptionalEnd }; N];
+        let mut parser = match Parser::new(rules, format) {
+            Ok(parser) => parser,
+            Err(err) => fail(err, rules, format),
+        };
+        let mut index = 0;
+        loop {
+            let token = match parser.next_token() {
+                Ok(Some(token)) => token.into_static_token(rules, format_str),
+                Ok(None) => break,
+                Err(err) => fail(err, rules, format),
+            };
+            // the placeholder has nothing to drop, but `const` contexts can't drop anything
+            core::mem::forget(core::mem::replace(&mut tokens[index], token));
+            index += 1;
+        }
+        assert!(index == N, "format string should have `N` tokens");
+        Self(tokens)
+    }
+
+    /// Returns the format of these tokens.
+    #[must_use]
+    pub const fn format(&'static self) -> Format<'static, S> {
+        Format {
+            tokens: Cow::Borrowed(&self.0),
+        }
+    }
+}
+
+/// Makes a [`Format`](crate::Format) from a string literal at compile time, so there is no cost to
+/// parse it at runtime. It works for the built-in schemes and for custom schemes with
+/// [`Scheme::RULES`](crate::Scheme::RULES), and can be used in a `const`.
+///
+/// An invalid format is a compile error with the message of the
+/// [`FormatError`](crate::FormatError) that [`Scheme::new_format`](crate::Scheme::new_format)
+/// would return.
+///
+/// # Example
+///
+/// ```
+/// use nextver::prelude::*;
+///
+/// const FORMAT: Format<CalSem> = nextver::format_str!(CalSem, "<YYYY>.<0M>.<PATCH>");
+///
+/// let version = FORMAT.new_version("2024.09.3")?;
+/// assert_eq!(Some(3), version.get(CalSemLevel::Patch));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// An invalid format does not compile:
+///
+/// ```compile_fail
+/// // error: specifiers must step decrease by their significance, got `<DD>` after `<YYYY>`
+/// let format = nextver::format_str!(nextver::Cal, "<YYYY>.<DD>");
+/// ```
+#[macro_export]
+macro_rules! format_str {
+    ($scheme:ty, $format_str:expr $(,)?) => {{
+        const FORMAT_STR: &str = $format_str;
+        const LEN: usize = $crate::__private::token_count::<$scheme>(FORMAT_STR);
+        const TOKENS: &$crate::__private::StaticTokens<$scheme, LEN> =
+            &$crate::__private::StaticTokens::new(FORMAT_STR);
+        TOKENS.format()
+    }};
+}
+
+/// A Format describes the structure of a version, comprised of *specifiers* and *literal text*.
+///
+/// Later, the `Format` can be used to parse a version string into a
+/// [`Version`](crate::version::Version) struct.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Format<'fs, S: Scheme> {
+    pub(crate) tokens: Cow<'fs, [FormatToken<'fs, S>]>,
+}
+
+impl<'fs, S: Scheme> Format<'fs, S> {
+    pub(crate) fn parse(format_str: &'fs str) -> Result<Self, FormatError> {
+        let derived_specs;
+        let rules = if let Some(rules) = S::RULES {
+            rules
+        } else {
+            derived_specs = SpecifierRules::derive_all();
+            SchemeRules::new(S::name(), &derived_specs)
+        };
+        let into_error = |err: ParseError| err.into_format_error(rules, format_str);
+
+        let mut parser = Parser::new(rules, format_str.as_bytes()).map_err(into_error)?;
+        let mut tokens = Vec::with_capacity(S::MAX_TOKENS);
+        while let Some(token) = parser.next_token().map_err(into_error)? {
+            tokens.push(token.into_token(rules, format_str));
+        }
+        Ok(Self {
+            tokens: Cow::Owned(tokens),
+        })
     }
 
     /// Parses a version string with this format and return a [`Version`] object.
@@ -243,12 +1214,208 @@ impl<'fs, S: Scheme> Format<'fs, S> {
     ///
     /// - If the version string does not match the format string, returns a
     ///   [`VersionError::VersionFormatMismatch`].
+    /// - If a value in the version string is greater than the greatest value of its specifier, like
+    ///   [`SpecValue::MAX`](crate::SpecValue) or `4` for `<Q>`, returns a
+    ///   [`VersionError::ValueOutOfRange`].
     pub fn new_version<'vs>(&self, version_str: &'vs str) -> Result<Version<'vs, S>, VersionError> {
         Version::parse(version_str, self)
     }
+
+    /// Parse a requirement string against this format, returning a [`Requirement`] that can check
+    /// whether versions of this format satisfy it. See [`Requirement`] for the syntax.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nextver::prelude::*;
+    ///
+    /// let format = Sem::new_format("<MAJOR>.<MINOR>.<PATCH>")?;
+    /// let requirement = format.new_requirement("^1.4")?;
+    /// assert!(requirement.matches(&format.new_version("1.7.2")?));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - If a comparator has no version, returns a [`RequirementError::EmptyComparator`].
+    /// - If the version of a comparator does not match the start of this format, returns a
+    ///   [`RequirementError::ComparatorFormatMismatch`].
+    pub fn new_requirement(
+        &self,
+        requirement_str: &str,
+    ) -> Result<Requirement<S>, RequirementError> {
+        Requirement::parse(requirement_str, self)
+    }
+
+    /// Returns this format with its literal text owned instead of borrowed from the format string,
+    /// so that it can outlive that string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nextver::prelude::*;
+    ///
+    /// fn read_format() -> Format<'static, Sem> {
+    ///     let format_str = String::from("v<MAJOR>.<MINOR>");
+    ///     Sem::new_format(&format_str).unwrap().into_owned()
+    /// }
+    ///
+    /// assert_eq!("v<MAJOR>.<MINOR>", read_format().to_string());
+    /// ```
+    #[must_use]
+    pub fn into_owned(self) -> Format<'static, S> {
+        Format {
+            tokens: self
+                .tokens
+                .into_owned()
+                .into_iter()
+                .map(FormatToken::into_owned)
+                .collect(),
+        }
+    }
+
+    /// Returns whether `spec` is in this format, including in its optional segments.
+    pub(crate) fn contains(&self, spec: &S::Specifier) -> bool {
+        self.tokens.iter().any(|token| match token {
+            FormatToken::Specifier(this)
+            | FormatToken::FixedWidth { spec: this, .. }
+            | FormatToken::PreRelease { spec: this, .. } => *this == spec,
+            FormatToken::OptionalStart | FormatToken::OptionalEnd | FormatToken::Literal(_) => {
+                false
+            }
+        })
+    }
+}
+
+impl<'fs> Format<'fs, Sem> {
+    /// Returns a [`VersionBuilder`] to make a version of this format from values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nextver::prelude::*;
+    ///
+    /// let format = Sem::new_format("<MAJOR>.<MINOR>.<PATCH>")?;
+    /// let version = format.builder().value(SemLevel::Major, 2).build()?;
+    /// assert_eq!("2.0.0", version.to_string());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn builder(&self) -> VersionBuilder<'_, 'fs, Sem> {
+        VersionBuilder::new(self, None, |_, _| None)
+    }
+
+    /// Returns the first version of this format, where `<MINOR>` is one and all other values are
+    /// zero, like `0.1.0`. (If there is no `<MINOR>`, `<MAJOR>` is one instead.)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nextver::prelude::*;
+    ///
+    /// let format = Sem::new_format("<MAJOR>.<MINOR>.<PATCH><PRE>")?;
+    /// assert_eq!("0.1.0", format.initial_version()?.to_string());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`VersionError::ValueOverflowsWidth`] if a fixed width is too small for one.
+    pub fn initial_version(&self) -> Result<Version<'static, Sem>, VersionError> {
+        let level = if self.contains(&SEM_MINOR) {
+            SemLevel::Minor
+        } else {
+            SemLevel::Major
+        };
+        self.builder().value(level, 1).build()
+    }
+}
+
+impl<'fs> Format<'fs, Cal> {
+    /// Returns a [`VersionBuilder`] to make a version of this format from values, where the values
+    /// of date specifiers come from `date`, which may be a [`Date`](crate::Date) or a
+    /// [`DateTime`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nextver::prelude::*;
+    ///
+    /// let format = Cal::new_format("<EPOCH>!<YYYY>.<0M>")?;
+    /// let date = Date::explicit(2024, 10, 18)?;
+    /// let version = format.builder(date).epoch(1).build()?;
+    /// assert_eq!("1!2024.10", version.to_string());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn builder(&self, date: impl Into<DateTime>) -> VersionBuilder<'_, 'fs, Cal> {
+        let date = date.into().as_naive_date_time();
+        VersionBuilder::new(self, Some(date), |spec, date| match spec {
+            CalSpecifier::Epoch | CalSpecifier::Build | CalSpecifier::Metadata => None,
+            _ => Some(spec.next_value(0, date)),
+        })
+    }
+
+    /// Returns the first version of this format, where the values of date specifiers come from
+    /// `date`, and all other values are zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`VersionError::NegativeYearValue`] if the year of `date` would be negative.
+    pub fn initial_version(
+        &self,
+        date: impl Into<DateTime>,
+    ) -> Result<Version<'static, Cal>, VersionError> {
+        self.builder(date).build()
+    }
+}
+
+impl<'fs> Format<'fs, CalSem> {
+    /// Returns a [`VersionBuilder`] to make a version of this format from values, where the values
+    /// of date specifiers come from `date`, which may be a [`Date`](crate::Date) or a
+    /// [`DateTime`].
+    pub fn builder(&self, date: impl Into<DateTime>) -> VersionBuilder<'_, 'fs, CalSem> {
+        let date = date.into().as_naive_date_time();
+        VersionBuilder::new(self, Some(date), |spec, date| match spec {
+            CalSemSpecifier::Cal(cal_spec) => Some(cal_spec.next_value(date)),
+            CalSemSpecifier::Sem(_) => None,
+        })
+    }
+
+    /// Returns the first version of this format, where the values of date specifiers come from
+    /// `date`, and all other values are zero, like `2024.10.0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nextver::prelude::*;
+    ///
+    /// let format = CalSem::new_format("<YYYY>.<MM>.<PATCH>")?;
+    /// let date = Date::explicit(2024, 10, 18)?;
+    /// assert_eq!("2024.10.0", format.initial_version(date)?.to_string());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`VersionError::NegativeYearValue`] if the year of `date` would be negative.
+    pub fn initial_version(
+        &self,
+        date: impl Into<DateTime>,
+    ) -> Result<Version<'static, CalSem>, VersionError> {
+        self.builder(date).build()
+    }
 }
 
-impl<'fs, S: Scheme> Display for Format<'fs, S> {
+/// Returns the text of a literal in a format string, with its escaped brackets unescaped.
+pub(crate) fn unescape_literal(literal: &[u8]) -> String {
+    unsafe { str::from_utf8_unchecked(literal) }
+        .replace("<<", "<")
+        .replace(">>", ">")
+        .replace("[[", "[")
+        .replace("]]", "]")
+}
+
+impl<S: Scheme> Display for Format<'_, S> {
     /// Display a format as a format string.
     ///
     /// # Example
@@ -261,27 +1428,62 @@ impl<'fs, S: Scheme> Display for Format<'fs, S> {
     /// assert_eq!(format_str, format.to_string());
     /// ```
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        for token in &self.tokens {
+        for token in self.tokens.iter() {
             f.write_str(&token.to_string())?;
         }
         Ok(())
     }
 }
 
+#[cfg(feature = "serde")]
+impl<S: Scheme> serde::Serialize for Format<'_, S> {
+    /// Serializes a format as its format string.
+    fn serialize<Ser: serde::Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, S: Scheme> serde::Deserialize<'de> for Format<'static, S> {
+    /// Deserializes a format from a format string.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let format_str = String::deserialize(deserializer)?;
+        Format::parse(&format_str)
+            .map(Format::into_owned)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
         scheme::{Cal, CalSem, Sem},
         specifier::{
-            CALSEM_MINOR, CALSEM_MONTH_SHORT, CALSEM_WEEK_SHORT, CALSEM_YEAR_FULL, CAL_DAY_SHORT,
-            CAL_MONTH_SHORT, CAL_WEEK_SHORT, CAL_YEAR_FULL, SEM_MAJOR, SEM_MINOR, SEM_PATCH,
+            CALSEM_FIRST, CALSEM_ISO_WEEK_ZERO_PADDED, CALSEM_ISO_YEAR, CALSEM_LAST,
+            CALSEM_METADATA, CALSEM_MINOR, CALSEM_MONTH_SHORT, CALSEM_PRE_RELEASE,
+            CALSEM_WEEK_SHORT, CALSEM_WEEK_ZERO_PADDED, CALSEM_YEAR_FULL, CAL_DAY_OF_YEAR_SHORT,
+            CAL_DAY_SHORT, CAL_FIRST, CAL_HOUR_SHORT, CAL_ISO_WEEK_SHORT, CAL_ISO_YEAR,
+            CAL_MINUTE_SHORT, CAL_MONTH_ABBREVIATED, CAL_MONTH_FULL, CAL_MONTH_SHORT, CAL_QUARTER,
+            CAL_WEEK_SHORT, CAL_YEAR_FULL, SEM_BUILD, SEM_EPOCH, SEM_FIRST, SEM_LAST, SEM_MAJOR,
+            SEM_METADATA, SEM_MINOR, SEM_PATCH, SEM_PRE_RELEASE, SEM_REVISION,
         },
     };
     use itertools::Itertools;
     use rstest::*;
     use std::iter;
 
+    /// Returns `specs` as listed in error messages, like `` `a`, `b`, or `c` ``.
+    fn expected_list<Sp: Specifier>(specs: &[&Sp]) -> String {
+        let patterns = specs.iter().map(|spec| format!("`{spec}`")).collect_vec();
+        match patterns.as_slice() {
+            [] => String::new(),
+            [only] => only.clone(),
+            [first, second] => format!("{first} or {second}"),
+            [rest @ .., last] => format!("{}, or {last}", rest.join(", ")),
+        }
+    }
+
     #[fixture]
     fn literal_parts() -> [&'static str; 3] {
         static LITERAL_PARTS: [&str; 3] = [
@@ -324,9 +1526,25 @@ mod tests {
     /// - `<MAJOR>`
     /// - `<MAJOR>`, `<MINOR>`
     /// - `<MAJOR>`, `<MINOR>`, `<PATCH>`
+    /// - `<MAJOR>`, `<MINOR>`, `<PATCH>`, `<REVISION>`
     #[test]
     fn test_sem_parse_ok() {
-        let format_strings = ["<MAJOR><MINOR><PATCH>", "<MAJOR><MINOR>", "<MAJOR>"];
+        let format_strings = [
+            "<MAJOR><MINOR><PATCH>",
+            "<MAJOR><MINOR>",
+            "<MAJOR>",
+            "<MAJOR><MINOR><PATCH><PRE>",
+            "<MAJOR><MINOR><PRE>",
+            "<MAJOR><PRE>",
+            "<MAJOR><MINOR><PATCH><PRE><META>",
+            "<MAJOR><MINOR><META>",
+            "<MAJOR><META>",
+            "<MAJOR><MINOR><PATCH><BUILD>",
+            "<MAJOR><MINOR><PATCH><PRE><BUILD><META>",
+            "<MAJOR><BUILD>",
+            "<MAJOR><MINOR><PATCH><REVISION>",
+            "<MAJOR><MINOR><PATCH><REVISION><PRE><BUILD><META>",
+        ];
 
         for format_string in format_strings {
             let actual = Sem::new_format(format_string);
@@ -342,12 +1560,27 @@ mod tests {
     /// - `[<year>]`, `[<month>]`
     /// - `[<year>]`, `[<month>]`, `[<day>]`
     /// - `[<year>]`, `[<week>]`
+    /// - `[<year>]`, `<Q>`
+    /// - `[<year>]`, `<Q>`, `[<month>]`
+    /// - `[<year>]`, `<Q>`, `[<month>]`, `[<day>]`
+    /// - `[<year>]`, `[<day of year>]`
+    /// - `[<year>]`, `[<month>]`, `[<day>]`, `[<hour>]`
+    /// - `[<year>]`, `[<month>]`, `[<day>]`, `[<hour>]`, `[<minute>]`
+    /// - `[<year>]`, `[<day of year>]`, `[<hour>]`, `[<minute>]`
+    /// - `<GGGG>`
+    /// - `<GGGG>`, `[<iso week>]`
     #[fixture]
     fn all_valid_cal_specs_product() -> impl Iterator<Item = Vec<&'static str>> {
         let years = || iter::once(vec!["<YYYY>", "<YY>", "<0Y>"]);
-        let months = || iter::once(vec!["<MM>", "<0M>"]);
+        let months = || iter::once(vec!["<MM>", "<0M>", "<MON>", "<Month>"]);
         let weeks = || iter::once(vec!["<WW>", "<0W>"]);
         let days = || iter::once(vec!["<DD>", "<0D>"]);
+        let quarters = || iter::once(vec!["<Q>"]);
+        let days_of_year = || iter::once(vec!["<DOY>", "<0DOY>"]);
+        let hours = || iter::once(vec!["<HH>", "<0H>"]);
+        let minutes = || iter::once(vec!["<MI>", "<0MI>"]);
+        let iso_years = || iter::once(vec!["<GGGG>"]);
+        let iso_weeks = || iter::once(vec!["<IW>", "<0IW>"]);
 
         let years_product = years().multi_cartesian_product();
         let years_months_product = years().chain(months()).multi_cartesian_product();
@@ -356,11 +1589,49 @@ mod tests {
             .chain(days())
             .multi_cartesian_product();
         let years_weeks_product = years().chain(weeks()).multi_cartesian_product();
+        let years_quarters_product = years().chain(quarters()).multi_cartesian_product();
+        let years_quarters_months_product = years()
+            .chain(quarters())
+            .chain(months())
+            .multi_cartesian_product();
+        let years_quarters_months_days_product = years()
+            .chain(quarters())
+            .chain(months())
+            .chain(days())
+            .multi_cartesian_product();
+        let years_days_of_year_product = years().chain(days_of_year()).multi_cartesian_product();
+        let years_months_days_hours_product = years()
+            .chain(months())
+            .chain(days())
+            .chain(hours())
+            .multi_cartesian_product();
+        let years_months_days_hours_minutes_product = years()
+            .chain(months())
+            .chain(days())
+            .chain(hours())
+            .chain(minutes())
+            .multi_cartesian_product();
+        let years_days_of_year_hours_minutes_product = years()
+            .chain(days_of_year())
+            .chain(hours())
+            .chain(minutes())
+            .multi_cartesian_product();
+        let iso_years_product = iso_years().multi_cartesian_product();
+        let iso_years_weeks_product = iso_years().chain(iso_weeks()).multi_cartesian_product();
 
         years_product
             .chain(years_months_product)
             .chain(years_months_days_product)
             .chain(years_weeks_product)
+            .chain(years_quarters_product)
+            .chain(years_quarters_months_product)
+            .chain(years_quarters_months_days_product)
+            .chain(years_days_of_year_product)
+            .chain(years_months_days_hours_product)
+            .chain(years_months_days_hours_minutes_product)
+            .chain(years_days_of_year_hours_minutes_product)
+            .chain(iso_years_product)
+            .chain(iso_years_weeks_product)
     }
 
     #[rstest]
@@ -369,6 +1640,13 @@ mod tests {
             let format_string = &spec_sequence.join("");
             let actual = Cal::new_format(format_string);
             assert_eq!(Ok(format_string), actual.map(|f| f.to_string()).as_ref());
+
+            // and with a build number and/or metadata at the end
+            for suffix in ["<BUILD>", "<META>", "<BUILD><META>"] {
+                let format_string = &format!("{format_string}{suffix}");
+                let actual = Cal::new_format(format_string);
+                assert_eq!(Ok(format_string), actual.map(|f| f.to_string()).as_ref());
+            }
         }
     }
 
@@ -380,21 +1658,63 @@ mod tests {
     /// - `[<year>]`, `[<month>]`, REST
     /// - `[<year>]`, `[<month>]`, `[<day>]`, REST
     /// - `[<year>]`, `[<week>]`, REST
+    /// - `[<year>]`, `<Q>`, REST
+    /// - `[<year>]`, `<Q>`, `[<month>]`, REST
+    /// - `[<year>]`, `<Q>`, `[<month>]`, `[<day>]`, REST
+    /// - `[<year>]`, `[<day of year>]`, REST
+    /// - `[<year>]`, `[<month>]`, `[<day>]`, `[<hour>]`, REST
+    /// - `[<year>]`, `[<month>]`, `[<day>]`, `[<hour>]`, `[<minute>]`, REST
+    /// - `[<year>]`, `[<day of year>]`, `[<hour>]`, `[<minute>]`, REST
+    /// - `<GGGG>`, REST
+    /// - `<GGGG>`, `[<iso week>]`, REST
     ///
     /// where REST is either:
     ///
     /// - `<MINOR>`, `<PATCH>`
     /// - `<PATCH>`
+    /// - `<PATCH>`, `<PRE>`
+    /// - `<PATCH>`, `<META>`
+    /// - `<PATCH>`, `<PRE>`, `<META>`
+    /// - `<PATCH>`, `<BUILD>`
+    /// - `<PATCH>`, `<PRE>`, `<BUILD>`, `<META>`
+    /// - `<MINOR>`, `<PATCH>`, `<REVISION>`
+    /// - `<PATCH>`, `<REVISION>`, `<PRE>`
     #[fixture]
     fn all_valid_calsem_specs_product(
         all_valid_cal_specs_product: impl Iterator<Item = Vec<&'static str>>,
     ) -> impl Iterator<Item = Vec<&'static str>> {
         all_valid_cal_specs_product
-            // augment each of these products with the three possible semantic suffix combinations
+            // augment each of these products with the possible semantic suffix combinations
             .flat_map(|iter| {
                 vec![
                     [iter.clone(), vec!["<MINOR>"], vec!["<PATCH>"]].concat(),
-                    [iter, vec!["<PATCH>"]].concat(),
+                    [iter.clone(), vec!["<PATCH>"]].concat(),
+                    [iter.clone(), vec!["<PATCH>"], vec!["<PRE>"]].concat(),
+                    [iter.clone(), vec!["<PATCH>"], vec!["<META>"]].concat(),
+                    [iter.clone(), vec!["<PATCH>"], vec!["<PRE>"], vec!["<META>"]].concat(),
+                    [iter.clone(), vec!["<PATCH>"], vec!["<BUILD>"]].concat(),
+                    [
+                        iter.clone(),
+                        vec!["<MINOR>"],
+                        vec!["<PATCH>"],
+                        vec!["<REVISION>"],
+                    ]
+                    .concat(),
+                    [
+                        iter.clone(),
+                        vec!["<PATCH>"],
+                        vec!["<REVISION>"],
+                        vec!["<PRE>"],
+                    ]
+                    .concat(),
+                    [
+                        iter,
+                        vec!["<PATCH>"],
+                        vec!["<PRE>"],
+                        vec!["<BUILD>"],
+                        vec!["<META>"],
+                    ]
+                    .concat(),
                 ]
             })
     }
@@ -413,7 +1733,6 @@ mod tests {
     #[test]
     fn test_bad_sem_format() {
         use super::FormatError::*;
-        use crate::scheme::priv_trait::Scheme;
 
         // not exhaustive, just a sample
         let args = [
@@ -424,28 +1743,63 @@ mod tests {
                 WrongFirstSpecifier {
                     first_spec: SEM_MINOR.to_string(),
                     scheme_name: Sem::name(),
-                    expected_first: Sem::first_variants_string(),
+                    expected_first: expected_list(SEM_FIRST),
+                },
+            ),
+            (
+                "<MAJOR><MAJOR>",
+                SpecifiersMustStepDecrease {
+                    prev: SEM_MAJOR.to_string(),
+                    next: SEM_MAJOR.to_string(),
+                },
+            ),
+            (
+                "<MAJOR><PATCH>",
+                SpecifiersMustStepDecrease {
+                    prev: SEM_MAJOR.to_string(),
+                    next: SEM_PATCH.to_string(),
+                },
+            ),
+            (
+                "<MAJOR><MINOR><REVISION>",
+                SpecifiersMustStepDecrease {
+                    prev: SEM_MINOR.to_string(),
+                    next: SEM_REVISION.to_string(),
+                },
+            ),
+            (
+                "<MAJOR><YYYY>",
+                UnacceptableSpecifier {
+                    spec: "<YYYY>".to_string(),
+                    scheme_name: Sem::name(),
+                },
+            ),
+            (
+                "<MAJOR><PRE><MINOR>",
+                SpecifiersMustStepDecrease {
+                    prev: SEM_PRE_RELEASE.to_string(),
+                    next: SEM_MINOR.to_string(),
                 },
             ),
             (
-                "<MAJOR><MAJOR>",
+                "<MAJOR><BUILD><MINOR>",
                 SpecifiersMustStepDecrease {
-                    prev: SEM_MAJOR.to_string(),
-                    next: SEM_MAJOR.to_string(),
+                    prev: SEM_BUILD.to_string(),
+                    next: SEM_MINOR.to_string(),
                 },
             ),
             (
-                "<MAJOR><PATCH>",
+                "<MAJOR><META><BUILD>",
                 SpecifiersMustStepDecrease {
-                    prev: SEM_MAJOR.to_string(),
-                    next: SEM_PATCH.to_string(),
+                    prev: SEM_METADATA.to_string(),
+                    next: SEM_BUILD.to_string(),
                 },
             ),
             (
-                "<MAJOR><YYYY>",
-                UnacceptableSpecifier {
-                    spec: "<YYYY>".to_string(),
-                    scheme_name: Sem::name(),
+                "<MAJOR><META><PRE>",
+                SpecifiersMustStepDecrease {
+                    prev: SEM_METADATA.to_string(),
+                    next: SEM_PRE_RELEASE.to_string(),
                 },
             ),
             (
@@ -463,9 +1817,9 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::too_many_lines)]
     fn test_bad_cal_format() {
         use super::FormatError::*;
-        use crate::scheme::priv_trait::Scheme;
 
         // not exhaustive, just a sample
         let args = [
@@ -476,7 +1830,7 @@ mod tests {
                 WrongFirstSpecifier {
                     first_spec: CAL_MONTH_SHORT.to_string(),
                     scheme_name: Cal::name(),
-                    expected_first: Cal::first_variants_string(),
+                    expected_first: expected_list(CAL_FIRST),
                 },
             ),
             (
@@ -500,6 +1854,76 @@ mod tests {
                     next: CAL_DAY_SHORT.to_string(),
                 },
             ),
+            (
+                "<YYYY><IW>",
+                SpecifiersMustStepDecrease {
+                    prev: CAL_YEAR_FULL.to_string(),
+                    next: CAL_ISO_WEEK_SHORT.to_string(),
+                },
+            ),
+            (
+                "<GGGG><WW>",
+                SpecifiersMustStepDecrease {
+                    prev: CAL_ISO_YEAR.to_string(),
+                    next: CAL_WEEK_SHORT.to_string(),
+                },
+            ),
+            (
+                "<YYYY><MON><Q>",
+                SpecifiersMustStepDecrease {
+                    prev: CAL_MONTH_ABBREVIATED.to_string(),
+                    next: CAL_QUARTER.to_string(),
+                },
+            ),
+            (
+                "<YYYY><Month><WW>",
+                SpecifiersMustStepDecrease {
+                    prev: CAL_MONTH_FULL.to_string(),
+                    next: CAL_WEEK_SHORT.to_string(),
+                },
+            ),
+            (
+                "<YYYY><MM><Q>",
+                SpecifiersMustStepDecrease {
+                    prev: CAL_MONTH_SHORT.to_string(),
+                    next: CAL_QUARTER.to_string(),
+                },
+            ),
+            (
+                "<YYYY><Q><DD>",
+                SpecifiersMustStepDecrease {
+                    prev: CAL_QUARTER.to_string(),
+                    next: CAL_DAY_SHORT.to_string(),
+                },
+            ),
+            (
+                "<YYYY><MM><DOY>",
+                SpecifiersMustStepDecrease {
+                    prev: CAL_MONTH_SHORT.to_string(),
+                    next: CAL_DAY_OF_YEAR_SHORT.to_string(),
+                },
+            ),
+            (
+                "<YYYY><MM><HH>",
+                SpecifiersMustStepDecrease {
+                    prev: CAL_MONTH_SHORT.to_string(),
+                    next: CAL_HOUR_SHORT.to_string(),
+                },
+            ),
+            (
+                "<YYYY><MM><DD><MI>",
+                SpecifiersMustStepDecrease {
+                    prev: CAL_DAY_SHORT.to_string(),
+                    next: CAL_MINUTE_SHORT.to_string(),
+                },
+            ),
+            (
+                "<GGGG><MM>",
+                SpecifiersMustStepDecrease {
+                    prev: CAL_ISO_YEAR.to_string(),
+                    next: CAL_MONTH_SHORT.to_string(),
+                },
+            ),
             (
                 "<YYYY",
                 UnterminatedSpecifier {
@@ -517,7 +1941,6 @@ mod tests {
     #[test]
     fn test_bad_calsem_format() {
         use super::FormatError::*;
-        use crate::scheme::priv_trait::Scheme;
 
         // not exhaustive, just a sample
         let args = [
@@ -541,7 +1964,7 @@ mod tests {
                 Incomplete {
                     last_spec: CALSEM_YEAR_FULL.to_string(),
                     scheme_name: CalSem::name(),
-                    expected_last: CalSem::last_variants_string(),
+                    expected_last: expected_list(CALSEM_LAST),
                 },
             ),
             (
@@ -549,7 +1972,7 @@ mod tests {
                 WrongFirstSpecifier {
                     first_spec: CAL_MONTH_SHORT.to_string(),
                     scheme_name: CalSem::name(),
-                    expected_first: CalSem::first_variants_string(),
+                    expected_first: expected_list(CALSEM_FIRST),
                 },
             ),
             (
@@ -571,7 +1994,35 @@ mod tests {
                 Incomplete {
                     last_spec: CALSEM_MINOR.to_string(),
                     scheme_name: CalSem::name(),
-                    expected_last: CalSem::last_variants_string(),
+                    expected_last: expected_list(CALSEM_LAST),
+                },
+            ),
+            (
+                "<YYYY><MINOR><PRE>",
+                SpecifiersMustStepDecrease {
+                    prev: CALSEM_MINOR.to_string(),
+                    next: CALSEM_PRE_RELEASE.to_string(),
+                },
+            ),
+            (
+                "<YYYY><0IW><PATCH>",
+                SpecifiersMustStepDecrease {
+                    prev: CALSEM_YEAR_FULL.to_string(),
+                    next: CALSEM_ISO_WEEK_ZERO_PADDED.to_string(),
+                },
+            ),
+            (
+                "<GGGG><0W><PATCH>",
+                SpecifiersMustStepDecrease {
+                    prev: CALSEM_ISO_YEAR.to_string(),
+                    next: CALSEM_WEEK_ZERO_PADDED.to_string(),
+                },
+            ),
+            (
+                "<YYYY><META>",
+                SpecifiersMustStepDecrease {
+                    prev: CALSEM_YEAR_FULL.to_string(),
+                    next: CALSEM_METADATA.to_string(),
                 },
             ),
         ];
@@ -582,6 +2033,251 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_optional() {
+        use super::FormatError::*;
+
+        let args = [
+            ("<MAJOR>.<MINOR>[.<PATCH>]", Ok("<MAJOR>.<MINOR>[.<PATCH>]")),
+            (
+                "<MAJOR>[.<MINOR>][.<PATCH>]",
+                Ok("<MAJOR>[.<MINOR>][.<PATCH>]"),
+            ),
+            ("<MAJOR>[.<MINOR:2>]", Ok("<MAJOR>[.<MINOR:2>]")),
+            (
+                "<MAJOR>[.<MINOR>].<PATCH><PRE>",
+                Ok("<MAJOR>[.<MINOR>].<PATCH><PRE>"),
+            ),
+            // escaped and unmatched brackets are literals
+            ("[[<MAJOR>]", Ok("[[<MAJOR>]")),
+            ("<MAJOR>]", Ok("<MAJOR>]")),
+            ("<MAJOR>[.<MINOR>]]]", Ok("<MAJOR>[.<MINOR>]]]")),
+            ("<MAJOR>[[.<MINOR>]]", Ok("<MAJOR>[[.<MINOR>]]")),
+            // specifiers are still ordered across the brackets
+            (
+                "<MAJOR>[.<PATCH>]",
+                Err(SpecifiersMustStepDecrease {
+                    prev: SEM_MAJOR.to_string(),
+                    next: SEM_PATCH.to_string(),
+                }),
+            ),
+            (
+                "<MAJOR>.<MINOR>[.<PATCH>",
+                Err(UnterminatedOptional {
+                    pattern: "[.<PATCH>".to_string(),
+                }),
+            ),
+            (
+                "<MAJOR>[.<MINOR>[.<PATCH>]]",
+                Err(NestedOptional {
+                    pattern: "[.<MINOR>[.<PATCH>]]".to_string(),
+                }),
+            ),
+            (
+                "<MAJOR>[.]",
+                Err(NoSpecifiersInOptional {
+                    segment: "[.]".to_string(),
+                }),
+            ),
+            (
+                "<MAJOR>.<MINOR>.<PATCH>[<PRE>]",
+                Err(UnacceptableOptionalSpecifier {
+                    spec: SEM_PRE_RELEASE.to_string(),
+                }),
+            ),
+        ];
+
+        for (format_str, expected) in args {
+            let actual = Sem::new_format(format_str).map(|f| f.to_string());
+            assert_eq!(expected.map(str::to_string), actual);
+        }
+
+        // calendar specifiers that can't be zero can't be optional
+        assert_eq!(
+            Err(UnacceptableOptionalSpecifier {
+                spec: CAL_DAY_SHORT.to_string(),
+            }),
+            Cal::new_format("<YYYY>.<MM>[.<DD>]")
+        );
+        assert!(Cal::new_format("<YYYY>.<0M>.<0D>[.<BUILD>]").is_ok());
+    }
+
+    #[test]
+    fn test_epoch() {
+        use super::FormatError::*;
+
+        let args = [
+            ("<EPOCH>!<MAJOR>.<MINOR>", Ok("<EPOCH>!<MAJOR>.<MINOR>")),
+            ("[<EPOCH>!]<MAJOR>.<MINOR>", Ok("[<EPOCH>!]<MAJOR>.<MINOR>")),
+            (
+                "<EPOCH>",
+                Err(Incomplete {
+                    last_spec: SEM_EPOCH.to_string(),
+                    scheme_name: Sem::name(),
+                    expected_last: expected_list(SEM_LAST),
+                }),
+            ),
+            (
+                "<EPOCH>!<MINOR>",
+                Err(SpecifiersMustStepDecrease {
+                    prev: SEM_EPOCH.to_string(),
+                    next: SEM_MINOR.to_string(),
+                }),
+            ),
+            (
+                "<MAJOR>!<EPOCH>",
+                Err(SpecifiersMustStepDecrease {
+                    prev: SEM_MAJOR.to_string(),
+                    next: SEM_EPOCH.to_string(),
+                }),
+            ),
+        ];
+
+        for (format_str, expected) in args {
+            let actual = Sem::new_format(format_str).map(|f| f.to_string());
+            assert_eq!(expected.map(str::to_string), actual);
+        }
+
+        assert!(Cal::new_format("<EPOCH>!<YYYY>.<0M>.<0D>").is_ok());
+        assert!(Cal::new_format("<EPOCH>!<GGGG>.<IW>").is_ok());
+        assert!(Cal::new_format("<EPOCH>!<MM>").is_err());
+        assert!(CalSem::new_format("<EPOCH>!<YY>.<MM>.<PATCH>").is_ok());
+        assert!(CalSem::new_format("<EPOCH>!<MINOR>.<PATCH>").is_err());
+        assert!(CalSem::new_format("<YY>.<MM>.<PATCH>.<EPOCH>").is_err());
+    }
+
+    #[test]
+    fn test_fixed_width() {
+        use super::FormatError::*;
+
+        let args = [
+            (
+                "<MAJOR>.<MINOR:2>.<PATCH:3>",
+                Ok("<MAJOR>.<MINOR:2>.<PATCH:3>"),
+            ),
+            ("<MAJOR:1>", Ok("<MAJOR:1>")),
+            ("<MAJOR><BUILD:5>", Ok("<MAJOR><BUILD:5>")),
+            (
+                "<MAJOR>.<MINOR:0>",
+                Err(InvalidWidth {
+                    width: "0".to_string(),
+                    min_width: 1,
+                }),
+            ),
+            (
+                "<MAJOR>.<MINOR:-1>",
+                Err(InvalidWidth {
+                    width: "-1".to_string(),
+                    min_width: 1,
+                }),
+            ),
+            (
+                "<MAJOR>.<MINOR:>",
+                Err(InvalidWidth {
+                    width: String::new(),
+                    min_width: 1,
+                }),
+            ),
+            // pre-release and metadata specifiers don't take a width
+            (
+                "<MAJOR><META:3>",
+                Err(UnacceptableSpecifier {
+                    spec: "<META:3>".to_string(),
+                    scheme_name: "semantic",
+                }),
+            ),
+        ];
+
+        for (format_str, expected) in args {
+            let actual = Sem::new_format(format_str).map(|f| f.to_string());
+            assert_eq!(expected.map(str::to_string), actual);
+        }
+
+        // calendar specifiers take a width, unless they are already zero-padded or named
+        let args = [
+            ("<YYYY:4>.<MM:2>.<DD:2>", Ok("<YYYY:4>.<MM:2>.<DD:2>")),
+            ("<YY>.<DOY:3>", Ok("<YY>.<DOY:3>")),
+            ("<GGGG:5>.<IW>", Ok("<GGGG:5>.<IW>")),
+            // full years always have at least four digits
+            (
+                "<YYYY:2>.<MM>",
+                Err(InvalidWidth {
+                    width: "2".to_string(),
+                    min_width: 4,
+                }),
+            ),
+            (
+                "<GGGG:3>.<IW>",
+                Err(InvalidWidth {
+                    width: "3".to_string(),
+                    min_width: 4,
+                }),
+            ),
+            (
+                "<YYYY>.<0M:3>",
+                Err(UnacceptableSpecifier {
+                    spec: "<0M:3>".to_string(),
+                    scheme_name: "calendar",
+                }),
+            ),
+            (
+                "<YYYY>.<MON:3>",
+                Err(UnacceptableSpecifier {
+                    spec: "<MON:3>".to_string(),
+                    scheme_name: "calendar",
+                }),
+            ),
+        ];
+
+        for (format_str, expected) in args {
+            let actual = Cal::new_format(format_str).map(|f| f.to_string());
+            assert_eq!(expected.map(str::to_string), actual);
+        }
+    }
+
+    #[test]
+    fn test_pre_release_labels() {
+        use super::FormatError::*;
+
+        let args = [
+            ("<MAJOR><PRE>", Ok("<MAJOR><PRE>")),
+            ("<MAJOR><PRE:dev|rc>", Ok("<MAJOR><PRE:dev|rc>")),
+            // the default labels are written without an argument
+            ("<MAJOR><PRE:alpha|beta|rc>", Ok("<MAJOR><PRE>")),
+            (
+                "<MAJOR><PRE:dev||rc>",
+                Err(InvalidPreReleaseLabels {
+                    labels: "dev||rc".to_string(),
+                }),
+            ),
+            (
+                "<MAJOR><PRE:>",
+                Err(InvalidPreReleaseLabels {
+                    labels: String::new(),
+                }),
+            ),
+            // other specifiers take a width, not labels
+            (
+                "<MAJOR><MINOR:dev>",
+                Err(InvalidWidth {
+                    width: "dev".to_string(),
+                    min_width: 1,
+                }),
+            ),
+            (
+                "<MAJOR><PRE:dev",
+                Err(UnterminatedSpecifier {
+                    pattern: "<PRE:dev".to_string(),
+                }),
+            ),
+        ];
+
+        for (format_str, expected) in args {
+            let actual = Sem::new_format(format_str).map(|f| f.to_string());
+            assert_eq!(expected.map(str::to_string), actual);
+        }
+    }
+
     #[test]
     fn test_bracket_escape() {
         let format = r"<YYYY><<YYYY>";
@@ -589,10 +2285,9 @@ mod tests {
         assert_eq!(
             Ok(vec![
                 FormatToken::Specifier(&CAL_YEAR_FULL),
-                FormatToken::Literal(b"<<YYYY>"),
-            ])
-            .as_ref(),
-            actual.as_ref().map(|f| &f.tokens)
+                FormatToken::Literal(Cow::Borrowed(b"<<YYYY>")),
+            ]),
+            actual.as_ref().map(|f| f.tokens.to_vec())
         );
         let round_tripped_format = actual.unwrap().to_string();
         assert_eq!(format, round_tripped_format);
@@ -639,4 +2334,290 @@ mod tests {
         let format2 = CalSem::new_format("<YYYY><PATCH>").unwrap();
         assert_ne!(format1, format2);
     }
+
+    /// The patterns of [`Custom`] specifiers, by their index.
+    static CUSTOM_PATTERNS: [&[u8]; 8] = [
+        b"<NUM>",
+        b"",
+        b"<>",
+        b"NUM",
+        b"<NUM",
+        "<NÜM>".as_bytes(),
+        b"<N:UM>",
+        b"<N[UM]>",
+    ];
+
+    /// A specifier of a custom scheme with the pattern at index `N` of [`CUSTOM_PATTERNS`].
+    #[derive(Debug, PartialEq, Eq)]
+    struct Custom<const N: usize>;
+
+    impl<const N: usize> Display for Custom<N> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(&String::from_utf8_lossy(self.format_pattern()))
+        }
+    }
+
+    impl<const N: usize> Specifier for Custom<N> {
+        fn format_pattern(&self) -> &'static [u8] {
+            CUSTOM_PATTERNS[N]
+        }
+        fn format_width(&self) -> usize {
+            0
+        }
+        fn parse_width(&self) -> crate::ParseWidth {
+            crate::ParseWidth::AtLeastOne
+        }
+        fn can_be_zero(&self) -> bool {
+            true
+        }
+        fn first_variants() -> &'static [&'static Self] {
+            &[&Custom]
+        }
+        fn last_variants() -> &'static [&'static Self] {
+            &[&Custom]
+        }
+        fn all() -> &'static [&'static Self] {
+            &[&Custom]
+        }
+        fn can_be_left_adjacent_to(&self, _other: &Self) -> bool {
+            false
+        }
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct CustomScheme<const N: usize>;
+
+    impl<const N: usize> Scheme for CustomScheme<N> {
+        type Specifier = Custom<N>;
+        const MAX_SPECIFIERS: usize = 1;
+        fn name() -> &'static str {
+            "custom"
+        }
+    }
+
+    fn custom_pattern_error<const N: usize>(format_str: &str) -> Option<FormatError> {
+        CustomScheme::<N>::new_format(format_str).err()
+    }
+
+    #[test]
+    fn test_custom_specifier_patterns() {
+        assert_eq!(None, custom_pattern_error::<0>("v<NUM>"));
+        assert_eq!(None, custom_pattern_error::<0>("<NUM:2>"));
+        let errors = [
+            custom_pattern_error::<1>("v1"),
+            custom_pattern_error::<2>("v<>"),
+            custom_pattern_error::<3>("NUM"),
+            custom_pattern_error::<4>("<NUM"),
+            custom_pattern_error::<5>("<NÜM>"),
+            custom_pattern_error::<6>("<N:UM>"),
+            custom_pattern_error::<7>("<N[UM]>"),
+        ];
+        for (error, pattern) in errors.into_iter().zip(&CUSTOM_PATTERNS[1..]) {
+            assert_eq!(
+                Some(FormatError::InvalidSpecifierPattern {
+                    pattern: String::from_utf8_lossy(pattern).into_owned()
+                }),
+                error
+            );
+        }
+    }
+
+    /// A specifier of a custom scheme with more specifiers than can be checked.
+    #[derive(Debug, PartialEq, Eq)]
+    struct Many;
+
+    impl Display for Many {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("<N>")
+        }
+    }
+
+    impl Specifier for Many {
+        fn format_pattern(&self) -> &'static [u8] {
+            b"<N>"
+        }
+        fn format_width(&self) -> usize {
+            0
+        }
+        fn parse_width(&self) -> crate::ParseWidth {
+            crate::ParseWidth::AtLeastOne
+        }
+        fn can_be_zero(&self) -> bool {
+            true
+        }
+        fn first_variants() -> &'static [&'static Self] {
+            &[&Many]
+        }
+        fn last_variants() -> &'static [&'static Self] {
+            &[&Many]
+        }
+        fn all() -> &'static [&'static Self] {
+            &[&Many; MAX_SPECIFIER_RULES + 1]
+        }
+        fn can_be_left_adjacent_to(&self, _other: &Self) -> bool {
+            false
+        }
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct ManyScheme;
+
+    impl Scheme for ManyScheme {
+        type Specifier = Many;
+        const MAX_SPECIFIERS: usize = 1;
+        fn name() -> &'static str {
+            "many"
+        }
+    }
+
+    #[test]
+    fn test_too_many_specifiers() {
+        assert_eq!(
+            Err(FormatError::TooManySpecifiers { count: 129 }),
+            ManyScheme::new_format("<N>")
+        );
+    }
+
+    /// Returns the message that [`format_str!`](crate::format_str) would fail to compile with for
+    /// `format_str`, if it is invalid.
+    fn const_message<S: Scheme>(format_str: &str) -> Option<String> {
+        let rules = static_rules::<S>();
+        let format = format_str.as_bytes();
+        let mut parser = match Parser::new(rules, format) {
+            Ok(parser) => parser,
+            Err(err) => return Some(Message::new(err, rules, format).as_str().to_owned()),
+        };
+        loop {
+            match parser.next_token() {
+                Ok(Some(_)) => {}
+                Ok(None) => return None,
+                Err(err) => return Some(Message::new(err, rules, format).as_str().to_owned()),
+            }
+        }
+    }
+
+    fn assert_const_message_matches<S: Scheme>(format_str: &str) {
+        assert_eq!(
+            S::new_format(format_str)
+                .err()
+                .as_ref()
+                .map(FormatError::to_string),
+            const_message::<S>(format_str),
+            "{format_str}"
+        );
+    }
+
+    /// Returns formats made of every specifier of `S`: alone, in every pair, after another in an
+    /// optional segment, and with every kind of argument, valid or not.
+    fn generated_formats<S: Scheme>() -> Vec<String> {
+        let patterns = S::Specifier::all()
+            .iter()
+            .map(|spec| String::from_utf8_lossy(spec.format_pattern()))
+            .collect_vec();
+        let mut formats = Vec::new();
+        for first in &patterns {
+            formats.push(first.to_string());
+            formats.push(format!("{first}[{first}]"));
+            for second in &patterns {
+                formats.push(format!("{first}.{second}"));
+                formats.push(format!("{first}[.{second}]"));
+                let name = &second[..second.len() - 1];
+                for arg in ["3", "+2", "0", "x", "dev|rc", "a|a", ""] {
+                    formats.push(format!("{first}.{name}:{arg}>"));
+                }
+                formats.push(format!("{first}.{name}:3"));
+            }
+        }
+        formats
+    }
+
+    #[test]
+    fn test_const_message_matches_generated() {
+        for format_str in generated_formats::<Sem>() {
+            assert_const_message_matches::<Sem>(&format_str);
+        }
+        for format_str in generated_formats::<Cal>() {
+            assert_const_message_matches::<Cal>(&format_str);
+        }
+        for format_str in generated_formats::<CalSem>() {
+            assert_const_message_matches::<CalSem>(&format_str);
+        }
+    }
+
+    #[test]
+    fn test_const_message_matches() {
+        let sem_formats = [
+            "<MAJOR>.<MINOR>.<PATCH>",
+            "v<EPOCH>!<MAJOR>.<MINOR>.<PATCH>.<REVISION><PRE:dev|rc>+<BUILD><META>",
+            "<MAJOR>[.<MINOR>[.<PATCH>]]",
+            "<MAJOR>[.<MINOR>]x]",
+            "<MAJOR>[.<MINOR>",
+            "<MAJOR>[.]",
+            "<MAJOR>[<PRE>]",
+            "<MAJOR>.<PATCH>",
+            "<MINOR>",
+            "<EPOCH>",
+            "<MAJOR:3>.<MINOR:+2>.<PATCH:0>",
+            "<MAJOR:x>",
+            "<MAJOR:99999999999999999999999>",
+            "<MAJOR:3",
+            "<MAJOR><PRE:a|b|a>",
+            "<MAJOR><PRE:a||b>",
+            "<MAJOR><PRE:>",
+            "<MAJOR><PRE:α>",
+            "<MAJOR>.<FOO>",
+            "<MAJOR>.<FOO",
+            "<<<MAJOR>[[]",
+            "<MAJOR>[.<MINOR>]]]",
+            "<MAJOR>[.<MINOR>]]",
+            "just literals ✓",
+            "",
+        ];
+        let cal_formats = [
+            "<YYYY>.<0M>.<0D>",
+            "[<EPOCH>!]<YY>.<MON>.<DD>.<0H><0MI>",
+            "<YYYY>[.<MM>]",
+            "<YYYY>[.<WW>]",
+            "<YYYY>.<MON:2>",
+            "<YYYY>.<MM:2>",
+            "<YYYY>.<DD>",
+            "<MM>",
+            "<EPOCH>",
+            "<GGGG>.<IW>.<MAJOR>",
+        ];
+        let cal_sem_formats = [
+            "<YYYY>.<0M>.<PATCH>",
+            "<YYYY>.<MINOR>",
+            "<YYYY>.<MM>.<MINOR>.<PATCH:2><PRE:a|b>",
+            "<YYYY>[.<MINOR>].<PATCH>",
+            "<PATCH>",
+            "<YYYY>.<MM>.<MAJOR>",
+        ];
+
+        for format_str in sem_formats {
+            assert_const_message_matches::<Sem>(format_str);
+        }
+        for format_str in cal_formats {
+            assert_const_message_matches::<Cal>(format_str);
+        }
+        for format_str in cal_sem_formats {
+            assert_const_message_matches::<CalSem>(format_str);
+        }
+    }
+
+    #[test]
+    fn test_format_macro() {
+        const FORMAT: Format<CalSem> =
+            crate::format_str!(CalSem, "[<EPOCH>!]<YYYY>.<0M>[.<PATCH>]<PRE:dev|rc>");
+        let format = CalSem::new_format("[<EPOCH>!]<YYYY>.<0M>[.<PATCH>]<PRE:dev|rc>").unwrap();
+        assert_eq!(format, FORMAT);
+        assert_eq!(format.to_string(), FORMAT.to_string());
+        assert!(matches!(FORMAT.tokens, Cow::Borrowed(_)));
+
+        // versions compare the same whichever way their format was made
+        let version = FORMAT.new_version("2024.09.1-dev.2").unwrap();
+        assert_eq!(format.new_version("2024.09.1-dev.2").unwrap(), version);
+        assert!(format.new_version("2024.09.1-rc.1").unwrap() > version);
+        assert!(format.new_version("2024.09.1").unwrap() > version);
+    }
 }
@@ -77,10 +77,16 @@
 //! - **Formats** define the structure of a version string. They are modeled by the [`Format`]
 //!   struct. They contains a sequence of *specifier* and *literal text* tokens. For example,
 //!   `<MAJOR>.<MINOR>.<PATCH>` is a format string that can be turned into a [`Format`] object.
+//!   Format strings known ahead of time can be made at compile time with [`format_str!`].
 //!
 //! - **Versions** are like Formats, but with actual values instead of specifiers. They represent a
 //!   a point in a project's development. These are modeled by the [`Version`] struct. They can be
-//!   incremented to new versions and compared amongst each other.
+//!   incremented to new versions and compared amongst each other. Their values can be read with
+//!   [`Version::parts`] or accessors like `get` and `year`. Wrap them in an [`OrdVersion`] to sort
+//!   them, or to use them in sets and as map keys. Check them against a [`Requirement`], such as
+//!   `>=1.2, <2`, made with [`Format::new_requirement`]. Convert them to another format of the
+//!   same scheme with `convert`, or move between the semantic and calendar-semantic schemes with
+//!   `migrate`, which makes a version that supersedes the current one.
 //!
 //! ## Format String Syntax
 //!
@@ -90,23 +96,41 @@
 //!
 //! ### Table
 //!
-//! In the "Example" column below, we reference a major of `1`, minor of `2`, patch of `3` and a
-//! date of `2001-02-03` (which is in the 4th week).
+//! In the "Example" column below, we reference an epoch of `1`, major of `1`, minor of `2`, patch
+//! of `3`, revision of `4`, and a date of `2001-02-03` (which is in the 4th week, or the 5th ISO
+//! week) at `14:05`.
 //!
 //! | Specifier | Example | [`Sem`] | [`CalSem`] | [`Cal`] | [Parse Width](#parse-width) | [Min. Format Width](#minimum-format-width) | Description |
 //! |---|---|---|---|---|---|---|---|
+//! | `<EPOCH>` | `1` | ✅ | ✅ | ✅ | >=1 | - | An epoch that is greater than every other value and is never reset. See [epochs](#epochs) |
 //! | `<MAJOR>` | `1` | ✅ | ❌ | ❌ | >=1 | - | The major part of a version |
 //! | `<MINOR>` | `2` | ✅ | ✅ | ❌ | >=1 | - | The minor part of a version |
 //! | `<PATCH>` | `3` | ✅ | ✅ | ❌ | >=1 | - | The patch part of a version |
+//! | `<REVISION>` | `4` | ✅ | ✅ | ❌ | >=1 | - | The revision part of a version, below patch |
+//! | `<PRE>` | `-rc.2` | ✅ | ✅ | ❌ | - | - | An optional pre-release label and number. See [pre-releases](#pre-releases) |
+//! | `<BUILD>` | `42` | ✅ | ✅ | ✅ | >=1 | - | A build number that increments on every bump and is never reset. See [build numbers](#build-numbers) |
+//! | `<META>` | `+ci.42` | ✅ | ✅ | ✅ | - | - | Optional build metadata. See [build metadata](#build-metadata) |
 //! | `<YYYY>` | `2001` | ❌ | ✅ | ✅ | >=1 | - | Full year, years less than 1 BCE are unsupported ([`0` refers to 1 BCE](https://en.wikipedia.org/wiki/Year_zero)) |
 //! | `<YY>` | `1` | ❌ | ✅ | ✅ | >=1 | - | Year minus `2000`. For now, has same effect as `year % 100`, but the year 2100 will be `100`, and so on |
 //! | `<0Y>` | `01` | ❌ | ✅ | ✅ | >=2 | 2 | Same as `YY` but zero-padded |
+//! | `<Q>` | `1` | ❌ | ✅ | ✅ | 1 | - | Quarter of the year (`1`–`4`) |
 //! | `<MM>` | `1` | ❌ | ✅ | ✅ | 1 or 2 | - | Month of year (`1`–`12`) |
 //! | `<0M>` | `01` | ❌ | ✅ | ✅ | 2 | 2 | Same as `MM` but zero-padded |
+//! | `<MON>` | `Feb` | ❌ | ✅ | ✅ | 3 | - | Abbreviated English month name (`Jan`–`Dec`). Ordered by month number |
+//! | `<Month>` | `February` | ❌ | ✅ | ✅ | 3 to 9 | - | Full English month name (`January`–`December`). Ordered by month number |
 //! | `<WW>` | `4` | ❌ | ✅ | ✅ | 1 or 2 | - | Week of the year (`0`–`53`), week 1 starts with the first Sunday in that year. |
 //! | `<0W>` | `04` | ❌ | ✅ | ✅ | 2 | 2 | Same as `WW` but zero-padded |
+//! | `<GGGG>` | `2001` | ❌ | ✅ | ✅ | >=1 | - | [ISO 8601 week-numbering year](https://en.wikipedia.org/wiki/ISO_week_date). May differ from the calendar year for the first and last few days of a year |
+//! | `<IW>` | `5` | ❌ | ✅ | ✅ | 1 or 2 | - | ISO 8601 week of the week-numbering year (`1`–`53`), weeks start on Monday. Must follow `<GGGG>` |
+//! | `<0IW>` | `05` | ❌ | ✅ | ✅ | 2 | 2 | Same as `IW` but zero-padded |
 //! | `<DD>` | `3` | ❌ | ✅ | ✅ | 1 or 2 | - | Day of the month (`1`–`31`) |
 //! | `<0D>` | `03` | ❌ | ✅ | ✅ | 2 | 2 | Same as `DD` but zero-padded |
+//! | `<DOY>` | `34` | ❌ | ✅ | ✅ | 1 to 3 | - | Day of the year (`1`–`366`) |
+//! | `<0DOY>` | `034` | ❌ | ✅ | ✅ | 3 | 3 | Same as `DOY` but zero-padded |
+//! | `<HH>` | `14` | ❌ | ✅ | ✅ | 1 or 2 | - | Hour of the day (`0`–`23`). Requires a [`DateTime`] to advance |
+//! | `<0H>` | `14` | ❌ | ✅ | ✅ | 2 | 2 | Same as `HH` but zero-padded |
+//! | `<MI>` | `5` | ❌ | ✅ | ✅ | 1 or 2 | - | Minute of the hour (`0`–`59`). Requires a [`DateTime`] to advance |
+//! | `<0MI>` | `05` | ❌ | ✅ | ✅ | 2 | 2 | Same as `MI` but zero-padded |
 //!
 //! Specifiers are case-sensitive. For example, `<major>` or `<yYyY>` are not a valid specifiers.
 //!
@@ -154,9 +178,170 @@
 //! - `2010` → `10`, as-is formatting
 //! - `2100` → `100`, as-is formatting
 //!
+//! ### Fixed Widths
+//!
+//! Specifiers whose values are unpadded numbers (such as `<MAJOR>`, `<PATCH>`, `<BUILD>`,
+//! `<YYYY>`, or `<MM>`) may be given an explicit width, like `<PATCH:3>`. Their values must then
+//! parse as exactly that many digits, leading zeros included, and are zero-padded to it when
+//! formatted. This also makes adjacent specifiers unambiguous without a separator. The full
+//! years, `<YYYY>` and `<GGGG>`, need a width of at least 4, since years since 1000 have four digits.
+//!
+//! Unlike the [minimum format width](#minimum-format-width), a value that outgrows its width is an
+//! error ([`NextError::ValueOverflowsWidth`]) instead of being left as-is.
+//!
+//! ```
+//! use nextver::prelude::*;
+//!
+//! let cur = Sem::new_version("<MAJOR>.<MINOR:2>.<PATCH:3>", "1.04.007")?;
+//! assert_eq!("1.05.000", cur.next(SemLevel::Minor)?.to_string());
+//!
+//! let cur = Sem::new_version("<MAJOR>.<MINOR:2>.<PATCH:3>", "1.99.007")?;
+//! assert!(cur.next(SemLevel::Minor).is_err());
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! ### Pre-releases
+//!
+//! The `<PRE>` specifier marks a version as a pre-release of the version made by the rest of the
+//! specifiers, like [SemVer pre-releases](https://semver.org/#spec-item-9). Its value is either
+//! `-<label>.<number>` (e.g., `-rc.2`), or, for a final release, nothing at all. It can only come
+//! after the other semantic specifiers.
+//!
+//! The labels are ordered. By default, they are `alpha` < `beta` < `rc`, and others can be given
+//! in the format string separated by `|`, like `<PRE:dev|alpha|rc>`. A pre-release compares as less
+//! than its final release.
+//!
+//! Incrementing a more significant value (or changing the date) with `next` makes a final release.
+//! To make a pre-release of it instead, use `next_pre_release`, which starts at the first label.
+//! Then, it is incremented with [`SemLevel::PreRelease`], [`SemLevel::PreReleaseLabel`], and
+//! finalized with [`SemLevel::FinalRelease`] (and the same-named [`CalSemLevel`] variants).
+//!
+//! ```
+//! use nextver::prelude::*;
+//!
+//! let format = Sem::new_format("<MAJOR>.<MINOR>.<PATCH><PRE>")?;
+//! let cur = format.new_version("1.3.0")?;
+//!
+//! let alpha = cur.next_pre_release(SemLevel::Minor)?;
+//! assert_eq!("1.4.0-alpha.0", alpha.to_string());
+//!
+//! let rc = alpha.next(SemLevel::PreReleaseLabel)?.next(SemLevel::PreReleaseLabel)?;
+//! assert_eq!("1.4.0-rc.0", rc.to_string());
+//!
+//! let rc_next = rc.next(SemLevel::PreRelease)?;
+//! assert_eq!("1.4.0-rc.1", rc_next.to_string());
+//!
+//! let release = rc_next.next(SemLevel::FinalRelease)?;
+//! assert_eq!("1.4.0", release.to_string());
+//!
+//! assert!(cur < alpha && alpha < rc && rc < rc_next && rc_next < release);
+//!
+//! // without a pre-release
+//! assert_eq!("1.4.1", release.next(SemLevel::Patch)?.to_string());
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! ### Build Metadata
+//!
+//! The `<META>` specifier holds [SemVer-like build metadata](https://semver.org/#spec-item-10). Its
+//! value is either `+<metadata>` (e.g., `+ci.42`), where the metadata is ASCII alphanumerics,
+//! hyphens, and dots, or, for no metadata, nothing at all. It can only come last.
+//!
+//! Build metadata is ignored when comparing versions. It is also not carried over when
+//! incrementing, so set it on the next version with [`Version::with_metadata`].
+//!
+//! ```
+//! use nextver::prelude::*;
+//!
+//! let format = Sem::new_format("<MAJOR>.<MINOR>.<PATCH><META>")?;
+//! let a = format.new_version("1.2.3+ci.41")?;
+//! let b = format.new_version("1.2.3+ci.42")?;
+//! assert!(a == b);
+//!
+//! let next = b.next(SemLevel::Patch)?;
+//! assert_eq!("1.2.4", next.to_string());
+//! assert_eq!("1.2.4+ci.43", next.with_metadata(Some("ci.43"))?.to_string());
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! ### Build Numbers
+//!
+//! The `<BUILD>` specifier is a monotonic counter, like Android's `versionCode`. It increments by
+//! one on *every* `next` call, and is never reset by semantic bumps or date changes. It may only
+//! be followed by `<META>`.
+//!
+//! Because it always increments, a [`Cal`] version with `<BUILD>` can be incremented more than once
+//! in the same period.
+//!
+//! ```
+//! use nextver::prelude::*;
+//!
+//! let cur = Sem::new_version("<MAJOR>.<MINOR>.<PATCH>+<BUILD>", "1.2.3+41")?;
+//! assert_eq!("2.0.0+42", cur.next(SemLevel::Major)?.to_string());
+//!
+//! let date = Date::explicit(2024, 2, 23)?;
+//! let cur = Cal::new_version("<YYYY>.<0M>.<0D>.<BUILD>", "2024.02.23.41")?;
+//! assert_eq!("2024.02.23.42", cur.next(date)?.to_string());
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! ### Epochs
+//!
+//! The `<EPOCH>` specifier, like [PEP 440](https://peps.python.org/pep-0440/#version-epochs)'s,
+//! is the most significant value of a version. It lets a version be ordered above all versions in
+//! an older scheme, such as a `1!1.0` after `2024.9.3`. It may only be first in a format, and is
+//! never reset by semantic bumps or date changes.
+//!
+//! It is incremented explicitly: with [`SemLevel::Epoch`] or [`CalSemLevel::Epoch`], or, as
+//! [`Cal`] has no levels, with [`Version::next_epoch`]. Incrementing it resets lesser semantic
+//! values to zero.
+//!
+//! Put it in an [optional segment](#optional-segments) so that versions without an epoch still
+//! match.
+//!
+//! ```
+//! use nextver::prelude::*;
+//!
+//! let format = Sem::new_format("[<EPOCH>!]<MAJOR>.<MINOR>")?;
+//!
+//! let old = format.new_version("2024.9")?;
+//! let cur = format.new_version("1!1.0")?;
+//! assert!(old < cur);
+//! assert_eq!("1!2.0", cur.next(SemLevel::Major)?.to_string());
+//! assert_eq!("2!0.0", cur.next(SemLevel::Epoch)?.to_string());
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! ### Optional Segments
+//!
+//! Wrap part of a format in square brackets to make it optional, like `<MAJOR>.<MINOR>[.<PATCH>]`.
+//! Versions may then have or omit that segment, so `2.1` and `2.1.3` both match.
+//!
+//! - An absent segment's values are zero. So, in comparisons, `2.1` equals `2.1.0`.
+//! - When incrementing, a segment is rendered only if any of its values are non-zero, or if a later
+//!   optional segment is rendered (so that values don't shift places).
+//! - Segments cannot be nested, and must contain at least one specifier. Only specifiers that can be
+//!   zero may be in them, so not `<PRE>`, `<META>` (which are already optional), or, for example,
+//!   `<DD>`.
+//!
+//! ```
+//! use nextver::prelude::*;
+//!
+//! let format = Sem::new_format("<MAJOR>.<MINOR>[.<PATCH>]")?;
+//!
+//! let cur = format.new_version("2.1")?;
+//! assert_eq!("2.1.1", cur.next(SemLevel::Patch)?.to_string());
+//!
+//! let cur = format.new_version("2.1.3")?;
+//! assert_eq!("2.2", cur.next(SemLevel::Minor)?.to_string());
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
 //! ### Escaping Brackets
 //!
-//! If you want to use a literal `<` in your format, escape it as `<<`.
+//! If you want to use a literal `<` in your format, escape it as `<<`. Likewise, escape a literal
+//! `[` as `[[`, and a literal `]` as `]]`. (A single `]` inside an optional segment ends it, and
+//! one outside of a segment is also a literal.)
 //!
 //! `>` must **not** be escaped.
 //!
@@ -171,9 +356,223 @@
 //! let version = format.new_version(version_str)?;
 //!
 //! assert_eq!(&version.to_string(), version_str);
+//!
+//! // double `]]` for a literal `]` inside an optional segment
+//! let format = Sem::new_format("<MAJOR>[ [[<MINOR>]]]")?;
+//! assert_eq!("1 [2]", format.new_version("1 [2]")?.to_string());
+//! assert_eq!("1", format.new_version("1")?.to_string());
 //! # Ok::<(), Box<dyn std::error::Error>>(())
 //! ```
 //!
+//! ## Custom Schemes
+//!
+//! If the built-in schemes don't fit, define your own by implementing [`Specifier`] for your
+//! specifiers and [`Scheme`] for your scheme. Formats and versions of it are parsed, displayed,
+//! and compared just like the built-in ones. They are incremented with [`Version::bump`], which
+//! changes each value as [`Specifier::bump_value`] says, or with [`Version::next_with`].
+//!
+//! ```
+//! use nextver::{NextError, ParseWidth, Scheme, SpecValue, Specifier};
+//! use std::fmt;
+//!
+//! #[derive(Debug, PartialEq, Eq)]
+//! enum InHouse {
+//!     Generation,
+//!     Year,
+//!     Build,
+//! }
+//!
+//! static ALL: &[&InHouse] = &[&InHouse::Generation, &InHouse::Year, &InHouse::Build];
+//!
+//! impl fmt::Display for InHouse {
+//!     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+//!         f.write_str(std::str::from_utf8(self.format_pattern()).unwrap())
+//!     }
+//! }
+//!
+//! impl Specifier for InHouse {
+//!     fn format_pattern(&self) -> &'static [u8] {
+//!         match self {
+//!             InHouse::Generation => b"<GENERATION>",
+//!             InHouse::Year => b"<YYYY>",
+//!             InHouse::Build => b"<BUILD>",
+//!         }
+//!     }
+//!     fn format_width(&self) -> usize {
+//!         0
+//!     }
+//!     fn parse_width(&self) -> ParseWidth {
+//!         ParseWidth::AtLeastOne
+//!     }
+//!     fn can_be_zero(&self) -> bool {
+//!         true
+//!     }
+//!     fn first_variants() -> &'static [&'static Self] {
+//!         &ALL[..1]
+//!     }
+//!     fn last_variants() -> &'static [&'static Self] {
+//!         &ALL[2..]
+//!     }
+//!     fn all() -> &'static [&'static Self] {
+//!         ALL
+//!     }
+//!     fn can_be_left_adjacent_to(&self, other: &Self) -> bool {
+//!         matches!(
+//!             (self, other),
+//!             (InHouse::Generation, InHouse::Year) | (InHouse::Year, InHouse::Build)
+//!         )
+//!     }
+//!     // a new generation starts counting builds from zero
+//!     fn bump_value(&self, value: SpecValue, level: &Self) -> Result<SpecValue, NextError> {
+//!         match (self, level) {
+//!             _ if self == level => Ok(value + 1),
+//!             (InHouse::Build, InHouse::Generation) => Ok(0),
+//!             _ => Ok(value),
+//!         }
+//!     }
+//! }
+//!
+//! #[derive(Debug, PartialEq, Eq)]
+//! struct InHouseScheme;
+//!
+//! impl Scheme for InHouseScheme {
+//!     type Specifier = InHouse;
+//!     const MAX_SPECIFIERS: usize = 3;
+//!     fn name() -> &'static str {
+//!         "in-house"
+//!     }
+//! }
+//!
+//! let cur = InHouseScheme::new_version("<GENERATION>.<YYYY>.<BUILD>", "3.2023.41")?;
+//! let next = cur.next_with(|value, spec| match spec {
+//!     InHouse::Generation => Ok(value),
+//!     InHouse::Year => Ok(2024),
+//!     InHouse::Build => Ok(value + 1),
+//! })?;
+//! assert_eq!("3.2024.42", next.to_string());
+//! assert!(cur < next);
+//! assert_eq!("4.2024.0", next.bump(&InHouse::Generation)?.to_string());
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! To make formats of a custom scheme at compile time with [`format_str!`], also give it
+//! [`Scheme::RULES`]. These are the same rules as its [`Specifier`] methods, written as
+//! [`SpecifierRules`] that can be read in `const` contexts.
+//!
+//! ```
+//! use nextver::{Format, Scheme, SchemeRules, SpecifierRules};
+//! # use nextver::{ParseWidth, Specifier};
+//! # use std::fmt;
+//! #
+//! # #[derive(Debug, PartialEq, Eq)]
+//! # enum InHouse {
+//! #     Generation,
+//! #     Year,
+//! #     Build,
+//! # }
+//! #
+//! # static ALL: &[&InHouse] = &[&InHouse::Generation, &InHouse::Year, &InHouse::Build];
+//! #
+//! # impl fmt::Display for InHouse {
+//! #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+//! #         f.write_str(std::str::from_utf8(self.format_pattern()).unwrap())
+//! #     }
+//! # }
+//! #
+//! # impl Specifier for InHouse {
+//! #     fn format_pattern(&self) -> &'static [u8] {
+//! #         match self {
+//! #             InHouse::Generation => b"<GENERATION>",
+//! #             InHouse::Year => b"<YYYY>",
+//! #             InHouse::Build => b"<BUILD>",
+//! #         }
+//! #     }
+//! #     fn format_width(&self) -> usize {
+//! #         0
+//! #     }
+//! #     fn parse_width(&self) -> ParseWidth {
+//! #         ParseWidth::AtLeastOne
+//! #     }
+//! #     fn can_be_zero(&self) -> bool {
+//! #         true
+//! #     }
+//! #     fn first_variants() -> &'static [&'static Self] {
+//! #         &ALL[..1]
+//! #     }
+//! #     fn last_variants() -> &'static [&'static Self] {
+//! #         &ALL[2..]
+//! #     }
+//! #     fn all() -> &'static [&'static Self] {
+//! #         ALL
+//! #     }
+//! #     fn can_be_left_adjacent_to(&self, other: &Self) -> bool {
+//! #         matches!(
+//! #             (self, other),
+//! #             (InHouse::Generation, InHouse::Year) | (InHouse::Year, InHouse::Build)
+//! #         )
+//! #     }
+//! # }
+//! #
+//! # #[derive(Debug, PartialEq, Eq)]
+//! # struct InHouseScheme;
+//! #
+//! // followers are given by their index in this array
+//! const IN_HOUSE_RULES: [SpecifierRules<InHouse>; 3] = [
+//!     SpecifierRules::new(&InHouse::Generation, b"<GENERATION>")
+//!         .first()
+//!         .optional()
+//!         .width(1)
+//!         .followed_by(&[1]),
+//!     SpecifierRules::new(&InHouse::Year, b"<YYYY>")
+//!         .optional()
+//!         .width(1)
+//!         .followed_by(&[2]),
+//!     SpecifierRules::new(&InHouse::Build, b"<BUILD>")
+//!         .last()
+//!         .optional()
+//!         .width(1),
+//! ];
+//!
+//! impl Scheme for InHouseScheme {
+//!     type Specifier = InHouse;
+//!     const MAX_SPECIFIERS: usize = 3;
+//!     const RULES: Option<SchemeRules<'static, InHouse>> =
+//!         Some(SchemeRules::new("in-house", &IN_HOUSE_RULES));
+//!     fn name() -> &'static str {
+//!         "in-house"
+//!     }
+//! }
+//!
+//! const FORMAT: Format<InHouseScheme> =
+//!     nextver::format_str!(InHouseScheme, "<GENERATION>.<YYYY>.<BUILD>");
+//! assert_eq!("3.2023.41", FORMAT.new_version("3.2023.41")?.to_string());
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! ## Serde
+//!
+//! With the `serde` feature, [`Format`], [`Version`], [`Date`], [`SemLevel`] and [`CalSemLevel`]
+//! can be serialized and deserialized. Formats and versions are represented by their strings, and
+//! dates as `YYYY-MM-DD`. Because a version string can only be parsed with a format, deserialize a
+//! [`Version`] by using a `&Format` as a
+//! [`DeserializeSeed`](https://docs.rs/serde/latest/serde/de/trait.DeserializeSeed.html).
+//!
+//! ## `no_std`
+//!
+//! The library only needs `alloc`. The default features are:
+//!
+//! - `std`: uses the standard library in nextver and its dependencies.
+//! - `clock`: enables [`Date::utc_now`], [`Date::local_now`] and the same on [`DateTime`]. Implies
+//!   `std`.
+//! - `cli`: builds the `nextver` binary. Implies `clock`.
+//!
+//! Without them, versions can still be parsed, compared and incremented with an explicit
+//! [`Date`]:
+//!
+//! ```toml
+//! nextver = { version = "0.8", default-features = false }
+//! ```
+//!
 //! ## Prelude
 //!
 //! nextver provides a prelude module for convenience. It contains everything needed to interact
@@ -201,25 +600,45 @@
 //! ```sh
 //! nextver --help
 //! ```
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![warn(missing_docs)]
 #![warn(clippy::pedantic)]
 #![warn(clippy::cargo)]
 
+extern crate alloc;
+
+mod builder;
+mod convert;
 mod format;
+mod requirement;
 mod scheme;
 mod specifier;
 mod version;
 
+pub use crate::builder::VersionBuilder;
+pub use crate::convert::{ConvertError, MigrateError};
 pub use crate::format::{Format, FormatError};
-pub use crate::scheme::{Cal, CalSem, CompositeError, Scheme, Sem};
-pub use crate::specifier::{CalSemLevel, SemLevel};
-pub use crate::version::{Date, DateError, NextError, Version, VersionError};
+pub use crate::requirement::{Requirement, RequirementError};
+pub use crate::scheme::{Cal, CalSem, CompositeError, Scheme, SchemeRules, Sem};
+pub use crate::specifier::{
+    CalSemLevel, ParseWidth, SemLevel, SpecValue, Specifier, SpecifierRules,
+};
+pub use crate::version::{
+    Date, DateError, DateTime, NextError, OrdVersion, Version, VersionError, VersionPart,
+};
+
+// used by `format_str!`
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::format::{token_count, StaticTokens};
+}
 
 /// A convenience module appropriate for glob imports (`use nextver::prelude::*;`).
 pub mod prelude {
     #[doc(no_inline)]
     pub use crate::{
-        Cal, CalSem, CalSemLevel, CompositeError, Date, DateError, Format, FormatError, NextError,
-        Scheme, Sem, SemLevel, Version, VersionError,
+        Cal, CalSem, CalSemLevel, CompositeError, ConvertError, Date, DateError, DateTime, Format,
+        FormatError, MigrateError, NextError, OrdVersion, Requirement, RequirementError, Scheme,
+        Sem, SemLevel, Version, VersionError,
     };
 }
@@ -3,9 +3,9 @@
 #![warn(clippy::pedantic)]
 #![warn(clippy::cargo)]
 
-use clap::{arg, command, Parser, Subcommand, ValueEnum};
+use clap::{Parser, Subcommand, ValueEnum};
 use core::str::FromStr;
-use nextver::prelude::*;
+use nextver::{prelude::*, SpecValue};
 
 #[derive(thiserror::Error, Debug, PartialEq)]
 enum NextVerCliError {
@@ -18,6 +18,9 @@ enum NextVerCliError {
     #[error(transparent)]
     LibraryNextError(#[from] NextError),
 
+    #[error(transparent)]
+    LibraryMigrateError(#[from] MigrateError),
+
     #[error("format string was invalid for all schemes")]
     NoValidScheme,
 
@@ -32,6 +35,22 @@ enum NextVerCliError {
     // names.
     #[error("this scheme requires a semantic specifier, use `-l`/`--sem-level`")]
     NoSemanticSpecifier,
+
+    #[error("`--by` and `--to` should only be used with sem scheme")]
+    StepWithoutSem,
+
+    #[error("`--pre-release` should only be used with sem or calsem scheme")]
+    PreReleaseWithCal,
+
+    #[error("version should be valid for a sem or calsem format to migrate it")]
+    NoMigrationScheme,
+
+    // Like `NoSemanticSpecifier`, this is a CLI usage error that depends on the guessed scheme.
+    #[error("migrating to sem scheme requires a major value, use `--major`")]
+    NoMajor,
+
+    #[error("`--major` should only be used when migrating to sem scheme")]
+    MajorWithoutSem,
 }
 
 #[derive(Clone, PartialEq, Eq, ValueEnum, Debug)]
@@ -78,12 +97,35 @@ fn validate(
     }
 }
 
+/// Sets the build metadata of `version` if `metadata` is given, and returns the version string.
+fn with_metadata<S: Scheme>(
+    version: &Version<S>,
+    metadata: Option<&str>,
+) -> Result<String, NextVerCliError> {
+    if metadata.is_some() {
+        Ok(version.with_metadata(metadata)?.to_string())
+    } else {
+        Ok(version.to_string())
+    }
+}
+
+/// How to change the value of the semantic specifier, if not by incrementing it by one.
+#[derive(Debug, Clone, Copy)]
+enum SemStep {
+    By(SpecValue),
+    To(SpecValue),
+}
+
+#[allow(clippy::too_many_arguments)]
 fn next(
     scheme: &SchemeArg,
     format_str: &str,
     version_str: &str,
-    date: Date,
+    date: DateTime,
     spec: Option<&SemLevelArg>,
+    step: Option<SemStep>,
+    pre_release: bool,
+    metadata: Option<&str>,
 ) -> Result<Output, NextVerCliError> {
     // functions to get the semantic specifier from the option, that error if we need it but
     // don't have it
@@ -96,23 +138,69 @@ fn next(
             .transpose()?
             .ok_or(NextVerCliError::NoSemanticSpecifier)
     };
+    let no_step = || {
+        if step.is_some() {
+            Err(NextVerCliError::StepWithoutSem)
+        } else {
+            Ok(())
+        }
+    };
+    let sem_next = |sem_ver: &Version<Sem>| {
+        let level = sem_spec()?;
+        let next_ver = match step {
+            None if pre_release => sem_ver.next_pre_release(level)?,
+            None => sem_ver.next(level)?,
+            Some(SemStep::By(amount)) => sem_ver.next_by(level, amount)?,
+            Some(SemStep::To(value)) => sem_ver.next_to(level, value)?,
+        };
+        with_metadata(&next_ver, metadata)
+    };
+    // cal formats have no semantic specifiers, so only an epoch level has an effect
+    let cal_next = |cal_ver: &Version<Cal>| {
+        no_step()?;
+        if pre_release {
+            return Err(NextVerCliError::PreReleaseWithCal);
+        }
+        let next_ver = if spec == Some(&SemLevelArg::Epoch) {
+            cal_ver.next_epoch(date)?
+        } else {
+            cal_ver.next(date)?
+        };
+        with_metadata(&next_ver, metadata)
+    };
+    let cal_sem_next = |cal_sem_ver: &Version<CalSem>| {
+        no_step()?;
+        let next_ver = if pre_release {
+            cal_sem_ver.next_pre_release(date, cal_sem_spec()?)?
+        } else {
+            cal_sem_ver.next(date, cal_sem_spec()?)?
+        };
+        with_metadata(&next_ver, metadata)
+    };
 
     let next_version = match scheme {
-        SchemeArg::Sem => Sem::next_version_string(format_str, version_str, sem_spec()?)?,
+        SchemeArg::Sem => {
+            let sem_ver = Sem::new_version(format_str, version_str)?;
+            sem_next(&sem_ver)?
+        }
 
-        SchemeArg::Cal => Cal::next_version_string(format_str, version_str, date)?,
+        SchemeArg::Cal => {
+            let cal_ver = Cal::new_version(format_str, version_str)?;
+            cal_next(&cal_ver)?
+        }
 
         SchemeArg::CalSem => {
-            CalSem::next_version_string(format_str, version_str, date, cal_sem_spec()?)?
+            let cal_sem_ver = CalSem::new_version(format_str, version_str)?;
+            cal_sem_next(&cal_sem_ver)?
         }
 
         SchemeArg::Guess => {
             if let Ok(sem_ver) = Sem::new_version(format_str, version_str) {
-                sem_ver.next(sem_spec()?)?.to_string()
+                sem_next(&sem_ver)?
             } else if let Ok(cal_ver) = Cal::new_version(format_str, version_str) {
-                cal_ver.next(date)?.to_string()
+                cal_next(&cal_ver)?
             } else if let Ok(cal_sem_ver) = CalSem::new_version(format_str, version_str) {
-                cal_sem_ver.next(date, cal_sem_spec()?)?.to_string()
+                cal_sem_next(&cal_sem_ver)?
             } else {
                 return Err(NextVerCliError::NoValidScheme);
             }
@@ -121,43 +209,95 @@ fn next(
     Ok((next_version, ExitCode::Success))
 }
 
+/// Migrates a sem version to the calsem `to_format_str` on `date`, or a calsem version to the sem
+/// `to_format_str` with the `major` value.
+fn migrate(
+    format_str: &str,
+    version_str: &str,
+    to_format_str: &str,
+    date: DateTime,
+    major: Option<SpecValue>,
+) -> Result<Output, NextVerCliError> {
+    let migrated_version = if let Ok(sem_ver) = Sem::new_version(format_str, version_str) {
+        if major.is_some() {
+            return Err(NextVerCliError::MajorWithoutSem);
+        }
+        let to_format = CalSem::new_format(to_format_str)?;
+        sem_ver.migrate(&to_format, date)?.to_string()
+    } else if let Ok(cal_sem_ver) = CalSem::new_version(format_str, version_str) {
+        let major = major.ok_or(NextVerCliError::NoMajor)?;
+        let to_format = Sem::new_format(to_format_str)?;
+        cal_sem_ver.migrate(&to_format, major)?.to_string()
+    } else {
+        return Err(NextVerCliError::NoMigrationScheme);
+    };
+    Ok((migrated_version, ExitCode::Success))
+}
+
 #[derive(Clone, PartialEq, Eq, ValueEnum, Debug)]
 enum SemLevelArg {
+    /// increment the epoch specifier
+    Epoch,
     /// increment the major semantic specifier
     Major,
     /// increment the minor semantic specifier
     Minor,
     /// increment the patch semantic specifier
     Patch,
+    /// increment the revision semantic specifier
+    Revision,
+    /// increment the number of the pre-release specifier
+    PreRelease,
+    /// promote the pre-release specifier to its next label
+    PreReleaseLabel,
+    /// finalize the pre-release specifier, removing it
+    FinalRelease,
 }
 
 impl SemLevelArg {
     fn to_sem_level(&self) -> SemLevel {
-        use SemLevelArg::{Major, Minor, Patch};
+        use SemLevelArg::{
+            Epoch, FinalRelease, Major, Minor, Patch, PreRelease, PreReleaseLabel, Revision,
+        };
         match self {
+            Epoch => SemLevel::Epoch,
             Major => SemLevel::Major,
             Minor => SemLevel::Minor,
             Patch => SemLevel::Patch,
+            Revision => SemLevel::Revision,
+            PreRelease => SemLevel::PreRelease,
+            PreReleaseLabel => SemLevel::PreReleaseLabel,
+            FinalRelease => SemLevel::FinalRelease,
         }
     }
 
     fn to_calsem_specifier(&self) -> Result<CalSemLevel, NextVerCliError> {
-        use SemLevelArg::{Major, Minor, Patch};
+        use SemLevelArg::{
+            Epoch, FinalRelease, Major, Minor, Patch, PreRelease, PreReleaseLabel, Revision,
+        };
         match self {
+            Epoch => Ok(CalSemLevel::Epoch),
             Major => Err(NextVerCliError::MajorSpecifierWithCalsem),
             Minor => Ok(CalSemLevel::Minor),
             Patch => Ok(CalSemLevel::Patch),
+            Revision => Ok(CalSemLevel::Revision),
+            PreRelease => Ok(CalSemLevel::PreRelease),
+            PreReleaseLabel => Ok(CalSemLevel::PreReleaseLabel),
+            FinalRelease => Ok(CalSemLevel::FinalRelease),
         }
     }
 }
 
-const UNPARSEABLE_DATE_ERROR: &str = "Could not parse provided date as `utc`, `local`, or `Y-M-D`";
+const UNPARSEABLE_DATE_ERROR: &str =
+    "Could not parse provided date as `utc`, `local`, `Y-M-D`, or `Y-M-DTH:M`";
 
-fn parse_date(s: &str) -> Result<Date, &'static str> {
+fn parse_date(s: &str) -> Result<DateTime, &'static str> {
     match s {
-        "utc" => Ok(Date::utc_now()),
-        "local" => Ok(Date::local_now()),
-        ymd => Ok(Date::from_str(ymd).map_err(|_| UNPARSEABLE_DATE_ERROR)?),
+        "utc" => Ok(DateTime::utc_now()),
+        "local" => Ok(DateTime::local_now()),
+        ymd_hm => DateTime::from_str(ymd_hm)
+            .or_else(|_| Date::from_str(ymd_hm).map(DateTime::from))
+            .map_err(|_| UNPARSEABLE_DATE_ERROR),
     }
 }
 
@@ -194,22 +334,71 @@ enum Subcommands {
         #[arg(short, long)]
         format: String,
 
-        /// The semantic specifier to increment. Cal formats ignore this option entirely, and calsem
-        /// formats only accept `minor` or `patch`.
+        /// The semantic specifier to increment. Cal formats ignore this option unless it is
+        /// `epoch`, and calsem formats accept all but `major`.
         #[arg(short = 'l', long, value_enum)]
         sem_level: Option<SemLevelArg>,
 
+        /// Increment the value of the semantic specifier by this amount instead of one. Only sem
+        /// formats accept this option.
+        #[arg(long, value_name = "AMOUNT", conflicts_with = "to")]
+        by: Option<SpecValue>,
+
+        /// Set the value of the semantic specifier to this value instead of incrementing it. The
+        /// next version must still be greater than the current one. Only sem formats accept this
+        /// option.
+        #[arg(long, value_name = "VALUE")]
+        to: Option<SpecValue>,
+
+        /// Make the next version a pre-release of the incremented version, starting at the first
+        /// label of `<PRE>`. Without this option, incrementing a value makes a final release. Only
+        /// sem and calsem formats accept this option.
+        #[arg(short, long, conflicts_with_all = ["by", "to"])]
+        pre_release: bool,
+
         /// The date to update calendar specifiers. Only has an effect if the format/version
         /// contain them. Can be either of the fixed strings `utc` or `local`, which use the current
-        /// date in those timezones, or a date in the format `Y-M-D`, for an explicit date made
-        /// from a year, month, and day.
-        #[arg(short, long, value_name = "utc|local|Y-M-D", value_parser = parse_date, default_value = "utc")]
-        date: Date,
+        /// date and time in those timezones, a date in the format `Y-M-D`, for an explicit date
+        /// made from a year, month, and day, or a date-time in the format `Y-M-DTH:M`, which also
+        /// sets the hour and minute.
+        #[arg(short, long, value_name = "utc|local|Y-M-D|Y-M-DTH:M", value_parser = parse_date, default_value = "utc")]
+        date: DateTime,
+
+        /// The build metadata to give the next version. Only has an effect if the format contains
+        /// `<META>`. Without this option, the next version has no build metadata.
+        #[arg(short, long)]
+        metadata: Option<String>,
 
         /// Interpret the format as the given scheme.
         #[arg(short, long, value_enum, default_value_t=SchemeArg::Guess)]
         scheme: SchemeArg,
     },
+
+    /// Migrates a version formatted by `FORMAT` to `TO_FORMAT`, between the sem and calsem
+    /// schemes. The migrated version is the first of `TO_FORMAT` and supersedes the current one,
+    /// incrementing the epoch if needed.
+    Migrate {
+        /// The current version string
+        version: String,
+
+        /// A string defining the structure of the version string, of the sem or calsem scheme
+        #[arg(short, long)]
+        format: String,
+
+        /// A string defining the structure of the migrated version, of the calsem scheme if
+        /// `FORMAT` is of the sem scheme and vice versa
+        #[arg(short, long)]
+        to_format: String,
+
+        /// The date of the migrated version when migrating to calsem scheme. Takes the same values
+        /// as the option of `next`.
+        #[arg(short, long, value_name = "utc|local|Y-M-D|Y-M-DTH:M", value_parser = parse_date, default_value = "utc")]
+        date: DateTime,
+
+        /// The major value of the migrated version. Required when migrating to sem scheme.
+        #[arg(long)]
+        major: Option<SpecValue>,
+    },
 }
 
 #[derive(Debug, PartialEq)]
@@ -248,7 +437,7 @@ fn main() -> std::process::ExitCode {
             eprintln!("{e}");
 
             // special handling for CLI usage error
-            if e == NextVerCliError::NoSemanticSpecifier {
+            if e == NextVerCliError::NoSemanticSpecifier || e == NextVerCliError::NoMajor {
                 ExitCode::CliUsageError
             } else {
                 ExitCode::Failure
@@ -269,9 +458,29 @@ fn run(cli: Cli) -> Result<Output, NextVerCliError> {
             format,
             version,
             sem_level: level,
+            by,
+            to,
+            pre_release,
             date,
+            metadata,
             scheme,
-        }) => next(&scheme, &format, &version, date, level.as_ref()),
+        }) => next(
+            &scheme,
+            &format,
+            &version,
+            date,
+            level.as_ref(),
+            by.map(SemStep::By).or(to.map(SemStep::To)),
+            pre_release,
+            metadata.as_deref(),
+        ),
+        Some(Subcommands::Migrate {
+            version,
+            format,
+            to_format,
+            date,
+            major,
+        }) => migrate(&format, &version, &to_format, date, major),
         None => unreachable!("clap should catch this no-subcommand case"),
     }
 }
@@ -315,4 +524,223 @@ mod tests {
 
         assert_eq!(Ok(("2024.08.1".to_string(), ExitCode::Success,)), run(res));
     }
+
+    #[test]
+    fn test_cal_date_time() {
+        let res = Cli::try_parse_from([
+            "nextver",
+            "next",
+            "2024.10.18.0900",
+            "--format",
+            "<YYYY>.<0M>.<0D>.<0H><0MI>",
+            "--date",
+            "2024-10-18T14:00",
+        ])
+        .unwrap();
+
+        assert_eq!(
+            Ok(("2024.10.18.1400".to_string(), ExitCode::Success,)),
+            run(res)
+        );
+    }
+
+    #[test]
+    fn test_sem_pre_release_label() {
+        let res = Cli::try_parse_from([
+            "nextver",
+            "next",
+            "1.4.0-beta.3",
+            "--format",
+            "<MAJOR>.<MINOR>.<PATCH><PRE>",
+            "--sem-level",
+            "pre-release-label",
+        ])
+        .unwrap();
+
+        assert_eq!(Ok(("1.4.0-rc.0".to_string(), ExitCode::Success,)), run(res));
+    }
+
+    #[test]
+    fn test_sem_pre_release_flag() {
+        let args = |pre_release: bool| {
+            let mut args = vec![
+                "nextver",
+                "next",
+                "1.3.2",
+                "--format",
+                "<MAJOR>.<MINOR>.<PATCH><PRE>",
+                "--sem-level",
+                "minor",
+            ];
+            if pre_release {
+                args.push("--pre-release");
+            }
+            Cli::try_parse_from(args).unwrap()
+        };
+
+        assert_eq!(
+            Ok(("1.4.0-alpha.0".to_string(), ExitCode::Success,)),
+            run(args(true))
+        );
+        assert_eq!(
+            Ok(("1.4.0".to_string(), ExitCode::Success,)),
+            run(args(false))
+        );
+    }
+
+    #[test]
+    fn test_cal_epoch() {
+        let res = Cli::try_parse_from([
+            "nextver",
+            "next",
+            "2024.9.3",
+            "--format",
+            "[<EPOCH>!]<YYYY>.<MM>.<DD>",
+            "--sem-level",
+            "epoch",
+            "--date",
+            "2024-09-03",
+        ])
+        .unwrap();
+
+        assert_eq!(Ok(("1!2024.9.3".to_string(), ExitCode::Success,)), run(res));
+    }
+
+    #[test]
+    fn test_sem_revision() {
+        let res = Cli::try_parse_from([
+            "nextver",
+            "next",
+            "1.4.0.7",
+            "--format",
+            "<MAJOR>.<MINOR>.<PATCH>.<REVISION>",
+            "--sem-level",
+            "revision",
+        ])
+        .unwrap();
+
+        assert_eq!(Ok(("1.4.0.8".to_string(), ExitCode::Success,)), run(res));
+    }
+
+    #[test]
+    fn test_sem_metadata() {
+        let res = Cli::try_parse_from([
+            "nextver",
+            "next",
+            "1.4.0+ci.41",
+            "--format",
+            "<MAJOR>.<MINOR>.<PATCH><META>",
+            "--sem-level",
+            "patch",
+            "--metadata",
+            "ci.42",
+        ])
+        .unwrap();
+
+        assert_eq!(
+            Ok(("1.4.1+ci.42".to_string(), ExitCode::Success,)),
+            run(res)
+        );
+    }
+
+    #[test]
+    fn test_sem_by_and_to() {
+        let next_with = |option, value| {
+            let res = Cli::try_parse_from([
+                "nextver",
+                "next",
+                "3.2.1",
+                "--format",
+                "<MAJOR>.<MINOR>.<PATCH>",
+                "--sem-level",
+                "major",
+                option,
+                value,
+            ])
+            .unwrap();
+            run(res)
+        };
+
+        assert_eq!(
+            Ok(("5.0.0".to_string(), ExitCode::Success,)),
+            next_with("--by", "2")
+        );
+        assert_eq!(
+            Ok(("5.0.0".to_string(), ExitCode::Success,)),
+            next_with("--to", "5")
+        );
+        assert!(matches!(
+            next_with("--to", "3"),
+            Err(NextVerCliError::LibraryNextError(
+                NextError::NotGreater { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_cal_sem_by() {
+        let res = Cli::try_parse_from([
+            "nextver",
+            "next",
+            "2024.08.0",
+            "--format",
+            "<YYYY>.<0W>.<PATCH>",
+            "--date",
+            "2024-02-26",
+            "--sem-level",
+            "patch",
+            "--by",
+            "2",
+        ])
+        .unwrap();
+
+        assert_eq!(Err(NextVerCliError::StepWithoutSem), run(res));
+    }
+
+    #[test]
+    fn test_migrate_to_calsem() {
+        let res = Cli::try_parse_from([
+            "nextver",
+            "migrate",
+            "3.8.2",
+            "--format",
+            "<MAJOR>.<MINOR>.<PATCH>",
+            "--to-format",
+            "<YYYY>.<0M>.<PATCH>",
+            "--date",
+            "2024-10-18",
+        ])
+        .unwrap();
+
+        assert_eq!(Ok(("2024.10.0".to_string(), ExitCode::Success,)), run(res));
+    }
+
+    #[test]
+    fn test_migrate_to_sem() {
+        let migrate_with = |major: Option<&str>| {
+            let mut args = vec![
+                "nextver",
+                "migrate",
+                "2024.10.3",
+                "--format",
+                "<YYYY>.<0M>.<PATCH>",
+                "--to-format",
+                "[<EPOCH>!]<MAJOR>.<MINOR>.<PATCH>",
+            ];
+            if let Some(major) = major {
+                args.extend(["--major", major]);
+            }
+            run(Cli::try_parse_from(args).unwrap())
+        };
+
+        assert_eq!(
+            Ok(("2025.0.0".to_string(), ExitCode::Success,)),
+            migrate_with(Some("2025"))
+        );
+        assert_eq!(
+            Ok(("1!4.0.0".to_string(), ExitCode::Success,)),
+            migrate_with(Some("4"))
+        );
+        assert_eq!(Err(NextVerCliError::NoMajor), migrate_with(None));
+    }
 }
@@ -0,0 +1,489 @@
+use crate::{
+    format::{unescape_literal, Format, FormatToken},
+    scheme::Scheme,
+    specifier::Specifier,
+    version::{Version, VersionToken},
+};
+use alloc::{
+    borrow::{Cow, ToOwned},
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    cmp::Ordering,
+    fmt::{self, Display},
+};
+
+/// An error that occurred while parsing a requirement string.
+#[allow(clippy::module_name_repetitions)]
+#[non_exhaustive]
+#[derive(thiserror::Error, Debug, PartialEq)]
+pub enum RequirementError {
+    /// A comparator in the requirement string has no version, as in `>=` or `1.2,`.
+    #[error("comparators in requirement should not be empty, got `{requirement}`")]
+    EmptyComparator {
+        /// The requirement string
+        requirement: String,
+    },
+
+    /// The version of a comparator does not match the start of the format, optionally followed by
+    /// wildcards.
+    #[error(
+        "version in comparator `{comparator}` should match the start of format `{format_string}`"
+    )]
+    ComparatorFormatMismatch {
+        /// The comparator string
+        comparator: String,
+        /// The format string
+        format_string: String,
+    },
+}
+
+/// The operator of a [`Comparator`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Exact,
+    Greater,
+    GreaterEq,
+    Less,
+    LessEq,
+    Caret,
+    Tilde,
+}
+
+impl Op {
+    /// The operators and their symbols, with longer symbols first so that they are matched first.
+    const SYMBOLS: [(Op, &'static str); 7] = [
+        (Op::GreaterEq, ">="),
+        (Op::LessEq, "<="),
+        (Op::Greater, ">"),
+        (Op::Less, "<"),
+        (Op::Exact, "="),
+        (Op::Caret, "^"),
+        (Op::Tilde, "~"),
+    ];
+
+    fn symbol(self) -> &'static str {
+        Self::SYMBOLS
+            .iter()
+            .find_map(|&(op, symbol)| (op == self).then_some(symbol))
+            .unwrap_or_default()
+    }
+}
+
+/// A single condition of a [`Requirement`], such as `>=1.4`.
+#[derive(Debug, Clone)]
+struct Comparator<S: Scheme> {
+    op: Op,
+    /// The version text as written, including wildcards, for display.
+    text: String,
+    /// The tokens of the start of the format that the version text matched.
+    version: Version<'static, S>,
+}
+
+impl<S: Scheme> Comparator<S> {
+    fn parse(comparator_str: &str, format: &Format<S>) -> Result<Self, RequirementError> {
+        let comparator_str = comparator_str.trim();
+        let (op, text) = Op::SYMBOLS
+            .iter()
+            .find_map(|&(op, symbol)| comparator_str.strip_prefix(symbol).map(|text| (op, text)))
+            .unwrap_or((Op::Exact, comparator_str));
+        let text = text.trim();
+        let version = parse_partial(text, format).ok_or_else(|| {
+            RequirementError::ComparatorFormatMismatch {
+                comparator: comparator_str.to_owned(),
+                format_string: format.to_string(),
+            }
+        })?;
+        Ok(Self {
+            op,
+            text: text.to_owned(),
+            version,
+        })
+    }
+
+    fn matches(&self, version: &Version<'_, S>) -> bool {
+        let Some(ordering) = cmp_start(version, &self.version, self.version.tokens.len()) else {
+            return false;
+        };
+        match self.op {
+            Op::Exact => ordering.is_eq(),
+            Op::Greater => ordering.is_gt(),
+            Op::GreaterEq => ordering.is_ge(),
+            Op::Less => ordering.is_lt(),
+            Op::LessEq => ordering.is_le(),
+            Op::Caret | Op::Tilde => {
+                ordering.is_ge()
+                    && cmp_start(version, &self.version, self.fixed_len())
+                        .is_some_and(Ordering::is_eq)
+            }
+        }
+    }
+
+    /// The number of tokens of the comparator's version that a matching version must equal for a
+    /// caret or tilde comparator.
+    fn fixed_len(&self) -> usize {
+        let value_ends = self
+            .version
+            .tokens
+            .iter()
+            .enumerate()
+            .filter_map(|(index, token)| match token {
+                VersionToken::Value { value, spec, .. } => Some((index + 1, *value, *spec)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let fixed_value = match self.op {
+            // up to the first non-zero value, or the last value if they are all zero
+            Op::Caret => value_ends
+                .iter()
+                .position(|&(_, value, _)| value != 0)
+                .or_else(|| value_ends.len().checked_sub(1)),
+            // up to the second value that is not an epoch, or the last value if there are fewer
+            _ => value_ends
+                .iter()
+                .enumerate()
+                .filter(|(_, &(_, _, spec))| !spec.is_epoch())
+                .map(|(position, _)| position)
+                .nth(1)
+                .or_else(|| value_ends.len().checked_sub(1)),
+        };
+        fixed_value.map_or(0, |position| value_ends[position].0)
+    }
+}
+
+impl<S: Scheme> Display for Comparator<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.op.symbol(), self.text)
+    }
+}
+
+/// Compares the first `len` tokens of `version` and `partial`, or returns `None` if they are not
+/// comparable.
+fn cmp_start<S: Scheme>(
+    version: &Version<'_, S>,
+    partial: &Version<'_, S>,
+    len: usize,
+) -> Option<Ordering> {
+    version
+        .tokens
+        .get(..len)?
+        .partial_cmp(&partial.tokens[..len])
+}
+
+/// Parses the version text of a comparator, which may be just the start of a version of `format`,
+/// followed by wildcards (`*`) in place of the specifiers of the rest of the format.
+fn parse_partial<S: Scheme>(text: &str, format: &Format<S>) -> Option<Version<'static, S>> {
+    if text == "*" {
+        return Some(Version::new(Vec::new()));
+    }
+    let tokens = &format.tokens;
+    let parse_start = |body: &str, end: usize| {
+        let start = Format {
+            tokens: Cow::Borrowed(&tokens[..end]),
+        };
+        (!body.contains('*'))
+            .then(|| Version::parse(body, &start).ok())
+            .flatten()
+            .map(Version::into_owned)
+    };
+    // try to match the most tokens first, where the text may end at any specifier outside of an
+    // optional segment
+    (0..=tokens.len()).rev().find_map(|end| {
+        if !is_outside_optional(&tokens[..end]) {
+            None
+        } else if text.contains('*') {
+            // the wildcards are in place of the tokens after `end`
+            if end == tokens.len() || is_literal(&tokens[end]) {
+                return None;
+            }
+            wildcard_texts(&tokens[end..])
+                .iter()
+                .filter_map(|wildcards| text.strip_suffix(wildcards.as_str()))
+                .find_map(|body| parse_start(body, end))
+        } else if end == tokens.len() || (end > 0 && !is_literal(&tokens[end - 1])) {
+            parse_start(text, end)
+        } else {
+            None
+        }
+    })
+}
+
+fn is_literal<S: Scheme>(token: &FormatToken<S>) -> bool {
+    matches!(token, FormatToken::Literal(_))
+}
+
+/// Returns true if every optional segment started in `tokens` also ends in them.
+fn is_outside_optional<S: Scheme>(tokens: &[FormatToken<S>]) -> bool {
+    tokens
+        .iter()
+        .rev()
+        .find(|token| matches!(token, FormatToken::OptionalStart | FormatToken::OptionalEnd))
+        .is_none_or(|token| matches!(token, FormatToken::OptionalEnd))
+}
+
+/// Returns the texts that the starts of `tokens` would have with each specifier replaced by a
+/// wildcard, ending after each specifier.
+fn wildcard_texts<S: Scheme>(tokens: &[FormatToken<S>]) -> Vec<String> {
+    let mut text = String::new();
+    let mut texts = Vec::new();
+    // the length of the text up to the last specifier of the optional segment being read, since an
+    // optional segment ends after its last specifier
+    let mut optional_end = None;
+    for token in tokens {
+        match token {
+            FormatToken::Literal(literal) => text.push_str(&unescape_literal(literal)),
+            FormatToken::OptionalStart => optional_end = Some(text.len()),
+            FormatToken::OptionalEnd => {
+                if let Some(optional_end) = optional_end.take() {
+                    text.truncate(optional_end);
+                }
+                texts.push(text.clone());
+            }
+            _ if optional_end.is_some() => {
+                text.push('*');
+                optional_end = Some(text.len());
+            }
+            _ => {
+                text.push('*');
+                texts.push(text.clone());
+            }
+        }
+    }
+    texts
+}
+
+/// A requirement that versions of a [`Format`] may satisfy, such as `>=2024.9, <2025` or `^1.4`.
+///
+/// Create one with [`Format::new_requirement`](struct.Format.html#method.new_requirement), and
+/// check versions against it with [`Requirement::matches`].
+///
+/// # Syntax
+///
+/// A requirement is made of comparators, which are an operator followed by a version. The version
+/// may be just the start of a version of the format, as in `1.4` for `<MAJOR>.<MINOR>.<PATCH>`.
+/// Then, only the values it has are compared.
+///
+/// | Operator | Matches versions that are… |
+/// | --- | --- |
+/// | `=` (or none) | equal to the version |
+/// | `>` | greater than the version |
+/// | `>=` | greater than or equal to the version |
+/// | `<` | less than the version |
+/// | `<=` | less than or equal to the version |
+/// | `^` | greater than or equal to the version, with the same values up to its first non-zero value. E.g., `^1.4` matches `1.7.2`, but not `2.0.0`. |
+/// | `~` | greater than or equal to the version, with the same first two values (not counting an epoch). E.g., `~1.4` matches `1.4.9`, but not `1.5.0`. |
+///
+/// The specifiers at the end of the version may be replaced by wildcards, `*`, which match any
+/// value, as in `1.*` or `2024.*.*`. A requirement of just `*` matches every version.
+///
+/// Comparators separated by commas (`,`) must all match, and alternatives separated by `||` must
+/// have at least one that matches, as in `>=1.2, <1.5 || ^2`.
+///
+/// Like versions, build metadata is ignored and pre-releases are less than their final releases.
+///
+/// # Example
+///
+/// ```
+/// use nextver::prelude::*;
+///
+/// let format = CalSem::new_format("<YYYY>.<MM>.<PATCH>")?;
+/// let requirement = format.new_requirement(">=2024.9, <2025")?;
+/// assert!(requirement.matches(&format.new_version("2024.10.3")?));
+/// assert!(!requirement.matches(&format.new_version("2025.1.0")?));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct Requirement<S: Scheme> {
+    alternatives: Vec<Vec<Comparator<S>>>,
+}
+
+impl<S: Scheme> Requirement<S> {
+    pub(crate) fn parse(
+        requirement_str: &str,
+        format: &Format<S>,
+    ) -> Result<Self, RequirementError> {
+        let alternatives = requirement_str
+            .split("||")
+            .map(|alternative| {
+                alternative
+                    .split(',')
+                    .map(|comparator_str| {
+                        if comparator_str
+                            .trim()
+                            .trim_start_matches(['=', '>', '<', '^', '~'])
+                            .trim()
+                            .is_empty()
+                        {
+                            Err(RequirementError::EmptyComparator {
+                                requirement: requirement_str.to_owned(),
+                            })
+                        } else {
+                            Comparator::parse(comparator_str, format)
+                        }
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { alternatives })
+    }
+
+    /// Returns whether `version` satisfies this requirement. Versions of a different format than
+    /// the requirement's never do.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nextver::prelude::*;
+    ///
+    /// let format = Sem::new_format("<MAJOR>.<MINOR>.<PATCH>")?;
+    /// let requirement = format.new_requirement("^1.4 || 0.9.*")?;
+    /// assert!(requirement.matches(&format.new_version("1.7.2")?));
+    /// assert!(requirement.matches(&format.new_version("0.9.5")?));
+    /// assert!(!requirement.matches(&format.new_version("1.3.0")?));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn matches(&self, version: &Version<'_, S>) -> bool {
+        self.alternatives.iter().any(|comparators| {
+            comparators
+                .iter()
+                .all(|comparator| comparator.matches(version))
+        })
+    }
+}
+
+impl<S: Scheme> Display for Requirement<S> {
+    /// Displays a requirement with the operators of its comparators made explicit.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, comparators) in self.alternatives.iter().enumerate() {
+            if index > 0 {
+                f.write_str(" || ")?;
+            }
+            for (index, comparator) in comparators.iter().enumerate() {
+                if index > 0 {
+                    f.write_str(", ")?;
+                }
+                write!(f, "{comparator}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CalSem, Sem};
+
+    fn matches<S: Scheme>(format: &Format<S>, requirement: &str, version: &str) -> bool {
+        format
+            .new_requirement(requirement)
+            .unwrap()
+            .matches(&format.new_version(version).unwrap())
+    }
+
+    #[test]
+    fn test_sem_comparators() {
+        let format = Sem::new_format("<MAJOR>.<MINOR>.<PATCH><PRE>").unwrap();
+        let cases = [
+            ("=1.2.3", "1.2.3", true),
+            ("1.2.3", "1.2.4", false),
+            ("=1.2", "1.2.9", true),
+            (">1.2", "1.2.9", false),
+            (">1.2", "1.3.0", true),
+            (">=1.2.3", "1.2.3", true),
+            (">=1.2.3", "1.2.3-rc.1", false),
+            ("<1.2.3", "1.2.3-rc.1", true),
+            ("<1.2", "1.1.9", true),
+            ("<=1.2", "1.2.9", true),
+            ("<=1.2", "1.3.0", false),
+            ("^1.4", "1.7.2", true),
+            ("^1.4", "1.3.9", false),
+            ("^1.4", "2.0.0", false),
+            ("^0.4.1", "0.4.9", true),
+            ("^0.4.1", "0.5.0", false),
+            ("^0.0.3", "0.0.4", false),
+            ("^0.0", "0.0.9", true),
+            ("^0.0", "0.1.0", false),
+            ("~1.4.2", "1.4.9", true),
+            ("~1.4.2", "1.5.0", false),
+            ("~1", "1.9.0", true),
+            ("~1", "2.0.0", false),
+            ("1.*", "1.9.9", true),
+            ("1.*.*", "2.0.0", false),
+            (">=1.*", "2.0.0", true),
+            ("*", "0.0.0-alpha.0", true),
+            (">=1.2, <1.5", "1.4.0", true),
+            (">=1.2, <1.5", "1.5.0", false),
+            ("<1 || >=2, <3", "0.1.0", true),
+            ("<1 || >=2, <3", "1.1.0", false),
+            ("<1 || >=2, <3", "2.1.0", true),
+        ];
+        for (requirement, version, expected) in cases {
+            assert_eq!(
+                expected,
+                matches(&format, requirement, version),
+                "{requirement} {version}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_cal_sem_comparators() {
+        let format = CalSem::new_format("<YYYY>.<MM>.<PATCH>").unwrap();
+        assert!(matches(&format, ">=2024.9, <2025", "2024.10.3"));
+        assert!(!matches(&format, ">=2024.9, <2025", "2024.8.3"));
+        assert!(!matches(&format, ">=2024.9, <2025", "2025.1.0"));
+        assert!(matches(&format, "^2024.9", "2024.12.0"));
+        assert!(matches(&format, "2024.*", "2024.1.0"));
+    }
+
+    #[test]
+    fn test_optional_and_epoch() {
+        let format = Sem::new_format("<EPOCH>!<MAJOR>.<MINOR>[.<PATCH>]").unwrap();
+        assert!(matches(&format, "0!1.2.*", "0!1.2"));
+        assert!(matches(&format, "0!1.*", "0!1.2.3"));
+        assert!(matches(&format, "~0!1.2", "0!1.2.3"));
+        assert!(!matches(&format, "~0!1.2", "0!1.3"));
+        assert!(matches(&format, "^0!1", "0!1.3"));
+        assert!(!matches(&format, "^0!1", "1!1.3"));
+    }
+
+    #[test]
+    fn test_other_format() {
+        let format = Sem::new_format("<MAJOR>.<MINOR>").unwrap();
+        let other_format = Sem::new_format("v<MAJOR>.<MINOR>").unwrap();
+        let requirement = format.new_requirement(">=1.0").unwrap();
+        assert!(!requirement.matches(&other_format.new_version("v2.0").unwrap()));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let format = Sem::new_format("<MAJOR>.<MINOR>").unwrap();
+        for requirement in ["", ">=", "1.2,", "1.2 ||"] {
+            assert_eq!(
+                Err(RequirementError::EmptyComparator {
+                    requirement: requirement.to_owned()
+                }),
+                format.new_requirement(requirement).map(|_| ())
+            );
+        }
+        for comparator in ["1.2.3", "1.", "*.2", "1.**", ">=a"] {
+            assert_eq!(
+                Err(RequirementError::ComparatorFormatMismatch {
+                    comparator: comparator.to_owned(),
+                    format_string: "<MAJOR>.<MINOR>".to_owned()
+                }),
+                format.new_requirement(comparator).map(|_| ())
+            );
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        let format = Sem::new_format("<MAJOR>.<MINOR>").unwrap();
+        let requirement = format.new_requirement(" >= 1.2,<2||1.*  ").unwrap();
+        assert_eq!(">=1.2, <2 || =1.*", requirement.to_string());
+    }
+}
@@ -1,9 +1,14 @@
 use crate::{
     format::{Format, FormatError},
-    specifier::{CalSemLevel, CalSemSpecifier, CalSpecifier, SemSpecifier, Specifier},
-    version::{Date, NextError, Version, VersionError},
+    specifier::{
+        CalSemLevel, CalSemSpecifier, CalSpecifier, SemSpecifier, Specifier, SpecifierRules,
+        CALSEM_RULES, CAL_RULES, SEM_RULES,
+    },
+    version::{DateTime, NextError, Version, VersionError},
     SemLevel,
 };
+use alloc::string::{String, ToString};
+use core::fmt::Debug;
 
 /// An error that occurred in a function that composes calls for other crate functions with other
 /// error types.
@@ -23,69 +28,68 @@ pub enum CompositeError {
     Next(#[from] NextError),
 }
 
-pub(crate) mod priv_trait {
-    use super::Specifier as SpecifierT;
-    use core::fmt::Debug;
-
-    /// A private trait that is implemented by the public [`super::Scheme`] trait. This is used to
-    /// define methods that are only meant to be used internally, and not by the user.
-    pub(crate) trait Scheme: Sized + Debug + PartialEq + Eq {
-        /// The kinds of specifiers this scheme uses
-        type Specifier: SpecifierT;
-
-        /// The maximum number of specifiers that can be in a format string. For a given scheme,
-        /// this should equal the largest number of specifiers that can be in a valid format.
-        ///
-        /// See [`Scheme::MAX_TOKENS`].
-        const MAX_SPECIFIERS: usize;
-
-        /// The maximum number of tokens that can be in a [`Format`] or [`Version`]. To account for
-        /// literals being around the specifiers, this is equal to the maximum number of specifiers
-        /// times 2, plus 1. Think fenceposts.
-        ///
-        /// This is useful for pre-allocating a vector to hold the tokens.
-        ///
-        /// See [`Scheme::MAX_SPECIFIERS`].
-        const MAX_TOKENS: usize = Self::MAX_SPECIFIERS * 2 + 1;
-
-        /// The specifiers that can be used as the first specifier in a format string, comma
-        /// separated, for use in error messages.
-        fn first_variants_string() -> String {
-            arr_to_english_or(Self::Specifier::first_variants())
-        }
-
-        /// The specifiers that can be used as the last specifier in a format string, comma
-        /// separated, for use in error messages.
-        fn last_variants_string() -> String {
-            arr_to_english_or(Self::Specifier::last_variants())
-        }
-
-        /// Returns a human readable name of the scheme for error messages.
-        fn name() -> &'static str;
+/// The rules of all the specifiers of a scheme, as data that can be read in `const` contexts, for
+/// [`Scheme::RULES`].
+#[derive(Debug)]
+pub struct SchemeRules<'r, Sp: 'static> {
+    pub(crate) name: &'static str,
+    pub(crate) specifiers: &'r [SpecifierRules<Sp>],
+}
+
+impl<'r, Sp> SchemeRules<'r, Sp> {
+    /// Returns the rules of a scheme whose [`Scheme::name`] is `name`, where `specifiers` are the
+    /// rules of each of its [`Specifier::all`], in the same order.
+    #[must_use]
+    pub const fn new(name: &'static str, specifiers: &'r [SpecifierRules<Sp>]) -> Self {
+        Self { name, specifiers }
     }
+}
 
-    fn arr_to_english_or(specs: &'static [&'static impl SpecifierT]) -> String {
-        let spec_strings = specs
-            .iter()
-            .map(|spec| format!("`{spec}`"))
-            .collect::<Vec<_>>();
-        match spec_strings.as_slice() {
-            [] => String::new(),
-            [a] => a.to_string(),
-            [a, b] => format!("{a} or {b}"),
-            [firsts @ .., last] => {
-                let mut joined = firsts.join(", ");
-                joined.push_str(&format!(", or {last}"));
-                joined
-            }
-        }
+// manually implemented because the derive macros would want `Sp` to be `Clone`
+impl<Sp> Clone for SchemeRules<'_, Sp> {
+    fn clone(&self) -> Self {
+        *self
     }
 }
 
+impl<Sp> Copy for SchemeRules<'_, Sp> {}
+
 /// A trait for versioning schemes, which dictate the kinds of specifiers/values allowed in
 /// formats/versions and the rules for incrementing them.
-#[allow(private_bounds)]
-pub trait Scheme: priv_trait::Scheme {
+///
+/// Besides the built-in [`Sem`], [`Cal`], and [`CalSem`] schemes, implement this trait (and
+/// [`Specifier`](crate::Specifier)) to define your own. See [custom
+/// schemes](crate#custom-schemes).
+pub trait Scheme: Sized + Debug + PartialEq + Eq {
+    /// The kinds of specifiers this scheme uses
+    type Specifier: Specifier;
+
+    /// The maximum number of specifiers that can be in a format string. For a given scheme,
+    /// this should equal the largest number of specifiers that can be in a valid format.
+    ///
+    /// See [`Scheme::MAX_TOKENS`].
+    const MAX_SPECIFIERS: usize;
+
+    /// The maximum number of tokens that can be in a [`Format`] or [`Version`]. To account for
+    /// literals being around the specifiers, this is equal to the maximum number of specifiers
+    /// times 2, plus 1. Think fenceposts.
+    ///
+    /// This is useful for pre-allocating a vector to hold the tokens.
+    ///
+    /// See [`Scheme::MAX_SPECIFIERS`].
+    const MAX_TOKENS: usize = Self::MAX_SPECIFIERS * 2 + 1;
+
+    /// The rules of this scheme's specifiers, which [`format_str!`](crate::format_str) needs to
+    /// check format strings and make their formats at compile time. When given, they are also
+    /// used by [`Scheme::new_format`], so they should agree with the [`Specifier`] methods they
+    /// come from.
+    ///
+    /// Schemes without them can only parse formats at runtime.
+    const RULES: Option<SchemeRules<'static, Self::Specifier>> = None;
+
+    /// Returns a human readable name of the scheme for error messages.
+    fn name() -> &'static str;
+
     /// Parse a format string containing specifier and literal tokens into a [`Format`].
     ///
     /// The format string is made up of specifiers and literals. Specifiers indicate numeric values
@@ -119,7 +123,9 @@ pub trait Scheme: priv_trait::Scheme {
     ///   scheme.
     /// - [`FormatError::Incomplete`] if the last specifier is not acceptable for the scheme.
     /// - [`FormatError::NoSpecifiersInFormat`] if there are no specifiers in the format.
-    fn new_format(format_str: &str) -> Result<Format<Self>, FormatError> {
+    /// - [`FormatError::InvalidPreReleaseLabels`] if the labels given to a `<PRE>` specifier are
+    ///   not valid.
+    fn new_format(format_str: &str) -> Result<Format<'_, Self>, FormatError> {
         Format::parse(format_str)
     }
 
@@ -196,9 +202,17 @@ pub trait Scheme: priv_trait::Scheme {
 ///
 /// # Rules
 ///
-/// - The first specifier must be `MAJOR`.
-/// - `MINOR` and `PATCH` are not required. If `MINOR` is present, it must be after `MAJOR`, and if
-///   `PATCH` is present, it must be after `MINOR`.
+/// - The first specifier must be `MAJOR`, or `EPOCH` followed by `MAJOR`. See
+///   [epochs](crate#epochs).
+/// - `MINOR`, `PATCH`, and `REVISION` are not required. If `MINOR` is present, it must be after
+///   `MAJOR`, if `PATCH` is present, it must be after `MINOR`, and if `REVISION` is present, it
+///   must be after `PATCH`.
+/// - `PRE` is not required. If present, it must come after any other semantic specifier. See
+///   [pre-releases](crate#pre-releases).
+/// - `BUILD` is not required. If present, it must be last, except for `META`. See
+///   [build numbers](crate#build-numbers).
+/// - `META` is not required. If present, it must be last. See
+///   [build metadata](crate#build-metadata).
 /// - As for all schemes, arbitrary literals can be placed in the format string. For example, dots,
 ///   hyphens, or any other character(s) can be used, such as `v<MAJOR>#<MINOR>-p<PATCH>`.
 ///
@@ -206,6 +220,12 @@ pub trait Scheme: priv_trait::Scheme {
 ///
 /// - `<MAJOR>.<MINOR>.<PATCH>`: Major, minor, and patch. Dot-separated.
 /// - `v<MAJOR>.<MINOR>`: `v` followed by major and minor. Dot-separated.
+/// - `<MAJOR>.<MINOR>.<PATCH><PRE>`: Major, minor, patch, and an optional pre-release, like
+///   `1.4.0-rc.2` or `1.4.0`.
+/// - `<MAJOR>.<MINOR>.<PATCH>.<REVISION>`: Major, minor, patch, and revision, like `1.4.0.7`.
+///   Dot-separated.
+/// - `<MAJOR>.<MINOR>.<PATCH><PRE><META>`: Like [SemVer](https://semver.org/), with an optional
+///   pre-release and optional build metadata, like `1.4.0-rc.2+ci.42`.
 #[derive(Debug, PartialEq, Eq)]
 pub struct Sem;
 
@@ -246,16 +266,19 @@ impl Sem {
     }
 }
 
-impl Scheme for Sem {}
+const SEM_NAME: &str = "semantic";
 
-impl priv_trait::Scheme for Sem {
+impl Scheme for Sem {
     type Specifier = SemSpecifier;
 
-    // longest exemplar is <MAJOR><MINOR><PATCH>
-    const MAX_SPECIFIERS: usize = 3;
+    // longest exemplar is <EPOCH><MAJOR><MINOR><PATCH><REVISION><PRE><BUILD><META>
+    const MAX_SPECIFIERS: usize = 8;
+
+    const RULES: Option<SchemeRules<'static, Self::Specifier>> =
+        Some(SchemeRules::new(SEM_NAME, &SEM_RULES));
 
     fn name() -> &'static str {
-        "semantic"
+        SEM_NAME
     }
 }
 
@@ -263,17 +286,32 @@ impl priv_trait::Scheme for Sem {
 ///
 /// This scheme is less useful than [`CalSem`] because there is no way to increment it twice in the
 /// same period of its least significant specifier. For example, a version with format
-/// `<YYYY>.<MM>.<DD>` can only be incremented/updated once per day.
+/// `<YYYY>.<MM>.<DD>` can only be incremented/updated once per day. (The exception is a format
+/// ending with `<BUILD>`, which changes on every increment. See
+/// [build numbers](crate#build-numbers).)
 ///
 /// See the available specifiers for this scheme in the [table](crate#table).
 ///
 /// # Rules
 ///
-/// - The first specifier must be a year (`YYYY`, `YY`, or `0Y`).
+/// - The first specifier must be a year (`YYYY`, `YY`, `0Y`, or `GGGG`), or `EPOCH` followed by a
+///   year. See [epochs](crate#epochs).
 /// - For adjacent specifiers `a` and `b`, `b` must be relative to `a`:
-///   - month specifiers are relative to year ones (e.g., `<YYYY>.<MM>`)
+///   - month specifiers are relative to year ones (e.g., `<YYYY>.<MM>`). This includes the month
+///     name specifiers `MON` and `Month` (e.g., `<YYYY>-<MON>`)
 ///   - day specifiers are relative to month ones (e.g., `<YYYY>.<MM>.<DD>`)
 ///   - week specifiers are relative to year ones (and *not month ones*) (e.g., `<YYYY>.<WW>`)
+///   - quarter specifiers are relative to year ones, and month specifiers may be relative to them
+///     (e.g., `<YYYY>.<Q>.<MM>`)
+///   - day-of-year specifiers are relative to year ones (e.g., `<YY>.<DOY>`)
+///   - hour specifiers are relative to day or day-of-year ones, and minute specifiers are relative
+///     to hour ones (e.g., `<YYYY>.<MM>.<DD>.<HH><MI>`)
+///   - ISO week specifiers are relative to the ISO year, and nothing else is (e.g.,
+///     `<GGGG>.<IW>`)
+/// - `BUILD` is not required. If present, it must be last, except for `META`. See
+///   [build numbers](crate#build-numbers).
+/// - `META` is not required. If present, it must be last. See
+///   [build metadata](crate#build-metadata).
 /// - As for all schemes, arbitrary literals can be placed in the format string. For example, dots,
 ///   hyphens, or any other character(s) can be used, such as `y<YYYY>m<MM>d<DD>`.
 ///
@@ -282,6 +320,11 @@ impl priv_trait::Scheme for Sem {
 /// - `<YYYY>.<0M>.<0D>`: Full year, zero-padded month, and zero-padded day. Dot-separated.
 /// - `<0Y>.<0M>.<0D>`: Zero-padded year, zero-padded month, and zero-padded day. Dot-separated.
 /// - `<YYYY>-<0W>`: Full year and zero-padded week. Hyphen-separated.
+/// - `<GGGG>-<0IW>`: ISO year and zero-padded ISO week. Hyphen-separated.
+/// - `<YY>.<0DOY>`: Short year and zero-padded day of year. Dot-separated.
+/// - `<YYYY>-<MON>`: Full year and abbreviated month name, like `2024-Oct`. Hyphen-separated.
+/// - `<YYYY>.<0M>.<0D>.<0H><0MI>`: Full year, zero-padded month, zero-padded day, zero-padded
+///   hour, and zero-padded minute. Dot-separated.
 #[derive(Debug, PartialEq, Eq)]
 pub struct Cal;
 
@@ -317,7 +360,7 @@ impl Cal {
     pub fn next_version_string(
         format_str: &str,
         version_str: &str,
-        date: Date,
+        date: impl Into<DateTime>,
     ) -> Result<String, CompositeError> {
         let format = Self::new_format(format_str)?;
         let version = Version::parse(version_str, &format)?;
@@ -326,16 +369,19 @@ impl Cal {
     }
 }
 
-impl Scheme for Cal {}
+const CAL_NAME: &str = "calendar";
 
-impl priv_trait::Scheme for Cal {
+impl Scheme for Cal {
     type Specifier = CalSpecifier;
 
-    // longest exemplar is <YYYY><MM><DD>
-    const MAX_SPECIFIERS: usize = 3;
+    // longest exemplar is <EPOCH><YYYY><Q><MM><DD><HH><MI><BUILD><META>
+    const MAX_SPECIFIERS: usize = 9;
+
+    const RULES: Option<SchemeRules<'static, Self::Specifier>> =
+        Some(SchemeRules::new(CAL_NAME, &CAL_RULES));
 
     fn name() -> &'static str {
-        "calendar"
+        CAL_NAME
     }
 }
 
@@ -349,13 +395,29 @@ impl priv_trait::Scheme for Cal {
 ///
 /// # Rules
 ///
-/// - The first specifier must be a year (`YYYY`, `YY`, or `0Y`).
+/// - The first specifier must be a year (`YYYY`, `YY`, `0Y`, or `GGGG`), or `EPOCH` followed by a
+///   year. See [epochs](crate#epochs).
 /// - For adjacent *calendar* specifiers `a` and `b`, `b` must be relative to `a`:
-///   - month specifiers are relative to year ones (e.g., `<YYYY>.<MM>`)
+///   - month specifiers are relative to year ones (e.g., `<YYYY>.<MM>`). This includes the month
+///     name specifiers `MON` and `Month` (e.g., `<YYYY>-<MON>`)
 ///   - day specifiers are relative to month ones (e.g., `<YYYY>.<MM>.<DD>`)
 ///   - week specifiers are relative to year ones (and *not month ones*) (e.g., `<YYYY>.<WW>`)
+///   - quarter specifiers are relative to year ones, and month specifiers may be relative to them
+///     (e.g., `<YYYY>.<Q>.<MM>`)
+///   - day-of-year specifiers are relative to year ones (e.g., `<YY>.<DOY>`)
+///   - hour specifiers are relative to day or day-of-year ones, and minute specifiers are relative
+///     to hour ones (e.g., `<YYYY>.<MM>.<DD>.<HH><MI>`)
+///   - ISO week specifiers are relative to the ISO year, and nothing else is (e.g.,
+///     `<GGGG>.<IW>`)
 /// - The format must end with the `PATCH` semantic specifier.
 ///   - `MINOR` may optionally come before `PATCH` if more granularity is desired.
+///   - `REVISION` may optionally come after `PATCH` if even more granularity is desired.
+///   - `PRE` may optionally come after those for pre-releases. See
+///     [pre-releases](crate#pre-releases).
+///   - `BUILD` may optionally come after those for a build number. See
+///     [build numbers](crate#build-numbers).
+///   - `META` may optionally come last for build metadata. See
+///     [build metadata](crate#build-metadata).
 /// - As for all schemes, arbitrary literals can be placed in the format string. For example, dots,
 ///   hyphens, or any other character(s) can be used, such as `y<YYYY>m<MM>d<DD>-p<PATCH>`.
 ///
@@ -367,6 +429,11 @@ impl priv_trait::Scheme for Cal {
 ///   Dot-separated.
 /// - `<YYYY>.<0W>-<MINOR>.<PATCH>`: Full year, zero-padded week, minor, and patch. Dot- and
 ///   hyphen-separated.
+/// - `<GGGG>.<0IW>.<PATCH>`: ISO year, zero-padded ISO week, and patch. Dot-separated.
+/// - `<YY>.<MON>.<PATCH>`: Short year, abbreviated month name, and patch, like `24.Oct.2`.
+///   Dot-separated.
+/// - `<YYYY>.Q<Q>.<PATCH>`: Full year, quarter, and patch. Dot-separated, with a literal `Q`
+///   before the quarter.
 #[derive(Debug, PartialEq, Eq)]
 pub struct CalSem;
 
@@ -403,7 +470,7 @@ impl CalSem {
     pub fn next_version_string(
         format_str: &str,
         version_str: &str,
-        date: Date,
+        date: impl Into<DateTime>,
         level: CalSemLevel,
     ) -> Result<String, CompositeError> {
         let format = Self::new_format(format_str)?;
@@ -413,15 +480,18 @@ impl CalSem {
     }
 }
 
-impl Scheme for CalSem {}
+const CALSEM_NAME: &str = "calendar-semantic";
 
-impl priv_trait::Scheme for CalSem {
+impl Scheme for CalSem {
     type Specifier = CalSemSpecifier;
 
-    // longest exemplar is <YYYY><MM><DD><MINOR><PATCH>
-    const MAX_SPECIFIERS: usize = 5;
+    // longest exemplar is <EPOCH><YYYY><Q><MM><DD><HH><MI><MINOR><PATCH><REVISION><PRE><BUILD><META>
+    const MAX_SPECIFIERS: usize = 13;
+
+    const RULES: Option<SchemeRules<'static, Self::Specifier>> =
+        Some(SchemeRules::new(CALSEM_NAME, &CALSEM_RULES));
 
     fn name() -> &'static str {
-        "calendar-semantic"
+        CALSEM_NAME
     }
 }
@@ -1,13 +1,83 @@
-use crate::version::NextError;
-use chrono::{Datelike, NaiveDate};
-use core::fmt::{self, Debug, Display};
-
-pub(crate) type SpecValue = u32;
+use crate::{format::bytes_eq, version::NextError};
+use alloc::sync::Arc;
+use alloc::{
+    borrow::ToOwned,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use chrono::{Datelike, NaiveDateTime, Timelike};
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug, Display},
+    hash::{Hash, Hasher},
+};
+
+/// The numeric value of a [`Specifier`] in a version.
+pub type SpecValue = u32;
 pub(crate) type SpecValueResult = Result<SpecValue, NextError>;
-type NextDateResultFn = fn(NaiveDate) -> SpecValueResult;
-type NextDateFn = fn(NaiveDate) -> SpecValue;
+type NextDateResultFn = fn(NaiveDateTime) -> SpecValueResult;
+type NextDateFn = fn(NaiveDateTime) -> SpecValue;
+
+/// Returns `value` plus one, or a [`NextError::ValueOverflow`] for `spec` if that is greater than
+/// [`SpecValue::MAX`].
+pub(crate) fn increment(value: SpecValue, spec: &impl Display) -> SpecValueResult {
+    Step::By(1).apply(value, spec)
+}
+
+/// Returns `value` minus one, or a [`NextError::ValueUnderflow`] for `spec` if it is zero.
+pub(crate) fn decrement(value: SpecValue, spec: &impl Display) -> SpecValueResult {
+    value
+        .checked_sub(1)
+        .ok_or_else(|| NextError::ValueUnderflow {
+            spec: spec.to_string(),
+        })
+}
+
+/// Returns the value of `spec` in the next version when `level` is incremented, where only `level`
+/// and the build number are incremented. See [`Specifier::bump_value`].
+fn default_bump_value<Sp: Specifier>(spec: &Sp, value: SpecValue, level: &Sp) -> SpecValueResult {
+    if spec == level || spec.is_build() {
+        increment(value, spec)
+    } else {
+        Ok(value)
+    }
+}
+
+/// Returns a [`NextError::SpecifierHasNoValue`] for `spec`, which has no numeric value to change,
+/// like `<PRE>` or `<META>`.
+fn no_value(spec: &impl Display) -> SpecValueResult {
+    Err(NextError::SpecifierHasNoValue {
+        spec: spec.to_string(),
+    })
+}
 
-fn full_year_next(date: NaiveDate) -> SpecValueResult {
+/// How the value of the specifier of a level changes when a version is incremented.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) enum Step {
+    /// Add this amount to the value.
+    By(SpecValue),
+    /// Replace the value with this one.
+    To(SpecValue),
+}
+
+impl Step {
+    /// Returns `value` after this step, or a [`NextError::ValueOverflow`] for `spec` if that is
+    /// greater than [`SpecValue::MAX`].
+    pub(crate) fn apply(self, value: SpecValue, spec: &impl Display) -> SpecValueResult {
+        match self {
+            Step::By(amount) => value
+                .checked_add(amount)
+                .ok_or_else(|| NextError::ValueOverflow {
+                    spec: spec.to_string(),
+                    value,
+                }),
+            Step::To(new_value) => Ok(new_value),
+        }
+    }
+}
+
+fn full_year_next(date: NaiveDateTime) -> SpecValueResult {
     // Note: Spec doesn't comment about years that are not 4-digit, so allow them
     let year = date.year();
     if year < 0 {
@@ -21,7 +91,7 @@ fn full_year_next(date: NaiveDate) -> SpecValueResult {
     }
 }
 
-fn short_year_next(date: NaiveDate) -> SpecValueResult {
+fn short_year_next(date: NaiveDateTime) -> SpecValueResult {
     let year = date.year();
     // while `year % 100` might seem like the right call, the spec allows this to be >=100 so that,
     // for example, `2001`, `2101`, `3001` are disambiguated as 1, 101, and 1001, respectively.
@@ -35,7 +105,32 @@ fn short_year_next(date: NaiveDate) -> SpecValueResult {
     }
 }
 
-fn weeks_from_sunday_next(date: NaiveDate) -> SpecValue {
+/// Returns the full year of a `value` of a year specifier with `type_`, or `None` if it would be
+/// greater than [`SpecValue::MAX`].
+pub(crate) fn full_year(type_: &YearType, value: SpecValue) -> Option<SpecValue> {
+    match type_ {
+        YearType::Full => Some(value),
+        YearType::Short | YearType::ZeroPadded => value.checked_add(2000),
+    }
+}
+
+fn iso_year_next(date: NaiveDateTime) -> SpecValueResult {
+    // the ISO week-numbering year can differ from the calendar year for the first and last few
+    // days of the year, e.g., 2024-12-30 is in ISO week 1 of 2025.
+    let year = date.iso_week().year();
+    if year < 0 {
+        Err(NextError::NegativeYearValue { year })
+    } else {
+        #[allow(clippy::cast_sign_loss)]
+        Ok(year as SpecValue)
+    }
+}
+
+fn iso_week_next(date: NaiveDateTime) -> SpecValue {
+    date.iso_week().week()
+}
+
+fn weeks_from_sunday_next(date: NaiveDateTime) -> SpecValue {
     let days_from_sunday = date.weekday().num_days_from_sunday();
 
     // This formula taken from a internal (`pub(crate)`) API inside chrono::NaiveDate. I'm unsure
@@ -43,86 +138,452 @@ fn weeks_from_sunday_next(date: NaiveDate) -> SpecValue {
     (6 + date.ordinal() - days_from_sunday) / 7
 }
 
-fn month_next(date: NaiveDate) -> SpecValue {
+fn quarter_next(date: NaiveDateTime) -> SpecValue {
+    date.month0() / 3 + 1
+}
+
+fn month_next(date: NaiveDateTime) -> SpecValue {
     date.month()
 }
 
-fn day_next(date: NaiveDate) -> SpecValue {
+fn day_next(date: NaiveDateTime) -> SpecValue {
     date.day()
 }
 
-pub(crate) trait Specifier: PartialEq + Eq + Debug + Display + Sized + 'static {
+fn day_of_year_next(date: NaiveDateTime) -> SpecValue {
+    date.ordinal()
+}
+
+fn hour_next(date: NaiveDateTime) -> SpecValue {
+    date.hour()
+}
+
+fn minute_next(date: NaiveDateTime) -> SpecValue {
+    date.minute()
+}
+
+/// A kind of value in a version, written as `<...>` in a format string, such as `<MAJOR>`.
+///
+/// Each [`Scheme`](crate::Scheme) has a set of specifiers, and implement this trait for a type
+/// (usually an enum) to define your own. The set is given by [`Specifier::all`], and the rules of
+/// which may follow which by [`Specifier::first_variants`], [`Specifier::last_variants`], and
+/// [`Specifier::can_be_left_adjacent_to`]. See [custom schemes](crate#custom-schemes) for an
+/// example.
+///
+/// Specifiers are compared by their [`format_pattern`](Specifier::format_pattern) when comparing
+/// versions, so each specifier of a scheme should have its own pattern.
+pub trait Specifier: PartialEq + Eq + Debug + Display + Sized + 'static {
+    /// Returns the pattern of this specifier in a format string, including its angle brackets, such
+    /// as `b"<MAJOR>"`. It should be printable ASCII, with at least one character and no `<`, `>`,
+    /// `[`, `]`, or `:` between the brackets, or parsing a format returns a
+    /// [`FormatError::InvalidSpecifierPattern`](crate::FormatError::InvalidSpecifierPattern).
     fn format_pattern(&self) -> &'static [u8];
 
+    /// Returns the minimum number of digits values are zero-padded to when formatted, or `0` for no
+    /// padding.
     fn format_width(&self) -> usize;
 
+    /// Returns true if values are zero-padded when formatted.
     fn has_zero_padding(&self) -> bool {
         self.format_width() > 0
     }
 
+    /// Returns `value` formatted as it appears in a version string. A value with no name in
+    /// [`Specifier::value_names`] is formatted as digits.
     fn format_value(&self, value: SpecValue) -> String {
-        format!("{:0len$}", value, len = self.format_width())
+        let name = self.value_names().and_then(|names| {
+            let idx = usize::try_from(value.checked_sub(1)?).ok()?;
+            names.get(idx)
+        });
+        match name {
+            Some(name) => (*name).to_string(),
+            None => format!("{:0len$}", value, len = self.format_width()),
+        }
     }
 
+    /// Returns the names of this specifier's values, starting from a value of `1`, if the values
+    /// are rendered as text instead of digits.
+    fn value_names(&self) -> Option<&'static [&'static str]> {
+        None
+    }
+
+    /// Returns the greatest value of this specifier, like `4` for the quarter, `<Q>`. A version
+    /// string with a greater value does not parse.
+    fn max_value(&self) -> SpecValue {
+        SpecValue::MAX
+    }
+
+    /// Returns the number of digits a value may take up when parsing a version string.
     fn parse_width(&self) -> ParseWidth;
 
+    /// Returns true if a value may be zero.
     fn can_be_zero(&self) -> bool;
 
+    /// Returns the specifiers that may be first in a format.
     fn first_variants() -> &'static [&'static Self];
 
+    /// Returns the specifiers that may be last in a format.
     fn last_variants() -> &'static [&'static Self];
 
+    /// Returns true if this specifier may be first in a format.
     fn can_be_first(&self) -> bool {
         Self::first_variants().contains(&self)
     }
 
+    /// Returns true if this specifier may be last in a format.
     fn can_be_last(&self) -> bool {
         Self::last_variants().contains(&self)
     }
 
+    /// Returns all the specifiers of this kind, which are the ones a format may contain.
     fn all() -> &'static [&'static Self];
 
+    /// Returns true if `other` may be the next specifier after this one in a format.
     fn can_be_left_adjacent_to(&self, other: &Self) -> bool;
+
+    /// Returns true if this is the pre-release specifier, whose values are a label and a number
+    /// instead of a single number.
+    fn is_pre_release(&self) -> bool {
+        false
+    }
+
+    /// Returns true if this is the build metadata specifier, whose values are text instead of a
+    /// number.
+    fn is_metadata(&self) -> bool {
+        false
+    }
+
+    /// Returns true if this is the build number specifier, which increments on every bump and is
+    /// never reset.
+    fn is_build(&self) -> bool {
+        false
+    }
+
+    /// Returns true if this is the epoch specifier, which is only incremented explicitly and is
+    /// never reset.
+    fn is_epoch(&self) -> bool {
+        false
+    }
+
+    /// Returns true if this specifier may be in an optional segment, as in `[.<PATCH>]`. An absent
+    /// segment's values are zero, so only specifiers that can be zero may be.
+    fn can_be_optional(&self) -> bool {
+        self.can_be_zero() && !self.is_pre_release() && !self.is_metadata()
+    }
+
+    /// Returns true if this specifier may be given an explicit, fixed width, as in `<PATCH:3>`.
+    /// Only specifiers whose values are unpadded digits can be.
+    fn can_have_width(&self) -> bool {
+        !self.has_zero_padding()
+            && self.value_names().is_none()
+            && !self.is_pre_release()
+            && !self.is_metadata()
+    }
+
+    /// Returns the least explicit width this specifier may be given, as in `<YYYY:4>`. This is
+    /// one by default. Override it for specifiers whose values always have more digits.
+    fn min_width(&self) -> usize {
+        1
+    }
+
+    /// Returns the value of this specifier in the next version when the value of `level` is
+    /// incremented by [`Version::bump`](crate::Version::bump).
+    ///
+    /// By default, `level` and the build number are incremented, and other values are kept.
+    /// Override this to reset less significant values, as in `1.2.3` to `1.3.0`. The built-in
+    /// specifiers change as in their schemes' `next` methods.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`NextError`] if the value cannot change, such as a
+    /// [`NextError::ValueOverflow`] if it would be greater than [`SpecValue::MAX`].
+    fn bump_value(&self, value: SpecValue, level: &Self) -> Result<SpecValue, NextError> {
+        default_bump_value(self, value, level)
+    }
 }
 
-pub(crate) enum ParseWidth {
+/// The number of characters a [`Specifier`]'s value may take up when parsing a version string.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum ParseWidth {
+    /// One or more characters
     AtLeastOne,
+    /// Two or more characters
     AtLeastTwo,
+    /// Exactly one character
+    One,
+    /// One or two characters
     OneOrTwo,
+    /// One to three characters
+    OneToThree,
+    /// Exactly two characters
     Two,
+    /// Exactly three characters
+    Three,
+    /// Three to nine characters
+    ThreeToNine,
 }
 
 impl ParseWidth {
-    pub(crate) fn min_width(&self) -> usize {
+    pub(crate) fn min_width(self) -> usize {
         match self {
-            Self::OneOrTwo | Self::AtLeastOne => 1,
+            Self::One | Self::OneOrTwo | Self::OneToThree | Self::AtLeastOne => 1,
             Self::AtLeastTwo | Self::Two => 2,
+            Self::Three | Self::ThreeToNine => 3,
         }
     }
 
-    pub(crate) fn max_width(&self) -> usize {
+    pub(crate) fn max_width(self) -> usize {
         match self {
             Self::AtLeastOne | Self::AtLeastTwo => usize::MAX,
+            Self::One => 1,
             Self::OneOrTwo | Self::Two => 2,
+            Self::OneToThree | Self::Three => 3,
+            Self::ThreeToNine => 9,
+        }
+    }
+}
+
+/// The greatest number of specifiers a scheme with [`SchemeRules`](crate::SchemeRules) may have,
+/// because [`SpecifierRules`] keep the specifiers that may follow one as bits.
+pub(crate) const MAX_SPECIFIER_RULES: usize = u128::BITS as usize;
+
+/// The rules of where a [`Specifier`] may be in a format string, as data that can be read in
+/// `const` contexts, where the [`Specifier`] methods they come from cannot be called.
+///
+/// A scheme gives them in [`Scheme::RULES`](crate::Scheme::RULES) so that
+/// [`format_str!`](crate::format_str) can make its formats at compile time. Start from
+/// [`SpecifierRules::new`], which may not be first, last, optional, or followed by anything, and
+/// add to it with the other methods. See [custom schemes](crate#custom-schemes) for an example.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, PartialEq, Eq)]
+pub struct SpecifierRules<Sp: 'static> {
+    pub(crate) spec: &'static Sp,
+    pub(crate) pattern: &'static [u8],
+    pub(crate) first: bool,
+    pub(crate) last: bool,
+    pub(crate) pre_release: bool,
+    pub(crate) optional: bool,
+    /// The least explicit width this specifier may be given, or `None` if it may not be given one.
+    pub(crate) min_width: Option<usize>,
+    /// A bit for each specifier that may follow this one, by its index in its scheme's rules.
+    pub(crate) followers: u128,
+}
+
+impl<Sp> SpecifierRules<Sp> {
+    /// Returns the rules of `spec`, whose [`Specifier::format_pattern`] is `pattern`.
+    #[must_use]
+    pub const fn new(spec: &'static Sp, pattern: &'static [u8]) -> Self {
+        Self {
+            spec,
+            pattern,
+            first: false,
+            last: false,
+            pre_release: false,
+            optional: false,
+            min_width: None,
+            followers: 0,
+        }
+    }
+
+    /// Returns these rules for a specifier that may be first, as in [`Specifier::can_be_first`].
+    #[must_use]
+    pub const fn first(mut self) -> Self {
+        self.first = true;
+        self
+    }
+
+    /// Returns these rules for a specifier that may be last, as in [`Specifier::can_be_last`].
+    #[must_use]
+    pub const fn last(mut self) -> Self {
+        self.last = true;
+        self
+    }
+
+    /// Returns these rules for the pre-release specifier, as in [`Specifier::is_pre_release`].
+    #[must_use]
+    pub const fn pre_release(mut self) -> Self {
+        self.pre_release = true;
+        self
+    }
+
+    /// Returns these rules for a specifier that may be in an optional segment, as in
+    /// [`Specifier::can_be_optional`].
+    #[must_use]
+    pub const fn optional(mut self) -> Self {
+        self.optional = true;
+        self
+    }
+
+    /// Returns these rules for a specifier that may be given an explicit width of at least
+    /// `min_width`, as in [`Specifier::can_have_width`] and [`Specifier::min_width`].
+    #[must_use]
+    pub const fn width(mut self, min_width: usize) -> Self {
+        // widths are at least one, whatever a custom specifier says
+        self.min_width = Some(if min_width == 0 { 1 } else { min_width });
+        self
+    }
+
+    /// Returns these rules for a specifier that may be followed by the specifiers at `indices` in
+    /// the scheme's rules, as in [`Specifier::can_be_left_adjacent_to`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if an index is 128 or more, since a scheme with rules has at most 128 specifiers.
+    #[must_use]
+    pub const fn followed_by(mut self, indices: &[usize]) -> Self {
+        let mut index = 0;
+        while index < indices.len() {
+            assert!(
+                indices[index] < MAX_SPECIFIER_RULES,
+                "specifier index should be less than 128"
+            );
+            self.followers |= 1 << indices[index];
+            index += 1;
         }
+        self
+    }
+
+    /// Returns true if the specifier at `index` in the scheme's rules may follow this one.
+    pub(crate) const fn is_followed_by(&self, index: usize) -> bool {
+        index < MAX_SPECIFIER_RULES && self.followers & (1 << index) != 0
     }
 }
 
+impl<Sp: Specifier> SpecifierRules<Sp> {
+    /// Returns the rules of each of [`Specifier::all`] from their [`Specifier`] methods, for
+    /// schemes without [`Scheme::RULES`](crate::Scheme::RULES).
+    pub(crate) fn derive_all() -> Vec<Self> {
+        let all = Sp::all();
+        all.iter()
+            .map(|&spec| {
+                let mut rules = Self::new(spec, spec.format_pattern());
+                rules.first = spec.can_be_first();
+                rules.last = spec.can_be_last();
+                rules.pre_release = spec.is_pre_release();
+                rules.optional = spec.can_be_optional();
+                if spec.can_have_width() {
+                    rules = rules.width(spec.min_width());
+                }
+                // more specifiers than bits are rejected when parsing
+                rules.followers = all
+                    .iter()
+                    .take(MAX_SPECIFIER_RULES)
+                    .enumerate()
+                    .filter(|(_, next)| spec.can_be_left_adjacent_to(next))
+                    .fold(0, |followers, (index, _)| followers | 1 << index);
+                rules
+            })
+            .collect()
+    }
+}
+
+// manually implemented because the derive macros would want `Sp` to be `Clone`
+impl<Sp> Clone for SpecifierRules<Sp> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Sp> Copy for SpecifierRules<Sp> {}
+
+/// Makes the [`SpecifierRules`] of the built-in specifiers in `$all` from their `const` methods,
+/// where `$first` and `$last` are the ones that may be first and last.
+macro_rules! builtin_rules {
+    ($all:expr, $first:expr, $last:expr) => {{
+        let mut rules = [SpecifierRules::new($all[0], b""); $all.len()];
+        let mut index = 0;
+        while index < $all.len() {
+            let spec = $all[index];
+            let mut this = SpecifierRules::new(spec, spec.pattern());
+            let mut other = 0;
+            while other < $first.len() {
+                if bytes_eq($first[other].pattern(), spec.pattern()) {
+                    this = this.first();
+                }
+                other += 1;
+            }
+            let mut other = 0;
+            while other < $last.len() {
+                if bytes_eq($last[other].pattern(), spec.pattern()) {
+                    this = this.last();
+                }
+                other += 1;
+            }
+            if spec.pre_release() {
+                this = this.pre_release();
+            }
+            // like the defaults of `Specifier::can_be_optional` and `Specifier::can_have_width`
+            if spec.zeroable() && !spec.pre_release() && !spec.metadata() {
+                this = this.optional();
+            }
+            if spec.width() == 0
+                && spec.names().is_none()
+                && !spec.pre_release()
+                && !spec.metadata()
+            {
+                this = this.width(spec.least_width());
+            }
+            let mut next = 0;
+            while next < $all.len() {
+                if spec.precedes($all[next]) {
+                    this.followers |= 1 << next;
+                }
+                next += 1;
+            }
+            rules[index] = this;
+            index += 1;
+        }
+        rules
+    }};
+}
+
+const EPOCH_FORMAT_PATTERN: &[u8] = b"<EPOCH>";
+
 const MAJOR_FORMAT_PATTERN: &[u8] = b"<MAJOR>";
 
 const MINOR_FORMAT_PATTERN: &[u8] = b"<MINOR>";
 
 const PATCH_FORMAT_PATTERN: &[u8] = b"<PATCH>";
 
+const REVISION_FORMAT_PATTERN: &[u8] = b"<REVISION>";
+
+const PRE_RELEASE_FORMAT_PATTERN: &[u8] = b"<PRE>";
+pub(crate) const PRE_RELEASE_DEFAULT_LABELS: &str = "alpha|beta|rc";
+
+const METADATA_FORMAT_PATTERN: &[u8] = b"<META>";
+
+const BUILD_FORMAT_PATTERN: &[u8] = b"<BUILD>";
+
+/// Returns true if `metadata` is valid build metadata: non-empty, and only ASCII alphanumerics,
+/// hyphens, and dots.
+pub(crate) fn is_valid_metadata(metadata: &[u8]) -> bool {
+    !metadata.is_empty()
+        && metadata
+            .iter()
+            .all(|&c| c.is_ascii_alphanumeric() || c == b'-' || c == b'.')
+}
+
+/// Formats build metadata, where `None` is no metadata.
+pub(crate) fn format_metadata(metadata: Option<&str>) -> String {
+    match metadata {
+        Some(metadata) => format!("+{metadata}"),
+        None => String::new(),
+    }
+}
+
 const SEM_FORMAT_WIDTH: usize = 0;
 const SEM_PARSE_WIDTH: ParseWidth = ParseWidth::AtLeastOne;
 const SEM_CAN_BE_ZERO: bool = true;
+const SEM_MIN_WIDTH: usize = 1;
 
 const YEAR_FULL_FORMAT_STRINGS: &[u8] = b"<YYYY>";
 const YEAR_FULL_FORMAT_WIDTH: usize = 0;
 const YEAR_FULL_NEXT_FN: NextDateResultFn = full_year_next;
 const YEAR_FULL_CAN_BE_ZERO: bool = true; // note that 0 is 1 BCE
 const YEAR_FULL_PARSE_WIDTH: ParseWidth = ParseWidth::AtLeastOne;
+// years since 1000 have four digits, so a smaller fixed width would never fit
+const YEAR_FULL_MIN_WIDTH: usize = 4;
 
 const YEAR_SHORT_FORMAT_STRINGS: &[u8] = b"<YY>";
 const YEAR_SHORT_FORMAT_WIDTH: usize = 0;
@@ -136,6 +597,20 @@ const YEAR_ZERO_PADDED_PARSE_WIDTH: ParseWidth = ParseWidth::AtLeastTwo;
 
 const YEAR_SHORT_AND_ZERO_PADDED_NEXT_FN: NextDateResultFn = short_year_next;
 
+const ISO_YEAR_FORMAT_STRINGS: &[u8] = b"<GGGG>";
+const ISO_YEAR_FORMAT_WIDTH: usize = 0;
+const ISO_YEAR_NEXT_FN: NextDateResultFn = iso_year_next;
+const ISO_YEAR_CAN_BE_ZERO: bool = true; // same as full year
+const ISO_YEAR_PARSE_WIDTH: ParseWidth = ParseWidth::AtLeastOne;
+const ISO_YEAR_MIN_WIDTH: usize = 4; // same as full year
+
+const QUARTER_FORMAT_STRINGS: &[u8] = b"<Q>";
+const QUARTER_FORMAT_WIDTH: usize = 0;
+const QUARTER_CAN_BE_ZERO: bool = false;
+const QUARTER_PARSE_WIDTH: ParseWidth = ParseWidth::One;
+const QUARTER_NEXT_FN: NextDateFn = quarter_next;
+const QUARTER_MAX_VALUE: SpecValue = 4;
+
 const MONTH_SHORT_FORMAT_STRINGS: &[u8] = b"<MM>";
 const MONTH_SHORT_FORMAT_WIDTH: usize = 0;
 
@@ -145,6 +620,32 @@ const MONTH_ZERO_PADDED_FORMAT_WIDTH: usize = 2;
 const MONTH_CAN_BE_ZERO: bool = false;
 const MONTH_NEXT_FN: NextDateFn = month_next;
 
+const MONTH_ABBREVIATED_FORMAT_STRINGS: &[u8] = b"<MON>";
+const MONTH_ABBREVIATED_PARSE_WIDTH: ParseWidth = ParseWidth::Three;
+const MONTH_ABBREVIATED_NAMES: &[&str] = &[
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+const MONTH_FULL_FORMAT_STRINGS: &[u8] = b"<Month>";
+const MONTH_FULL_PARSE_WIDTH: ParseWidth = ParseWidth::ThreeToNine;
+const MONTH_FULL_NAMES: &[&str] = &[
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+// names are never padded
+const MONTH_NAME_FORMAT_WIDTH: usize = 0;
+
 const WEEK_SHORT_FORMAT_STRINGS: &[u8] = b"<WW>";
 const WEEK_SHORT_FORMAT_WIDTH: usize = 0;
 
@@ -154,6 +655,15 @@ const WEEK_ZERO_PADDED_FORMAT_WIDTH: usize = 2;
 const WEEK_CAN_BE_ZERO: bool = true;
 const WEEK_NEXT_FN: NextDateFn = weeks_from_sunday_next;
 
+const ISO_WEEK_SHORT_FORMAT_STRINGS: &[u8] = b"<IW>";
+const ISO_WEEK_SHORT_FORMAT_WIDTH: usize = 0;
+
+const ISO_WEEK_ZERO_PADDED_FORMAT_STRINGS: &[u8] = b"<0IW>";
+const ISO_WEEK_ZERO_PADDED_FORMAT_WIDTH: usize = 2;
+
+const ISO_WEEK_CAN_BE_ZERO: bool = false;
+const ISO_WEEK_NEXT_FN: NextDateFn = iso_week_next;
+
 const DAY_SHORT_FORMAT_STRINGS: &[u8] = b"<DD>";
 const DAY_SHORT_FORMAT_WIDTH: usize = 0;
 
@@ -165,76 +675,474 @@ const MONTH_WEEK_DAY_SHORT_PARSE_WIDTH: ParseWidth = ParseWidth::OneOrTwo;
 const DAY_NEXT_FN: NextDateFn = day_next;
 const DAY_CAN_BE_ZERO: bool = false;
 
+const DAY_OF_YEAR_SHORT_FORMAT_STRINGS: &[u8] = b"<DOY>";
+const DAY_OF_YEAR_SHORT_FORMAT_WIDTH: usize = 0;
+const DAY_OF_YEAR_SHORT_PARSE_WIDTH: ParseWidth = ParseWidth::OneToThree;
+
+const DAY_OF_YEAR_ZERO_PADDED_FORMAT_STRINGS: &[u8] = b"<0DOY>";
+const DAY_OF_YEAR_ZERO_PADDED_FORMAT_WIDTH: usize = 3;
+const DAY_OF_YEAR_ZERO_PADDED_PARSE_WIDTH: ParseWidth = ParseWidth::Three;
+
+const DAY_OF_YEAR_CAN_BE_ZERO: bool = false;
+const DAY_OF_YEAR_NEXT_FN: NextDateFn = day_of_year_next;
+const DAY_OF_YEAR_MAX_VALUE: SpecValue = 366; // in leap years
+
+const HOUR_SHORT_FORMAT_STRINGS: &[u8] = b"<HH>";
+const HOUR_SHORT_FORMAT_WIDTH: usize = 0;
+
+const HOUR_ZERO_PADDED_FORMAT_STRINGS: &[u8] = b"<0H>";
+const HOUR_ZERO_PADDED_FORMAT_WIDTH: usize = 2;
+
+const HOUR_CAN_BE_ZERO: bool = true;
+const HOUR_NEXT_FN: NextDateFn = hour_next;
+
+const MINUTE_SHORT_FORMAT_STRINGS: &[u8] = b"<MI>";
+const MINUTE_SHORT_FORMAT_WIDTH: usize = 0;
+
+const MINUTE_ZERO_PADDED_FORMAT_STRINGS: &[u8] = b"<0MI>";
+const MINUTE_ZERO_PADDED_FORMAT_WIDTH: usize = 2;
+
+const MINUTE_CAN_BE_ZERO: bool = true;
+const MINUTE_NEXT_FN: NextDateFn = minute_next;
+
+/// How a year specifier's value is written.
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
 #[non_exhaustive]
-pub(crate) enum YearType {
+pub enum YearType {
+    /// The full year, such as `2024` for `<YYYY>`.
     Full,
+    /// The years since 2000, such as `24` or `124` for `<YY>`.
     Short,
+    /// The years since 2000, zero-padded to two digits, such as `06` for `<0Y>`.
     ZeroPadded,
 }
 
+/// How the value of a calendar specifier other than the year is written.
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
 #[non_exhaustive]
-pub(crate) enum NonYearType {
+pub enum NonYearType {
+    /// Without padding, such as `1` for `<MM>`.
     Short,
+    /// Zero-padded, such as `01` for `<0M>`.
     ZeroPadded,
 }
 
+/// How a month name specifier's value is written.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+#[non_exhaustive]
+pub enum NameType {
+    /// The first three letters of the name, such as `Jan` for `<MON>`.
+    Abbreviated,
+    /// The full name, such as `January` for `<Month>`.
+    Full,
+}
+
 pub(crate) trait Level {
     type Specifier: Specifier;
 
-    fn spec(&self) -> &Self::Specifier;
+    fn spec(&self) -> &'static Self::Specifier;
+
+    /// Returns whether this level has a value, which is false for levels that promote or finalize
+    /// a pre-release.
+    fn has_value(&self) -> bool;
+
+    /// Returns how a pre-release value should change when this level is incremented, where `step`
+    /// is how its number changes if this is the pre-release level.
+    fn pre_release_bump(&self, step: Step) -> PreReleaseBump;
+}
+
+/// How a pre-release value changes when a version is incremented.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) enum PreReleaseBump {
+    /// A more significant value was incremented, making a final release.
+    Clear,
+    /// A more significant value was incremented, and a pre-release of it is started at the first
+    /// label.
+    Reset,
+    /// Step the number, keeping the label.
+    Number(Step),
+    /// Promote to the next label.
+    Label,
+    /// Remove the pre-release, making the version a final release.
+    Final,
+}
+
+/// The value of a pre-release specifier in a version that is a pre-release: an index into its
+/// [`PreReleaseLabels`] and a number.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub(crate) struct PreReleaseValue {
+    pub(crate) label: usize,
+    pub(crate) number: SpecValue,
+}
+
+/// Returns the pre-release value that [`PreReleaseLabels::next_value`] would have been given to
+/// return `cur_value` with `bump`.
+pub(crate) fn prev_pre_release(
+    cur_value: Option<PreReleaseValue>,
+    bump: PreReleaseBump,
+    spec: &impl Display,
+) -> Result<Option<PreReleaseValue>, NextError> {
+    match (bump, cur_value) {
+        (PreReleaseBump::Number(_), Some(PreReleaseValue { label, number })) => {
+            Ok(Some(PreReleaseValue {
+                label,
+                number: decrement(number, spec)?,
+            }))
+        }
+        (PreReleaseBump::Number(_), None) => Err(NextError::NotPreRelease),
+        // the number before a clear, reset, promotion, or finalization could have been anything
+        (
+            PreReleaseBump::Clear
+            | PreReleaseBump::Reset
+            | PreReleaseBump::Label
+            | PreReleaseBump::Final,
+            _,
+        ) => Err(NextError::PrevValueUnknown {
+            spec: spec.to_string(),
+        }),
+    }
+}
+
+/// Compares two pre-release values, where `None` is a final release. As in
+/// [SemVer](https://semver.org/#spec-item-11), a final release is greater than any of its
+/// pre-releases.
+pub(crate) fn cmp_pre_release(a: Option<PreReleaseValue>, b: Option<PreReleaseValue>) -> Ordering {
+    match (a, b) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(a), Some(b)) => a.cmp(&b),
+    }
+}
+
+/// Returns true if `labels` are valid `|`-separated pre-release labels, such as `alpha|beta|rc`:
+/// there is at least one, and each is non-empty, has only ASCII alphanumerics and hyphens, and is
+/// not repeated.
+pub(crate) const fn are_valid_labels(labels: &[u8]) -> bool {
+    let mut label_start = 0;
+    loop {
+        let label_end = find_label_end(labels, label_start);
+        if label_end == label_start {
+            return false;
+        }
+        let mut index = label_start;
+        while index < label_end {
+            if !labels[index].is_ascii_alphanumeric() && labels[index] != b'-' {
+                return false;
+            }
+            index += 1;
+        }
+        let (_, rest) = labels.split_at(label_start);
+        let (label, _) = rest.split_at(label_end - label_start);
+        // compare with every later label
+        let mut other_start = label_end + 1;
+        while other_start <= labels.len() {
+            let other_end = find_label_end(labels, other_start);
+            let (_, rest) = labels.split_at(other_start);
+            let (other, _) = rest.split_at(other_end - other_start);
+            if bytes_eq(label, other) {
+                return false;
+            }
+            other_start = other_end + 1;
+        }
+        if label_end == labels.len() {
+            return true;
+        }
+        label_start = label_end + 1;
+    }
+}
+
+/// Returns the end of the label in `labels` that starts at `start`.
+const fn find_label_end(labels: &[u8], start: usize) -> usize {
+    let mut index = start;
+    while index < labels.len() && labels[index] != b'|' {
+        index += 1;
+    }
+    index
+}
+
+/// The ordered set of labels a pre-release specifier accepts, from least to greatest, held as
+/// their `|`-separated text.
+#[derive(Debug, Clone)]
+pub(crate) enum PreReleaseLabels {
+    /// Labels known at compile time, such as the default ones or those in a format made by
+    /// [`format_str!`](crate::format_str).
+    Static(&'static str),
+    /// Labels parsed from a format string at runtime.
+    Shared(Arc<str>),
+}
+
+impl PreReleaseLabels {
+    fn as_str(&self) -> &str {
+        match self {
+            Self::Static(labels) => labels,
+            Self::Shared(labels) => labels,
+        }
+    }
+
+    pub(crate) fn is_default(&self) -> bool {
+        self.as_str() == PRE_RELEASE_DEFAULT_LABELS
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &str> {
+        self.as_str().split('|')
+    }
+
+    pub(crate) fn get(&self, label: usize) -> &str {
+        self.iter()
+            .nth(label)
+            .expect("label index should be in range")
+    }
+
+    /// Returns the index of `label`, if it is one of these labels.
+    pub(crate) fn position(&self, label: &str) -> Option<usize> {
+        self.iter().position(|this_label| this_label == label)
+    }
+
+    pub(crate) fn format_value(&self, value: Option<PreReleaseValue>) -> String {
+        match value {
+            Some(PreReleaseValue { label, number }) => format!("-{}.{number}", self.get(label)),
+            None => String::new(),
+        }
+    }
+
+    pub(crate) fn next_value(
+        &self,
+        cur_value: Option<PreReleaseValue>,
+        bump: PreReleaseBump,
+        spec: &impl Display,
+    ) -> Result<Option<PreReleaseValue>, NextError> {
+        match (bump, cur_value) {
+            (PreReleaseBump::Clear, _) | (PreReleaseBump::Final, Some(_)) => Ok(None),
+            (PreReleaseBump::Reset, _) => Ok(Some(PreReleaseValue {
+                label: 0,
+                number: 0,
+            })),
+            (PreReleaseBump::Number(step), Some(PreReleaseValue { label, number })) => {
+                Ok(Some(PreReleaseValue {
+                    label,
+                    number: step.apply(number, spec)?,
+                }))
+            }
+            (PreReleaseBump::Label, Some(PreReleaseValue { label, .. })) => {
+                if label + 1 < self.iter().count() {
+                    Ok(Some(PreReleaseValue {
+                        label: label + 1,
+                        number: 0,
+                    }))
+                } else {
+                    Err(NextError::NoNextPreReleaseLabel {
+                        label: self.get(label).to_owned(),
+                    })
+                }
+            }
+            (PreReleaseBump::Number(_) | PreReleaseBump::Label | PreReleaseBump::Final, None) => {
+                Err(NextError::NotPreRelease)
+            }
+        }
+    }
+}
+
+impl Default for PreReleaseLabels {
+    fn default() -> Self {
+        Self::Static(PRE_RELEASE_DEFAULT_LABELS)
+    }
+}
+
+impl PartialEq for PreReleaseLabels {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for PreReleaseLabels {}
+
+impl PartialOrd for PreReleaseLabels {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PreReleaseLabels {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl Hash for PreReleaseLabels {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
 }
 
 /// A semantic version specifier, such as `<MAJOR>` or `<MINOR>`.
 #[derive(Debug, PartialEq, Eq, Clone)]
 #[non_exhaustive]
-pub(crate) enum SemSpecifier {
+pub enum SemSpecifier {
+    /// An epoch specifier, such as `<EPOCH>`.
+    Epoch,
     /// A major version specifier, such as `<MAJOR>`.
     Major,
     /// A minor version specifier, such as `<MINOR>`.
     Minor,
     /// A patch version specifier, such as `<PATCH>`.
     Patch,
+    /// A revision version specifier, such as `<REVISION>`.
+    Revision,
+    /// A pre-release specifier, such as `<PRE>`.
+    PreRelease,
+    /// A build metadata specifier, such as `<META>`.
+    Metadata,
+    /// A monotonic build number specifier, such as `<BUILD>`.
+    Build,
 }
 
 impl SemSpecifier {
-    pub(crate) fn next_value(&self, cur_value: SpecValue, level: SemLevel) -> SpecValue {
-        match (self, level) {
-            (_, _) if level.spec() == self => cur_value + 1,
-            (_, SemLevel::Major) | (SemSpecifier::Patch, SemLevel::Minor) => 0,
+    /// The `const` version of [`Specifier::format_pattern`].
+    pub(crate) const fn pattern(&self) -> &'static [u8] {
+        use SemSpecifier as S;
+        match self {
+            S::Epoch => EPOCH_FORMAT_PATTERN,
+            S::Major => MAJOR_FORMAT_PATTERN,
+            S::Minor => MINOR_FORMAT_PATTERN,
+            S::Patch => PATCH_FORMAT_PATTERN,
+            S::Revision => REVISION_FORMAT_PATTERN,
+            S::PreRelease => PRE_RELEASE_FORMAT_PATTERN,
+            S::Metadata => METADATA_FORMAT_PATTERN,
+            S::Build => BUILD_FORMAT_PATTERN,
+        }
+    }
+
+    /// The `const` version of [`Specifier::can_be_left_adjacent_to`].
+    pub(crate) const fn precedes(&self, other: &Self) -> bool {
+        use SemSpecifier as S;
+        matches!(
+            (self, other),
+            (S::Epoch, S::Major)
+                | (S::Major, S::Minor)
+                | (S::Minor, S::Patch)
+                | (S::Patch, S::Revision)
+                | (S::Major | S::Minor | S::Patch | S::Revision, S::PreRelease)
+                | (
+                    S::Major | S::Minor | S::Patch | S::Revision | S::PreRelease,
+                    S::Build
+                )
+                | (
+                    S::Major | S::Minor | S::Patch | S::Revision | S::PreRelease | S::Build,
+                    S::Metadata
+                )
+        )
+    }
+
+    /// The `const` version of [`Specifier::format_width`].
+    #[allow(clippy::unused_self)] // called by `builtin_rules!` like the other specifiers' twins
+    pub(crate) const fn width(&self) -> usize {
+        SEM_FORMAT_WIDTH
+    }
+
+    /// The `const` version of [`Specifier::can_be_zero`].
+    #[allow(clippy::unused_self)] // called by `builtin_rules!` like the other specifiers' twins
+    pub(crate) const fn zeroable(&self) -> bool {
+        SEM_CAN_BE_ZERO
+    }
+
+    /// The `const` version of [`Specifier::min_width`].
+    #[allow(clippy::unused_self)] // called by `builtin_rules!` like the other specifiers' twins
+    pub(crate) const fn least_width(&self) -> usize {
+        SEM_MIN_WIDTH
+    }
+
+    /// The `const` version of [`Specifier::value_names`].
+    #[allow(clippy::unused_self)] // called by `builtin_rules!` like the other specifiers' twins
+    pub(crate) const fn names(&self) -> Option<&'static [&'static str]> {
+        None
+    }
+
+    /// The `const` version of [`Specifier::is_pre_release`].
+    pub(crate) const fn pre_release(&self) -> bool {
+        matches!(self, SemSpecifier::PreRelease)
+    }
+
+    /// The `const` version of [`Specifier::is_metadata`].
+    pub(crate) const fn metadata(&self) -> bool {
+        matches!(self, SemSpecifier::Metadata)
+    }
+
+    pub(crate) fn next_value(
+        &self,
+        cur_value: SpecValue,
+        level: SemLevel,
+        step: Step,
+    ) -> SpecValueResult {
+        let next_value = match self {
+            // the build number increments on every bump and is never reset
+            SemSpecifier::Build => increment(cur_value, self)?,
+            SemSpecifier::PreRelease | SemSpecifier::Metadata => return no_value(self),
+            _ if level.spec() == self => step.apply(cur_value, self)?,
+            _ if self.is_reset_by(level) => 0,
             _ => cur_value,
+        };
+        Ok(next_value)
+    }
+
+    /// Returns the value that [`Self::next_value`] would have been given to return `cur_value`
+    /// with `level`.
+    pub(crate) fn prev_value(&self, cur_value: SpecValue, level: SemLevel) -> SpecValueResult {
+        match self {
+            SemSpecifier::Build => decrement(cur_value, self),
+            SemSpecifier::PreRelease | SemSpecifier::Metadata => no_value(self),
+            _ if level.spec() == self => decrement(cur_value, self),
+            // a value that was reset could have been anything
+            _ if self.is_reset_by(level) => Err(NextError::PrevValueUnknown {
+                spec: self.to_string(),
+            }),
+            _ => Ok(cur_value),
         }
     }
+
+    /// Returns whether this is a lesser value that is reset when the value of `level` is
+    /// incremented, like an odometer.
+    ///
+    /// The epoch and build number are never reset.
+    fn is_reset_by(&self, level: SemLevel) -> bool {
+        use SemSpecifier as S;
+        matches!(
+            (self, level),
+            (
+                S::Major | S::Minor | S::Patch | S::Revision,
+                SemLevel::Epoch
+            ) | (S::Minor | S::Patch | S::Revision, SemLevel::Major)
+                | (S::Patch | S::Revision, SemLevel::Minor)
+                | (S::Revision, SemLevel::Patch)
+        )
+    }
 }
 
 impl Display for SemSpecifier {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(unsafe { std::str::from_utf8_unchecked(self.format_pattern()) })
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(unsafe { core::str::from_utf8_unchecked(self.format_pattern()) })
     }
 }
 
 impl Specifier for SemSpecifier {
     fn format_pattern(&self) -> &'static [u8] {
-        use SemSpecifier as S;
-        match self {
-            S::Major => MAJOR_FORMAT_PATTERN,
-            S::Minor => MINOR_FORMAT_PATTERN,
-            S::Patch => PATCH_FORMAT_PATTERN,
-        }
+        self.pattern()
     }
 
     fn format_width(&self) -> usize {
-        SEM_FORMAT_WIDTH
+        self.width()
+    }
+
+    fn min_width(&self) -> usize {
+        self.least_width()
     }
 
     fn first_variants() -> &'static [&'static SemSpecifier] {
-        static FIRST: &[&SemSpecifier] = &[&SEM_MAJOR];
-        FIRST
+        SEM_FIRST
     }
 
     fn last_variants() -> &'static [&'static SemSpecifier] {
-        SEM_ALL
+        SEM_LAST
     }
 
     fn all() -> &'static [&'static Self] {
@@ -246,40 +1154,130 @@ impl Specifier for SemSpecifier {
     }
 
     fn can_be_zero(&self) -> bool {
-        SEM_CAN_BE_ZERO
+        self.zeroable()
     }
 
     fn can_be_left_adjacent_to(&self, other: &Self) -> bool {
-        use SemSpecifier as S;
-        matches!((self, other), (S::Major, S::Minor) | (S::Minor, S::Patch))
+        self.precedes(other)
+    }
+
+    fn is_pre_release(&self) -> bool {
+        self.pre_release()
+    }
+
+    fn is_metadata(&self) -> bool {
+        self.metadata()
+    }
+
+    fn is_build(&self) -> bool {
+        matches!(self, SemSpecifier::Build)
+    }
+
+    fn is_epoch(&self) -> bool {
+        matches!(self, SemSpecifier::Epoch)
+    }
+
+    fn bump_value(&self, value: SpecValue, level: &Self) -> SpecValueResult {
+        let level = match level {
+            SemSpecifier::Epoch => SemLevel::Epoch,
+            SemSpecifier::Major => SemLevel::Major,
+            SemSpecifier::Minor => SemLevel::Minor,
+            SemSpecifier::Patch => SemLevel::Patch,
+            SemSpecifier::Revision => SemLevel::Revision,
+            SemSpecifier::PreRelease | SemSpecifier::Metadata | SemSpecifier::Build => {
+                return default_bump_value(self, value, level)
+            }
+        };
+        self.next_value(value, level, Step::By(1))
     }
 }
+pub(crate) const SEM_EPOCH: SemSpecifier = SemSpecifier::Epoch;
 pub(crate) const SEM_MAJOR: SemSpecifier = SemSpecifier::Major;
 pub(crate) const SEM_MINOR: SemSpecifier = SemSpecifier::Minor;
 pub(crate) const SEM_PATCH: SemSpecifier = SemSpecifier::Patch;
-const SEM_ALL: &[&SemSpecifier] = &[&SEM_MAJOR, &SEM_MINOR, &SEM_PATCH];
+pub(crate) const SEM_REVISION: SemSpecifier = SemSpecifier::Revision;
+pub(crate) const SEM_PRE_RELEASE: SemSpecifier = SemSpecifier::PreRelease;
+pub(crate) const SEM_METADATA: SemSpecifier = SemSpecifier::Metadata;
+pub(crate) const SEM_BUILD: SemSpecifier = SemSpecifier::Build;
+pub(crate) const SEM_ALL: &[&SemSpecifier] = &[
+    &SEM_EPOCH,
+    &SEM_MAJOR,
+    &SEM_MINOR,
+    &SEM_PATCH,
+    &SEM_REVISION,
+    &SEM_PRE_RELEASE,
+    &SEM_METADATA,
+    &SEM_BUILD,
+];
+pub(crate) const SEM_FIRST: &[&SemSpecifier] = &[&SEM_EPOCH, &SEM_MAJOR];
+// all but the epoch, which must be followed by a major
+pub(crate) const SEM_LAST: &[&SemSpecifier] = SEM_ALL.split_at(1).1;
+pub(crate) const SEM_RULES: [SpecifierRules<SemSpecifier>; SEM_ALL.len()] =
+    builtin_rules!(SEM_ALL, SEM_FIRST, SEM_LAST);
 
 /// A semantic specifier to increment in a [`Sem`](crate::Sem) [`Version`](crate::Version).
+///
+/// With the `serde` feature, levels are (de)serialized in kebab-case, e.g., `pre-release`.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 #[non_exhaustive]
 pub enum SemLevel {
-    /// Refers to the major version specifier,`<MAJOR>`. It is greater than `<MINOR>` and `<PATCH>`.
+    /// Refers to the epoch specifier, `<EPOCH>`. It is greater than all other specifiers, and is
+    /// never reset when they are incremented.
+    Epoch,
+    /// Refers to the major version specifier,`<MAJOR>`. It is greater than `<MINOR>`, `<PATCH>`,
+    /// and `<REVISION>`.
     Major,
-    /// Refers to the minor version specifier,`<MINOR>`. It is greater than `<PATCH>` and less than
-    /// `<MAJOR>`.
+    /// Refers to the minor version specifier,`<MINOR>`. It is greater than `<PATCH>` and
+    /// `<REVISION>` and less than `<MAJOR>`.
     Minor,
-    /// Refers to the patch version specifier,`<PATCH>`. It is less than `<MINOR>` and `<MAJOR>`.
+    /// Refers to the patch version specifier,`<PATCH>`. It is greater than `<REVISION>` and less
+    /// than `<MINOR>` and `<MAJOR>`.
     Patch,
+    /// Refers to the revision version specifier,`<REVISION>`. It is less than `<PATCH>`,
+    /// `<MINOR>`, and `<MAJOR>`.
+    Revision,
+    /// Refers to the number of the pre-release specifier, `<PRE>`. Incrementing it keeps the
+    /// current label, e.g., `rc.1` → `rc.2`.
+    PreRelease,
+    /// Refers to the label of the pre-release specifier, `<PRE>`. Incrementing it promotes the
+    /// version to the next label and resets the number to zero, e.g., `beta.3` → `rc.0`.
+    PreReleaseLabel,
+    /// Refers to finalizing the pre-release specifier, `<PRE>`. "Incrementing" it removes the
+    /// pre-release from the version, e.g., `1.4.0-rc.2` → `1.4.0`.
+    FinalRelease,
 }
 
 impl Level for SemLevel {
     type Specifier = SemSpecifier;
 
-    fn spec(&self) -> &Self::Specifier {
+    fn spec(&self) -> &'static Self::Specifier {
         match self {
+            Self::Epoch => &SEM_EPOCH,
             Self::Major => &SEM_MAJOR,
             Self::Minor => &SEM_MINOR,
             Self::Patch => &SEM_PATCH,
+            Self::Revision => &SEM_REVISION,
+            Self::PreRelease | Self::PreReleaseLabel | Self::FinalRelease => &SEM_PRE_RELEASE,
+        }
+    }
+
+    fn has_value(&self) -> bool {
+        !matches!(self, Self::PreReleaseLabel | Self::FinalRelease)
+    }
+
+    fn pre_release_bump(&self, step: Step) -> PreReleaseBump {
+        match self {
+            Self::Epoch | Self::Major | Self::Minor | Self::Patch | Self::Revision => {
+                PreReleaseBump::Clear
+            }
+            Self::PreRelease => PreReleaseBump::Number(step),
+            Self::PreReleaseLabel => PreReleaseBump::Label,
+            Self::FinalRelease => PreReleaseBump::Final,
         }
     }
 }
@@ -290,60 +1288,92 @@ impl AsRef<SemLevel> for SemLevel {
     }
 }
 
+/// A calendar version specifier, such as `<YYYY>` or `<MM>`.
 #[derive(Debug, PartialEq, Eq, Clone)]
 #[non_exhaustive]
-pub(crate) enum CalSpecifier {
+pub enum CalSpecifier {
+    /// An epoch specifier, `<EPOCH>`.
+    Epoch,
+    /// A year specifier, such as `<YYYY>`.
     Year(YearType),
+    /// An ISO 8601 week-numbering year specifier, `<GGGG>`.
+    IsoYear,
+    /// A quarter of the year specifier, `<Q>`.
+    Quarter,
+    /// A month specifier, such as `<MM>`.
     Month(NonYearType),
+    /// A month name specifier, such as `<MON>`.
+    MonthName(NameType),
+    /// A week of the year specifier, such as `<WW>`.
     Week(NonYearType),
+    /// An ISO 8601 week specifier, such as `<IW>`.
+    IsoWeek(NonYearType),
+    /// A day of the month specifier, such as `<DD>`.
     Day(NonYearType),
+    /// A day of the year specifier, such as `<DOY>`.
+    DayOfYear(NonYearType),
+    /// An hour specifier, such as `<HH>`.
+    Hour(NonYearType),
+    /// A minute specifier, such as `<MI>`.
+    Minute(NonYearType),
+    /// A monotonic build number specifier, `<BUILD>`.
+    Build,
+    /// A build metadata specifier, `<META>`.
+    Metadata,
 }
 
 impl CalSpecifier {
-    pub(crate) fn next_value(&self, date: NaiveDate) -> SpecValueResult {
-        match &self {
-            CalSpecifier::Year(type_) => match type_ {
-                YearType::Full => YEAR_FULL_NEXT_FN(date),
-                YearType::Short | YearType::ZeroPadded => YEAR_SHORT_AND_ZERO_PADDED_NEXT_FN(date),
-            },
-            CalSpecifier::Month(_) => Ok(MONTH_NEXT_FN(date)),
-            CalSpecifier::Week(_) => Ok(WEEK_NEXT_FN(date)),
-            CalSpecifier::Day(_) => Ok(DAY_NEXT_FN(date)),
-        }
-    }
-}
-
-impl Display for CalSpecifier {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(unsafe { std::str::from_utf8_unchecked(self.format_pattern()) })
-    }
-}
-
-impl Specifier for CalSpecifier {
-    fn format_pattern(&self) -> &'static [u8] {
+    /// The `const` version of [`Specifier::format_pattern`].
+    pub(crate) const fn pattern(&self) -> &'static [u8] {
         use CalSpecifier as C;
         match self {
+            C::Epoch => EPOCH_FORMAT_PATTERN,
             C::Year(type_) => match type_ {
                 YearType::Full => YEAR_FULL_FORMAT_STRINGS,
                 YearType::Short => YEAR_SHORT_FORMAT_STRINGS,
                 YearType::ZeroPadded => YEAR_ZERO_PADDED_FORMAT_STRINGS,
             },
+            C::IsoYear => ISO_YEAR_FORMAT_STRINGS,
+            C::Quarter => QUARTER_FORMAT_STRINGS,
             C::Month(type_) => match type_ {
                 NonYearType::Short => MONTH_SHORT_FORMAT_STRINGS,
                 NonYearType::ZeroPadded => MONTH_ZERO_PADDED_FORMAT_STRINGS,
             },
+            C::MonthName(type_) => match type_ {
+                NameType::Abbreviated => MONTH_ABBREVIATED_FORMAT_STRINGS,
+                NameType::Full => MONTH_FULL_FORMAT_STRINGS,
+            },
             C::Week(type_) => match type_ {
                 NonYearType::Short => WEEK_SHORT_FORMAT_STRINGS,
                 NonYearType::ZeroPadded => WEEK_ZERO_PADDED_FORMAT_STRINGS,
             },
+            C::IsoWeek(type_) => match type_ {
+                NonYearType::Short => ISO_WEEK_SHORT_FORMAT_STRINGS,
+                NonYearType::ZeroPadded => ISO_WEEK_ZERO_PADDED_FORMAT_STRINGS,
+            },
             C::Day(type_) => match type_ {
                 NonYearType::Short => DAY_SHORT_FORMAT_STRINGS,
                 NonYearType::ZeroPadded => DAY_ZERO_PADDED_FORMAT_STRINGS,
             },
+            C::DayOfYear(type_) => match type_ {
+                NonYearType::Short => DAY_OF_YEAR_SHORT_FORMAT_STRINGS,
+                NonYearType::ZeroPadded => DAY_OF_YEAR_ZERO_PADDED_FORMAT_STRINGS,
+            },
+            C::Hour(type_) => match type_ {
+                NonYearType::Short => HOUR_SHORT_FORMAT_STRINGS,
+                NonYearType::ZeroPadded => HOUR_ZERO_PADDED_FORMAT_STRINGS,
+            },
+            C::Minute(type_) => match type_ {
+                NonYearType::Short => MINUTE_SHORT_FORMAT_STRINGS,
+                NonYearType::ZeroPadded => MINUTE_ZERO_PADDED_FORMAT_STRINGS,
+            },
+            C::Build => BUILD_FORMAT_PATTERN,
+            C::Metadata => METADATA_FORMAT_PATTERN,
         }
     }
 
-    fn format_width(&self) -> usize {
+    /// The `const` version of [`Specifier::format_width`].
+    pub(crate) const fn width(&self) -> usize {
         use CalSpecifier as C;
         match self {
             C::Year(type_) => match type_ {
@@ -351,28 +1381,183 @@ impl Specifier for CalSpecifier {
                 YearType::Short => YEAR_SHORT_FORMAT_WIDTH,
                 YearType::ZeroPadded => YEAR_ZERO_PADDED_FORMAT_WIDTH,
             },
+            C::IsoYear => ISO_YEAR_FORMAT_WIDTH,
+            C::Quarter => QUARTER_FORMAT_WIDTH,
             C::Month(type_) => match type_ {
                 NonYearType::Short => MONTH_SHORT_FORMAT_WIDTH,
                 NonYearType::ZeroPadded => MONTH_ZERO_PADDED_FORMAT_WIDTH,
             },
+            C::MonthName(_) => MONTH_NAME_FORMAT_WIDTH,
             C::Week(type_) => match type_ {
                 NonYearType::Short => WEEK_SHORT_FORMAT_WIDTH,
                 NonYearType::ZeroPadded => WEEK_ZERO_PADDED_FORMAT_WIDTH,
             },
+            C::IsoWeek(type_) => match type_ {
+                NonYearType::Short => ISO_WEEK_SHORT_FORMAT_WIDTH,
+                NonYearType::ZeroPadded => ISO_WEEK_ZERO_PADDED_FORMAT_WIDTH,
+            },
             C::Day(type_) => match type_ {
                 NonYearType::Short => DAY_SHORT_FORMAT_WIDTH,
                 NonYearType::ZeroPadded => DAY_ZERO_PADDED_FORMAT_WIDTH,
             },
+            C::DayOfYear(type_) => match type_ {
+                NonYearType::Short => DAY_OF_YEAR_SHORT_FORMAT_WIDTH,
+                NonYearType::ZeroPadded => DAY_OF_YEAR_ZERO_PADDED_FORMAT_WIDTH,
+            },
+            C::Hour(type_) => match type_ {
+                NonYearType::Short => HOUR_SHORT_FORMAT_WIDTH,
+                NonYearType::ZeroPadded => HOUR_ZERO_PADDED_FORMAT_WIDTH,
+            },
+            C::Minute(type_) => match type_ {
+                NonYearType::Short => MINUTE_SHORT_FORMAT_WIDTH,
+                NonYearType::ZeroPadded => MINUTE_ZERO_PADDED_FORMAT_WIDTH,
+            },
+            C::Epoch | C::Build | C::Metadata => SEM_FORMAT_WIDTH,
+        }
+    }
+
+    /// The `const` version of [`Specifier::can_be_zero`].
+    pub(crate) const fn zeroable(&self) -> bool {
+        use CalSpecifier as C;
+        match self {
+            C::Year(type_) => match type_ {
+                YearType::Full => YEAR_FULL_CAN_BE_ZERO,
+                YearType::Short => YEAR_SHORT_CAN_BE_ZERO,
+                YearType::ZeroPadded => YEAR_ZERO_PADDED_CAN_BE_ZERO,
+            },
+            C::IsoYear => ISO_YEAR_CAN_BE_ZERO,
+            C::Quarter => QUARTER_CAN_BE_ZERO,
+            C::Month(_) | C::MonthName(_) => MONTH_CAN_BE_ZERO,
+            C::Week(_) => WEEK_CAN_BE_ZERO,
+            C::IsoWeek(_) => ISO_WEEK_CAN_BE_ZERO,
+            C::Day(_) => DAY_CAN_BE_ZERO,
+            C::DayOfYear(_) => DAY_OF_YEAR_CAN_BE_ZERO,
+            C::Hour(_) => HOUR_CAN_BE_ZERO,
+            C::Minute(_) => MINUTE_CAN_BE_ZERO,
+            C::Epoch | C::Build | C::Metadata => SEM_CAN_BE_ZERO,
+        }
+    }
+
+    /// The `const` version of [`Specifier::min_width`].
+    pub(crate) const fn least_width(&self) -> usize {
+        match self {
+            CalSpecifier::Year(YearType::Full) => YEAR_FULL_MIN_WIDTH,
+            CalSpecifier::IsoYear => ISO_YEAR_MIN_WIDTH,
+            _ => SEM_MIN_WIDTH,
+        }
+    }
+
+    /// The `const` version of [`Specifier::can_be_left_adjacent_to`].
+    pub(crate) const fn precedes(&self, other: &Self) -> bool {
+        use CalSpecifier as C;
+        matches!(
+            (self, other),
+            (C::Epoch, C::Year(_) | C::IsoYear)
+                | (
+                    C::Year(_),
+                    C::Quarter | C::Month(_) | C::MonthName(_) | C::Week(_) | C::DayOfYear(_)
+                )
+                | (C::IsoYear, C::IsoWeek(_))
+                | (C::Quarter, C::Month(_) | C::MonthName(_))
+                | (C::Month(_) | C::MonthName(_), C::Day(_))
+                | (C::Day(_) | C::DayOfYear(_), C::Hour(_))
+                | (C::Hour(_), C::Minute(_))
+                | (
+                    C::Year(_)
+                        | C::IsoYear
+                        | C::Quarter
+                        | C::Month(_)
+                        | C::MonthName(_)
+                        | C::Week(_)
+                        | C::IsoWeek(_)
+                        | C::Day(_)
+                        | C::DayOfYear(_)
+                        | C::Hour(_)
+                        | C::Minute(_),
+                    C::Build | C::Metadata
+                )
+                | (C::Build, C::Metadata)
+        )
+    }
+
+    /// The `const` version of [`Specifier::value_names`].
+    pub(crate) const fn names(&self) -> Option<&'static [&'static str]> {
+        match self {
+            CalSpecifier::MonthName(NameType::Abbreviated) => Some(MONTH_ABBREVIATED_NAMES),
+            CalSpecifier::MonthName(NameType::Full) => Some(MONTH_FULL_NAMES),
+            _ => None,
+        }
+    }
+
+    /// The `const` version of [`Specifier::is_pre_release`]. Calendar formats have no
+    /// pre-release specifier.
+    #[allow(clippy::unused_self)] // called by `builtin_rules!` like the other specifiers' twins
+    pub(crate) const fn pre_release(&self) -> bool {
+        false
+    }
+
+    /// The `const` version of [`Specifier::is_metadata`].
+    pub(crate) const fn metadata(&self) -> bool {
+        matches!(self, CalSpecifier::Metadata)
+    }
+
+    pub(crate) fn next_value(&self, cur_value: SpecValue, date: NaiveDateTime) -> SpecValueResult {
+        match &self {
+            // the epoch is only incremented explicitly, by `next_epoch`, and is never reset
+            CalSpecifier::Epoch => Ok(cur_value),
+            CalSpecifier::Year(type_) => match type_ {
+                YearType::Full => YEAR_FULL_NEXT_FN(date),
+                YearType::Short | YearType::ZeroPadded => YEAR_SHORT_AND_ZERO_PADDED_NEXT_FN(date),
+            },
+            CalSpecifier::IsoYear => ISO_YEAR_NEXT_FN(date),
+            CalSpecifier::Quarter => Ok(QUARTER_NEXT_FN(date)),
+            CalSpecifier::Month(_) | CalSpecifier::MonthName(_) => Ok(MONTH_NEXT_FN(date)),
+            CalSpecifier::Week(_) => Ok(WEEK_NEXT_FN(date)),
+            CalSpecifier::IsoWeek(_) => Ok(ISO_WEEK_NEXT_FN(date)),
+            CalSpecifier::Day(_) => Ok(DAY_NEXT_FN(date)),
+            CalSpecifier::DayOfYear(_) => Ok(DAY_OF_YEAR_NEXT_FN(date)),
+            CalSpecifier::Hour(_) => Ok(HOUR_NEXT_FN(date)),
+            CalSpecifier::Minute(_) => Ok(MINUTE_NEXT_FN(date)),
+            // the build number increments on every bump and is never reset
+            CalSpecifier::Build => increment(cur_value, self),
+            CalSpecifier::Metadata => no_value(self),
         }
     }
 
+    /// Like [`Self::next_value`], but the build number is decremented instead.
+    pub(crate) fn prev_value(&self, cur_value: SpecValue, date: NaiveDateTime) -> SpecValueResult {
+        match &self {
+            CalSpecifier::Build => decrement(cur_value, self),
+            _ => self.next_value(cur_value, date),
+        }
+    }
+}
+
+impl Display for CalSpecifier {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(unsafe { core::str::from_utf8_unchecked(self.format_pattern()) })
+    }
+}
+
+impl Specifier for CalSpecifier {
+    fn format_pattern(&self) -> &'static [u8] {
+        self.pattern()
+    }
+
+    fn format_width(&self) -> usize {
+        self.width()
+    }
+
+    fn min_width(&self) -> usize {
+        self.least_width()
+    }
+
     fn first_variants() -> &'static [&'static Self] {
-        static FIRST: &[&CalSpecifier] = &[&CAL_YEAR_FULL, &CAL_YEAR_SHORT, &CAL_YEAR_ZERO_PADDED];
-        FIRST
+        CAL_FIRST
     }
 
     fn last_variants() -> &'static [&'static Self] {
-        CAL_ALL
+        CAL_LAST
     }
 
     fn all() -> &'static [&'static Self] {
@@ -384,117 +1569,258 @@ impl Specifier for CalSpecifier {
             CalSpecifier::Year(YearType::Full) => YEAR_FULL_PARSE_WIDTH,
             CalSpecifier::Year(YearType::Short) => YEAR_SHORT_PARSE_WIDTH,
             CalSpecifier::Year(YearType::ZeroPadded) => YEAR_ZERO_PADDED_PARSE_WIDTH,
+            CalSpecifier::IsoYear => ISO_YEAR_PARSE_WIDTH,
+            CalSpecifier::Quarter => QUARTER_PARSE_WIDTH,
             CalSpecifier::Month(NonYearType::ZeroPadded)
             | CalSpecifier::Week(NonYearType::ZeroPadded)
-            | CalSpecifier::Day(NonYearType::ZeroPadded) => MONTH_WEEK_DAY_ZERO_PADDED_PARSE_WIDTH,
+            | CalSpecifier::IsoWeek(NonYearType::ZeroPadded)
+            | CalSpecifier::Day(NonYearType::ZeroPadded)
+            | CalSpecifier::Hour(NonYearType::ZeroPadded)
+            | CalSpecifier::Minute(NonYearType::ZeroPadded) => {
+                MONTH_WEEK_DAY_ZERO_PADDED_PARSE_WIDTH
+            }
             CalSpecifier::Month(NonYearType::Short)
             | CalSpecifier::Week(NonYearType::Short)
-            | CalSpecifier::Day(NonYearType::Short) => MONTH_WEEK_DAY_SHORT_PARSE_WIDTH,
+            | CalSpecifier::IsoWeek(NonYearType::Short)
+            | CalSpecifier::Day(NonYearType::Short)
+            | CalSpecifier::Hour(NonYearType::Short)
+            | CalSpecifier::Minute(NonYearType::Short) => MONTH_WEEK_DAY_SHORT_PARSE_WIDTH,
+            CalSpecifier::MonthName(NameType::Abbreviated) => MONTH_ABBREVIATED_PARSE_WIDTH,
+            CalSpecifier::MonthName(NameType::Full) => MONTH_FULL_PARSE_WIDTH,
+            CalSpecifier::DayOfYear(NonYearType::Short) => DAY_OF_YEAR_SHORT_PARSE_WIDTH,
+            CalSpecifier::DayOfYear(NonYearType::ZeroPadded) => DAY_OF_YEAR_ZERO_PADDED_PARSE_WIDTH,
+            CalSpecifier::Epoch | CalSpecifier::Build | CalSpecifier::Metadata => SEM_PARSE_WIDTH,
         }
     }
 
     fn can_be_zero(&self) -> bool {
-        use CalSpecifier as C;
-        match self {
-            C::Year(type_) => match type_ {
-                YearType::Full => YEAR_FULL_CAN_BE_ZERO,
-                YearType::Short => YEAR_SHORT_CAN_BE_ZERO,
-                YearType::ZeroPadded => YEAR_ZERO_PADDED_CAN_BE_ZERO,
-            },
-            C::Month(_) => MONTH_CAN_BE_ZERO,
-            C::Week(_) => WEEK_CAN_BE_ZERO,
-            C::Day(_) => DAY_CAN_BE_ZERO,
-        }
+        self.zeroable()
     }
 
     fn can_be_left_adjacent_to(&self, other: &Self) -> bool {
-        use CalSpecifier as C;
-        matches!(
-            (self, other),
-            (C::Year(_), C::Month(_) | C::Week(_)) | (C::Month(_), C::Day(_))
-        )
+        self.precedes(other)
+    }
+
+    fn is_metadata(&self) -> bool {
+        self.metadata()
+    }
+
+    fn is_build(&self) -> bool {
+        matches!(self, CalSpecifier::Build)
+    }
+
+    fn is_epoch(&self) -> bool {
+        matches!(self, CalSpecifier::Epoch)
+    }
+
+    fn value_names(&self) -> Option<&'static [&'static str]> {
+        self.names()
+    }
+
+    fn max_value(&self) -> SpecValue {
+        match self {
+            CalSpecifier::Quarter => QUARTER_MAX_VALUE,
+            CalSpecifier::DayOfYear(_) => DAY_OF_YEAR_MAX_VALUE,
+            _ => SpecValue::MAX,
+        }
     }
 }
+pub(crate) const CAL_EPOCH: CalSpecifier = CalSpecifier::Epoch;
 pub(crate) const CAL_YEAR_FULL: CalSpecifier = CalSpecifier::Year(YearType::Full);
 pub(crate) const CAL_YEAR_SHORT: CalSpecifier = CalSpecifier::Year(YearType::Short);
 pub(crate) const CAL_YEAR_ZERO_PADDED: CalSpecifier = CalSpecifier::Year(YearType::ZeroPadded);
+pub(crate) const CAL_ISO_YEAR: CalSpecifier = CalSpecifier::IsoYear;
+pub(crate) const CAL_QUARTER: CalSpecifier = CalSpecifier::Quarter;
 pub(crate) const CAL_MONTH_SHORT: CalSpecifier = CalSpecifier::Month(NonYearType::Short);
 pub(crate) const CAL_MONTH_ZERO_PADDED: CalSpecifier = CalSpecifier::Month(NonYearType::ZeroPadded);
+pub(crate) const CAL_MONTH_ABBREVIATED: CalSpecifier =
+    CalSpecifier::MonthName(NameType::Abbreviated);
+pub(crate) const CAL_MONTH_FULL: CalSpecifier = CalSpecifier::MonthName(NameType::Full);
 pub(crate) const CAL_WEEK_SHORT: CalSpecifier = CalSpecifier::Week(NonYearType::Short);
 pub(crate) const CAL_WEEK_ZERO_PADDED: CalSpecifier = CalSpecifier::Week(NonYearType::ZeroPadded);
+pub(crate) const CAL_ISO_WEEK_SHORT: CalSpecifier = CalSpecifier::IsoWeek(NonYearType::Short);
+pub(crate) const CAL_ISO_WEEK_ZERO_PADDED: CalSpecifier =
+    CalSpecifier::IsoWeek(NonYearType::ZeroPadded);
 pub(crate) const CAL_DAY_SHORT: CalSpecifier = CalSpecifier::Day(NonYearType::Short);
 pub(crate) const CAL_DAY_ZERO_PADDED: CalSpecifier = CalSpecifier::Day(NonYearType::ZeroPadded);
-const CAL_ALL: &[&CalSpecifier] = &[
+pub(crate) const CAL_DAY_OF_YEAR_SHORT: CalSpecifier = CalSpecifier::DayOfYear(NonYearType::Short);
+pub(crate) const CAL_DAY_OF_YEAR_ZERO_PADDED: CalSpecifier =
+    CalSpecifier::DayOfYear(NonYearType::ZeroPadded);
+pub(crate) const CAL_HOUR_SHORT: CalSpecifier = CalSpecifier::Hour(NonYearType::Short);
+pub(crate) const CAL_HOUR_ZERO_PADDED: CalSpecifier = CalSpecifier::Hour(NonYearType::ZeroPadded);
+pub(crate) const CAL_MINUTE_SHORT: CalSpecifier = CalSpecifier::Minute(NonYearType::Short);
+pub(crate) const CAL_MINUTE_ZERO_PADDED: CalSpecifier =
+    CalSpecifier::Minute(NonYearType::ZeroPadded);
+pub(crate) const CAL_BUILD: CalSpecifier = CalSpecifier::Build;
+pub(crate) const CAL_METADATA: CalSpecifier = CalSpecifier::Metadata;
+pub(crate) const CAL_FIRST: &[&CalSpecifier] = &[
+    &CAL_EPOCH,
+    &CAL_YEAR_FULL,
+    &CAL_YEAR_SHORT,
+    &CAL_YEAR_ZERO_PADDED,
+    &CAL_ISO_YEAR,
+];
+// all but the epoch, which must be followed by a year
+pub(crate) const CAL_LAST: &[&CalSpecifier] = CAL_ALL.split_at(1).1;
+pub(crate) const CAL_ALL: &[&CalSpecifier] = &[
+    &CAL_EPOCH,
     &CAL_YEAR_FULL,
     &CAL_YEAR_SHORT,
     &CAL_YEAR_ZERO_PADDED,
+    &CAL_ISO_YEAR,
+    &CAL_QUARTER,
     &CAL_MONTH_SHORT,
     &CAL_MONTH_ZERO_PADDED,
+    &CAL_MONTH_ABBREVIATED,
+    &CAL_MONTH_FULL,
     &CAL_WEEK_SHORT,
     &CAL_WEEK_ZERO_PADDED,
+    &CAL_ISO_WEEK_SHORT,
+    &CAL_ISO_WEEK_ZERO_PADDED,
     &CAL_DAY_SHORT,
     &CAL_DAY_ZERO_PADDED,
+    &CAL_DAY_OF_YEAR_SHORT,
+    &CAL_DAY_OF_YEAR_ZERO_PADDED,
+    &CAL_HOUR_SHORT,
+    &CAL_HOUR_ZERO_PADDED,
+    &CAL_MINUTE_SHORT,
+    &CAL_MINUTE_ZERO_PADDED,
+    &CAL_BUILD,
+    &CAL_METADATA,
 ];
+pub(crate) const CAL_RULES: [SpecifierRules<CalSpecifier>; CAL_ALL.len()] =
+    builtin_rules!(CAL_ALL, CAL_FIRST, CAL_LAST);
 
+/// A calendar specifier in a [`CalSem`](crate::CalSem) format, such as `<YYYY>` or `<MM>`.
 #[derive(Debug, PartialEq, Eq, Clone)]
-pub(crate) enum CalSemCalSpecifier {
+pub enum CalSemCalSpecifier {
+    /// A year specifier, such as `<YYYY>`.
     Year(YearType),
+    /// An ISO 8601 week-numbering year specifier, `<GGGG>`.
+    IsoYear,
+    /// A quarter of the year specifier, `<Q>`.
+    Quarter,
+    /// A month specifier, such as `<MM>`.
     Month(NonYearType),
+    /// A month name specifier, such as `<MON>`.
+    MonthName(NameType),
+    /// A week of the year specifier, such as `<WW>`.
     Week(NonYearType),
+    /// An ISO 8601 week specifier, such as `<IW>`.
+    IsoWeek(NonYearType),
+    /// A day of the month specifier, such as `<DD>`.
     Day(NonYearType),
+    /// A day of the year specifier, such as `<DOY>`.
+    DayOfYear(NonYearType),
+    /// An hour specifier, such as `<HH>`.
+    Hour(NonYearType),
+    /// A minute specifier, such as `<MI>`.
+    Minute(NonYearType),
 }
 
 impl CalSemCalSpecifier {
-    pub(crate) fn next_value(&self, date: NaiveDate) -> SpecValueResult {
+    /// Returns the [`CalSpecifier`] of the same unit and type.
+    pub(crate) fn to_cal(&self) -> CalSpecifier {
+        use CalSemCalSpecifier as CSC;
+        match self {
+            CSC::Year(type_) => CalSpecifier::Year(type_.clone()),
+            CSC::IsoYear => CalSpecifier::IsoYear,
+            CSC::Quarter => CalSpecifier::Quarter,
+            CSC::Month(type_) => CalSpecifier::Month(type_.clone()),
+            CSC::MonthName(type_) => CalSpecifier::MonthName(type_.clone()),
+            CSC::Week(type_) => CalSpecifier::Week(type_.clone()),
+            CSC::IsoWeek(type_) => CalSpecifier::IsoWeek(type_.clone()),
+            CSC::Day(type_) => CalSpecifier::Day(type_.clone()),
+            CSC::DayOfYear(type_) => CalSpecifier::DayOfYear(type_.clone()),
+            CSC::Hour(type_) => CalSpecifier::Hour(type_.clone()),
+            CSC::Minute(type_) => CalSpecifier::Minute(type_.clone()),
+        }
+    }
+
+    pub(crate) fn next_value(&self, date: NaiveDateTime) -> SpecValueResult {
         use CalSemCalSpecifier as CSC;
         match &self {
             CSC::Year(type_) => match type_ {
                 YearType::Full => YEAR_FULL_NEXT_FN(date),
                 YearType::Short | YearType::ZeroPadded => YEAR_SHORT_AND_ZERO_PADDED_NEXT_FN(date),
             },
-            CSC::Month(_) => Ok(MONTH_NEXT_FN(date)),
+            CSC::IsoYear => ISO_YEAR_NEXT_FN(date),
+            CSC::Quarter => Ok(QUARTER_NEXT_FN(date)),
+            CSC::Month(_) | CSC::MonthName(_) => Ok(MONTH_NEXT_FN(date)),
             CSC::Week(_) => Ok(WEEK_NEXT_FN(date)),
+            CSC::IsoWeek(_) => Ok(ISO_WEEK_NEXT_FN(date)),
             CSC::Day(_) => Ok(DAY_NEXT_FN(date)),
+            CSC::DayOfYear(_) => Ok(DAY_OF_YEAR_NEXT_FN(date)),
+            CSC::Hour(_) => Ok(HOUR_NEXT_FN(date)),
+            CSC::Minute(_) => Ok(MINUTE_NEXT_FN(date)),
         }
     }
 }
 
+/// A semantic specifier in a [`CalSem`](crate::CalSem) format, such as `<MINOR>` or `<PATCH>`.
 #[derive(Debug, PartialEq, Eq, Clone)]
-pub(crate) enum CalSemSemSpecifier {
+pub enum CalSemSemSpecifier {
+    /// An epoch specifier, `<EPOCH>`.
+    Epoch,
+    /// A minor version specifier, `<MINOR>`.
     Minor,
+    /// A patch version specifier, `<PATCH>`.
     Patch,
+    /// A revision version specifier, `<REVISION>`.
+    Revision,
+    /// A pre-release specifier, such as `<PRE>`.
+    PreRelease,
+    /// A build metadata specifier, `<META>`.
+    Metadata,
+    /// A monotonic build number specifier, `<BUILD>`.
+    Build,
 }
 
 impl CalSemSemSpecifier {
-    pub(crate) fn next_value(&self, cur_value: SpecValue, level: CalSemLevel) -> SpecValue {
+    pub(crate) fn next_value(&self, cur_value: SpecValue, level: CalSemLevel) -> SpecValueResult {
+        use CalSemLevel as L;
         use CalSemSemSpecifier as CSS;
-        match level {
-            CalSemLevel::Minor => match self {
-                CSS::Minor => cur_value + 1,
-                CSS::Patch => 0,
-            },
-            CalSemLevel::Patch => match self {
-                CSS::Minor => cur_value,
-                CSS::Patch => cur_value + 1,
-            },
-        }
+        let next_value = match (level, self) {
+            (_, CSS::PreRelease | CSS::Metadata) => return no_value(self),
+            // the build number increments on every bump and is never reset
+            (_, CSS::Build)
+            | (L::Epoch, CSS::Epoch)
+            | (L::Minor, CSS::Minor)
+            | (L::Patch, CSS::Patch)
+            | (L::Revision, CSS::Revision) => increment(cur_value, self)?,
+            (L::Epoch, _) | (L::Minor, CSS::Patch | CSS::Revision) | (L::Patch, CSS::Revision) => 0,
+            // the epoch is only incremented explicitly and is never reset
+            _ => cur_value,
+        };
+        Ok(next_value)
+    }
+}
+
+impl Display for CalSemSemSpecifier {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&CalSemSpecifier::Sem(self.clone()), f)
     }
 }
 
+/// A [`CalSem`](crate::CalSem) version specifier: either a calendar one, such as `<YYYY>`, or a
+/// semantic one, such as `<PATCH>`.
 #[derive(Debug, PartialEq, Eq, Clone)]
-pub(crate) enum CalSemSpecifier {
+pub enum CalSemSpecifier {
+    /// A calendar specifier, whose value comes from a date.
     Cal(CalSemCalSpecifier),
+    /// A semantic specifier, whose value is incremented.
     Sem(CalSemSemSpecifier),
 }
 
 impl Display for CalSemSpecifier {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(unsafe { std::str::from_utf8_unchecked(self.format_pattern()) })
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(unsafe { core::str::from_utf8_unchecked(self.format_pattern()) })
     }
 }
 
-impl Specifier for CalSemSpecifier {
-    fn format_pattern(&self) -> &'static [u8] {
+impl CalSemSpecifier {
+    /// The `const` version of [`Specifier::format_pattern`].
+    pub(crate) const fn pattern(&self) -> &'static [u8] {
         use CalSemCalSpecifier as CSC;
         use CalSemSemSpecifier as CSS;
         use CalSemSpecifier as S;
@@ -504,24 +1830,52 @@ impl Specifier for CalSemSpecifier {
                 YearType::Short => YEAR_SHORT_FORMAT_STRINGS,
                 YearType::ZeroPadded => YEAR_ZERO_PADDED_FORMAT_STRINGS,
             },
+            S::Cal(CSC::IsoYear) => ISO_YEAR_FORMAT_STRINGS,
+            S::Cal(CSC::Quarter) => QUARTER_FORMAT_STRINGS,
             S::Cal(CSC::Month(type_)) => match type_ {
                 NonYearType::Short => MONTH_SHORT_FORMAT_STRINGS,
                 NonYearType::ZeroPadded => MONTH_ZERO_PADDED_FORMAT_STRINGS,
             },
+            S::Cal(CSC::MonthName(type_)) => match type_ {
+                NameType::Abbreviated => MONTH_ABBREVIATED_FORMAT_STRINGS,
+                NameType::Full => MONTH_FULL_FORMAT_STRINGS,
+            },
             S::Cal(CSC::Week(type_)) => match type_ {
                 NonYearType::Short => WEEK_SHORT_FORMAT_STRINGS,
                 NonYearType::ZeroPadded => WEEK_ZERO_PADDED_FORMAT_STRINGS,
             },
+            S::Cal(CSC::IsoWeek(type_)) => match type_ {
+                NonYearType::Short => ISO_WEEK_SHORT_FORMAT_STRINGS,
+                NonYearType::ZeroPadded => ISO_WEEK_ZERO_PADDED_FORMAT_STRINGS,
+            },
             S::Cal(CSC::Day(type_)) => match type_ {
                 NonYearType::Short => DAY_SHORT_FORMAT_STRINGS,
                 NonYearType::ZeroPadded => DAY_ZERO_PADDED_FORMAT_STRINGS,
             },
+            S::Cal(CSC::DayOfYear(type_)) => match type_ {
+                NonYearType::Short => DAY_OF_YEAR_SHORT_FORMAT_STRINGS,
+                NonYearType::ZeroPadded => DAY_OF_YEAR_ZERO_PADDED_FORMAT_STRINGS,
+            },
+            S::Cal(CSC::Hour(type_)) => match type_ {
+                NonYearType::Short => HOUR_SHORT_FORMAT_STRINGS,
+                NonYearType::ZeroPadded => HOUR_ZERO_PADDED_FORMAT_STRINGS,
+            },
+            S::Cal(CSC::Minute(type_)) => match type_ {
+                NonYearType::Short => MINUTE_SHORT_FORMAT_STRINGS,
+                NonYearType::ZeroPadded => MINUTE_ZERO_PADDED_FORMAT_STRINGS,
+            },
             S::Sem(CSS::Minor) => MINOR_FORMAT_PATTERN,
+            S::Sem(CSS::Epoch) => EPOCH_FORMAT_PATTERN,
             S::Sem(CSS::Patch) => PATCH_FORMAT_PATTERN,
+            S::Sem(CSS::Revision) => REVISION_FORMAT_PATTERN,
+            S::Sem(CSS::PreRelease) => PRE_RELEASE_FORMAT_PATTERN,
+            S::Sem(CSS::Metadata) => METADATA_FORMAT_PATTERN,
+            S::Sem(CSS::Build) => BUILD_FORMAT_PATTERN,
         }
     }
 
-    fn format_width(&self) -> usize {
+    /// The `const` version of [`Specifier::format_width`].
+    pub(crate) const fn width(&self) -> usize {
         use CalSemCalSpecifier as CSC;
         use CalSemSemSpecifier as CSS;
         use CalSemSpecifier as S;
@@ -531,38 +1885,211 @@ impl Specifier for CalSemSpecifier {
                 YearType::Short => YEAR_SHORT_FORMAT_WIDTH,
                 YearType::ZeroPadded => YEAR_ZERO_PADDED_FORMAT_WIDTH,
             },
+            S::Cal(CSC::IsoYear) => ISO_YEAR_FORMAT_WIDTH,
+            S::Cal(CSC::Quarter) => QUARTER_FORMAT_WIDTH,
             S::Cal(CSC::Month(type_)) => match type_ {
                 NonYearType::Short => MONTH_SHORT_FORMAT_WIDTH,
                 NonYearType::ZeroPadded => MONTH_ZERO_PADDED_FORMAT_WIDTH,
             },
+            S::Cal(CSC::MonthName(_)) => MONTH_NAME_FORMAT_WIDTH,
             S::Cal(CSC::Week(type_)) => match type_ {
                 NonYearType::Short => WEEK_SHORT_FORMAT_WIDTH,
                 NonYearType::ZeroPadded => WEEK_ZERO_PADDED_FORMAT_WIDTH,
             },
+            S::Cal(CSC::IsoWeek(type_)) => match type_ {
+                NonYearType::Short => ISO_WEEK_SHORT_FORMAT_WIDTH,
+                NonYearType::ZeroPadded => ISO_WEEK_ZERO_PADDED_FORMAT_WIDTH,
+            },
             S::Cal(CSC::Day(type_)) => match type_ {
                 NonYearType::Short => DAY_SHORT_FORMAT_WIDTH,
                 NonYearType::ZeroPadded => DAY_ZERO_PADDED_FORMAT_WIDTH,
             },
-            S::Sem(CSS::Minor | CSS::Patch) => SEM_FORMAT_WIDTH,
+            S::Cal(CSC::DayOfYear(type_)) => match type_ {
+                NonYearType::Short => DAY_OF_YEAR_SHORT_FORMAT_WIDTH,
+                NonYearType::ZeroPadded => DAY_OF_YEAR_ZERO_PADDED_FORMAT_WIDTH,
+            },
+            S::Cal(CSC::Hour(type_)) => match type_ {
+                NonYearType::Short => HOUR_SHORT_FORMAT_WIDTH,
+                NonYearType::ZeroPadded => HOUR_ZERO_PADDED_FORMAT_WIDTH,
+            },
+            S::Cal(CSC::Minute(type_)) => match type_ {
+                NonYearType::Short => MINUTE_SHORT_FORMAT_WIDTH,
+                NonYearType::ZeroPadded => MINUTE_ZERO_PADDED_FORMAT_WIDTH,
+            },
+            S::Sem(
+                CSS::Epoch
+                | CSS::Minor
+                | CSS::Patch
+                | CSS::Revision
+                | CSS::PreRelease
+                | CSS::Metadata
+                | CSS::Build,
+            ) => SEM_FORMAT_WIDTH,
+        }
+    }
+
+    /// The `const` version of [`Specifier::can_be_zero`].
+    pub(crate) const fn zeroable(&self) -> bool {
+        use CalSemCalSpecifier as CSC;
+        use CalSemSemSpecifier as CSS;
+        use CalSemSpecifier as S;
+        match self {
+            S::Cal(CSC::Year(type_)) => match type_ {
+                YearType::Full => YEAR_FULL_CAN_BE_ZERO,
+                YearType::Short => YEAR_SHORT_CAN_BE_ZERO,
+                YearType::ZeroPadded => YEAR_ZERO_PADDED_CAN_BE_ZERO,
+            },
+            S::Cal(CSC::IsoYear) => ISO_YEAR_CAN_BE_ZERO,
+            S::Cal(CSC::Quarter) => QUARTER_CAN_BE_ZERO,
+            S::Cal(CSC::Month(_) | CSC::MonthName(_)) => MONTH_CAN_BE_ZERO,
+            S::Cal(CSC::Week(_)) => WEEK_CAN_BE_ZERO,
+            S::Cal(CSC::IsoWeek(_)) => ISO_WEEK_CAN_BE_ZERO,
+            S::Cal(CSC::Day(_)) => DAY_CAN_BE_ZERO,
+            S::Cal(CSC::DayOfYear(_)) => DAY_OF_YEAR_CAN_BE_ZERO,
+            S::Cal(CSC::Hour(_)) => HOUR_CAN_BE_ZERO,
+            S::Cal(CSC::Minute(_)) => MINUTE_CAN_BE_ZERO,
+            S::Sem(
+                CSS::Epoch
+                | CSS::Minor
+                | CSS::Patch
+                | CSS::Revision
+                | CSS::PreRelease
+                | CSS::Metadata
+                | CSS::Build,
+            ) => SEM_CAN_BE_ZERO,
+        }
+    }
+
+    /// The `const` version of [`Specifier::min_width`].
+    pub(crate) const fn least_width(&self) -> usize {
+        match self {
+            CalSemSpecifier::Cal(CalSemCalSpecifier::Year(YearType::Full)) => YEAR_FULL_MIN_WIDTH,
+            CalSemSpecifier::Cal(CalSemCalSpecifier::IsoYear) => ISO_YEAR_MIN_WIDTH,
+            _ => SEM_MIN_WIDTH,
+        }
+    }
+
+    /// The `const` version of [`Specifier::can_be_left_adjacent_to`].
+    pub(crate) const fn precedes(&self, other: &Self) -> bool {
+        use CalSemCalSpecifier as CSC;
+        use CalSemSemSpecifier as CSS;
+        use CalSemSpecifier as S;
+        matches!(
+            (self, other),
+            (S::Sem(CSS::Epoch), S::Cal(CSC::Year(_) | CSC::IsoYear))
+                | (
+                    S::Cal(CSC::Year(_)),
+                    S::Cal(
+                        CSC::Quarter
+                            | CSC::Month(_)
+                            | CSC::MonthName(_)
+                            | CSC::Week(_)
+                            | CSC::DayOfYear(_)
+                    ) | S::Sem(CSS::Minor | CSS::Patch)
+                )
+                | (
+                    S::Cal(CSC::IsoYear),
+                    S::Cal(CSC::IsoWeek(_)) | S::Sem(CSS::Minor | CSS::Patch)
+                )
+                | (
+                    S::Cal(CSC::Quarter),
+                    S::Cal(CSC::Month(_) | CSC::MonthName(_)) | S::Sem(CSS::Minor | CSS::Patch)
+                )
+                | (
+                    S::Cal(CSC::Month(_) | CSC::MonthName(_)),
+                    S::Cal(CSC::Day(_)) | S::Sem(CSS::Minor | CSS::Patch)
+                )
+                | (
+                    S::Cal(CSC::Day(_) | CSC::DayOfYear(_)),
+                    S::Cal(CSC::Hour(_))
+                )
+                | (S::Cal(CSC::Hour(_)), S::Cal(CSC::Minute(_)))
+                | (
+                    S::Cal(
+                        CSC::Week(_)
+                            | CSC::IsoWeek(_)
+                            | CSC::Day(_)
+                            | CSC::DayOfYear(_)
+                            | CSC::Hour(_)
+                            | CSC::Minute(_)
+                    ),
+                    S::Sem(CSS::Minor)
+                )
+                | (
+                    S::Cal(
+                        CSC::Week(_)
+                            | CSC::IsoWeek(_)
+                            | CSC::Day(_)
+                            | CSC::DayOfYear(_)
+                            | CSC::Hour(_)
+                            | CSC::Minute(_)
+                    ) | S::Sem(CSS::Minor),
+                    S::Sem(CSS::Patch)
+                )
+                | (S::Sem(CSS::Patch), S::Sem(CSS::Revision))
+                | (S::Sem(CSS::Patch | CSS::Revision), S::Sem(CSS::PreRelease))
+                | (
+                    S::Sem(CSS::Patch | CSS::Revision | CSS::PreRelease),
+                    S::Sem(CSS::Build)
+                )
+                | (
+                    S::Sem(CSS::Patch | CSS::Revision | CSS::PreRelease | CSS::Build),
+                    S::Sem(CSS::Metadata)
+                )
+        )
+    }
+
+    /// The `const` version of [`Specifier::value_names`].
+    pub(crate) const fn names(&self) -> Option<&'static [&'static str]> {
+        match self {
+            CalSemSpecifier::Cal(CalSemCalSpecifier::MonthName(NameType::Abbreviated)) => {
+                Some(MONTH_ABBREVIATED_NAMES)
+            }
+            CalSemSpecifier::Cal(CalSemCalSpecifier::MonthName(NameType::Full)) => {
+                Some(MONTH_FULL_NAMES)
+            }
+            _ => None,
         }
     }
 
+    /// The `const` version of [`Specifier::is_pre_release`].
+    pub(crate) const fn pre_release(&self) -> bool {
+        matches!(self, CalSemSpecifier::Sem(CalSemSemSpecifier::PreRelease))
+    }
+
+    /// The `const` version of [`Specifier::is_metadata`].
+    pub(crate) const fn metadata(&self) -> bool {
+        matches!(self, CalSemSpecifier::Sem(CalSemSemSpecifier::Metadata))
+    }
+}
+
+impl Specifier for CalSemSpecifier {
+    fn format_pattern(&self) -> &'static [u8] {
+        self.pattern()
+    }
+
+    fn format_width(&self) -> usize {
+        self.width()
+    }
+
+    fn min_width(&self) -> usize {
+        self.least_width()
+    }
+
     fn can_be_first(&self) -> bool {
-        matches!(self, CalSemSpecifier::Cal(CalSemCalSpecifier::Year(_)))
+        matches!(
+            self,
+            CalSemSpecifier::Cal(CalSemCalSpecifier::Year(_) | CalSemCalSpecifier::IsoYear)
+                | CalSemSpecifier::Sem(CalSemSemSpecifier::Epoch)
+        )
     }
 
     fn first_variants() -> &'static [&'static Self] {
-        static FIRST: &[&CalSemSpecifier] = &[
-            &CALSEM_YEAR_FULL,
-            &CALSEM_YEAR_SHORT,
-            &CALSEM_YEAR_ZERO_PADDED,
-        ];
-        FIRST
+        CALSEM_FIRST
     }
 
     fn last_variants() -> &'static [&'static Self] {
-        static LAST: &[&CalSemSpecifier] = &[&CALSEM_PATCH];
-        LAST
+        CALSEM_LAST
     }
 
     fn all() -> &'static [&'static Self] {
@@ -577,108 +2104,251 @@ impl Specifier for CalSemSpecifier {
             S::Cal(CSC::Year(YearType::Full)) => YEAR_FULL_PARSE_WIDTH,
             S::Cal(CSC::Year(YearType::Short)) => YEAR_SHORT_PARSE_WIDTH,
             S::Cal(CSC::Year(YearType::ZeroPadded)) => YEAR_ZERO_PADDED_PARSE_WIDTH,
+            S::Cal(CSC::IsoYear) => ISO_YEAR_PARSE_WIDTH,
+            S::Cal(CSC::Quarter) => QUARTER_PARSE_WIDTH,
             S::Cal(
                 CSC::Month(NonYearType::ZeroPadded)
                 | CSC::Week(NonYearType::ZeroPadded)
-                | CSC::Day(NonYearType::ZeroPadded),
+                | CSC::IsoWeek(NonYearType::ZeroPadded)
+                | CSC::Day(NonYearType::ZeroPadded)
+                | CSC::Hour(NonYearType::ZeroPadded)
+                | CSC::Minute(NonYearType::ZeroPadded),
             ) => MONTH_WEEK_DAY_ZERO_PADDED_PARSE_WIDTH,
             S::Cal(
                 CSC::Month(NonYearType::Short)
                 | CSC::Week(NonYearType::Short)
-                | CSC::Day(NonYearType::Short),
+                | CSC::IsoWeek(NonYearType::Short)
+                | CSC::Day(NonYearType::Short)
+                | CSC::Hour(NonYearType::Short)
+                | CSC::Minute(NonYearType::Short),
             ) => MONTH_WEEK_DAY_SHORT_PARSE_WIDTH,
-            S::Sem(CSS::Minor | CSS::Patch) => SEM_PARSE_WIDTH,
+            S::Cal(CSC::MonthName(NameType::Abbreviated)) => MONTH_ABBREVIATED_PARSE_WIDTH,
+            S::Cal(CSC::MonthName(NameType::Full)) => MONTH_FULL_PARSE_WIDTH,
+            S::Cal(CSC::DayOfYear(NonYearType::Short)) => DAY_OF_YEAR_SHORT_PARSE_WIDTH,
+            S::Cal(CSC::DayOfYear(NonYearType::ZeroPadded)) => DAY_OF_YEAR_ZERO_PADDED_PARSE_WIDTH,
+            S::Sem(
+                CSS::Epoch
+                | CSS::Minor
+                | CSS::Patch
+                | CSS::Revision
+                | CSS::PreRelease
+                | CSS::Metadata
+                | CSS::Build,
+            ) => SEM_PARSE_WIDTH,
         }
     }
 
     fn can_be_zero(&self) -> bool {
-        use CalSemCalSpecifier as CSC;
-        use CalSemSemSpecifier as CSS;
-        use CalSemSpecifier as S;
-        match self {
-            S::Cal(CSC::Year(type_)) => match type_ {
-                YearType::Full => YEAR_FULL_CAN_BE_ZERO,
-                YearType::Short => YEAR_SHORT_CAN_BE_ZERO,
-                YearType::ZeroPadded => YEAR_ZERO_PADDED_CAN_BE_ZERO,
-            },
-            S::Cal(CSC::Month(_)) => MONTH_CAN_BE_ZERO,
-            S::Cal(CSC::Week(_)) => WEEK_CAN_BE_ZERO,
-            S::Cal(CSC::Day(_)) => DAY_CAN_BE_ZERO,
-            S::Sem(CSS::Minor | CSS::Patch) => SEM_CAN_BE_ZERO,
-        }
+        self.zeroable()
     }
 
     fn can_be_left_adjacent_to(&self, other: &Self) -> bool {
-        use CalSemCalSpecifier as CSC;
+        self.precedes(other)
+    }
+
+    fn is_pre_release(&self) -> bool {
+        self.pre_release()
+    }
+
+    fn is_metadata(&self) -> bool {
+        self.metadata()
+    }
+
+    fn is_build(&self) -> bool {
+        matches!(self, CalSemSpecifier::Sem(CalSemSemSpecifier::Build))
+    }
+
+    fn is_epoch(&self) -> bool {
+        matches!(self, CalSemSpecifier::Sem(CalSemSemSpecifier::Epoch))
+    }
+
+    fn bump_value(&self, value: SpecValue, level: &Self) -> SpecValueResult {
         use CalSemSemSpecifier as CSS;
         use CalSemSpecifier as S;
-        matches!(
-            (self, other),
-            (
-                S::Cal(CSC::Year(_)),
-                S::Cal(CSC::Month(_) | CSC::Week(_)) | S::Sem(CSS::Minor | CSS::Patch)
-            ) | (
-                S::Cal(CSC::Month(_)),
-                S::Cal(CSC::Day(_)) | S::Sem(CSS::Minor | CSS::Patch)
-            ) | (S::Cal(CSC::Week(_) | CSC::Day(_)), S::Sem(CSS::Minor))
-                | (
-                    S::Cal(CSC::Week(_) | CSC::Day(_)) | S::Sem(CSS::Minor),
-                    S::Sem(CSS::Patch)
-                )
-        )
+        let (
+            S::Sem(this),
+            S::Sem(level_spec @ (CSS::Epoch | CSS::Minor | CSS::Patch | CSS::Revision)),
+        ) = (self, level)
+        else {
+            return default_bump_value(self, value, level);
+        };
+        let level = match level_spec {
+            CSS::Epoch => CalSemLevel::Epoch,
+            CSS::Minor => CalSemLevel::Minor,
+            CSS::Patch => CalSemLevel::Patch,
+            _ => CalSemLevel::Revision,
+        };
+        this.next_value(value, level)
+    }
+
+    fn value_names(&self) -> Option<&'static [&'static str]> {
+        self.names()
+    }
+
+    fn max_value(&self) -> SpecValue {
+        match self {
+            CalSemSpecifier::Cal(CalSemCalSpecifier::Quarter) => QUARTER_MAX_VALUE,
+            CalSemSpecifier::Cal(CalSemCalSpecifier::DayOfYear(_)) => DAY_OF_YEAR_MAX_VALUE,
+            _ => SpecValue::MAX,
+        }
     }
 }
+pub(crate) const CALSEM_EPOCH: CalSemSpecifier = CalSemSpecifier::Sem(CalSemSemSpecifier::Epoch);
 pub(crate) const CALSEM_YEAR_FULL: CalSemSpecifier =
     CalSemSpecifier::Cal(CalSemCalSpecifier::Year(YearType::Full));
 pub(crate) const CALSEM_YEAR_SHORT: CalSemSpecifier =
     CalSemSpecifier::Cal(CalSemCalSpecifier::Year(YearType::Short));
 pub(crate) const CALSEM_YEAR_ZERO_PADDED: CalSemSpecifier =
     CalSemSpecifier::Cal(CalSemCalSpecifier::Year(YearType::ZeroPadded));
+pub(crate) const CALSEM_ISO_YEAR: CalSemSpecifier =
+    CalSemSpecifier::Cal(CalSemCalSpecifier::IsoYear);
+pub(crate) const CALSEM_QUARTER: CalSemSpecifier =
+    CalSemSpecifier::Cal(CalSemCalSpecifier::Quarter);
 pub(crate) const CALSEM_MONTH_SHORT: CalSemSpecifier =
     CalSemSpecifier::Cal(CalSemCalSpecifier::Month(NonYearType::Short));
 pub(crate) const CALSEM_MONTH_ZERO_PADDED: CalSemSpecifier =
     CalSemSpecifier::Cal(CalSemCalSpecifier::Month(NonYearType::ZeroPadded));
+pub(crate) const CALSEM_MONTH_ABBREVIATED: CalSemSpecifier =
+    CalSemSpecifier::Cal(CalSemCalSpecifier::MonthName(NameType::Abbreviated));
+pub(crate) const CALSEM_MONTH_FULL: CalSemSpecifier =
+    CalSemSpecifier::Cal(CalSemCalSpecifier::MonthName(NameType::Full));
 pub(crate) const CALSEM_WEEK_SHORT: CalSemSpecifier =
     CalSemSpecifier::Cal(CalSemCalSpecifier::Week(NonYearType::Short));
 pub(crate) const CALSEM_WEEK_ZERO_PADDED: CalSemSpecifier =
     CalSemSpecifier::Cal(CalSemCalSpecifier::Week(NonYearType::ZeroPadded));
+pub(crate) const CALSEM_ISO_WEEK_SHORT: CalSemSpecifier =
+    CalSemSpecifier::Cal(CalSemCalSpecifier::IsoWeek(NonYearType::Short));
+pub(crate) const CALSEM_ISO_WEEK_ZERO_PADDED: CalSemSpecifier =
+    CalSemSpecifier::Cal(CalSemCalSpecifier::IsoWeek(NonYearType::ZeroPadded));
 pub(crate) const CALSEM_DAY_SHORT: CalSemSpecifier =
     CalSemSpecifier::Cal(CalSemCalSpecifier::Day(NonYearType::Short));
 pub(crate) const CALSEM_DAY_ZERO_PADDED: CalSemSpecifier =
     CalSemSpecifier::Cal(CalSemCalSpecifier::Day(NonYearType::ZeroPadded));
+pub(crate) const CALSEM_DAY_OF_YEAR_SHORT: CalSemSpecifier =
+    CalSemSpecifier::Cal(CalSemCalSpecifier::DayOfYear(NonYearType::Short));
+pub(crate) const CALSEM_DAY_OF_YEAR_ZERO_PADDED: CalSemSpecifier =
+    CalSemSpecifier::Cal(CalSemCalSpecifier::DayOfYear(NonYearType::ZeroPadded));
+pub(crate) const CALSEM_HOUR_SHORT: CalSemSpecifier =
+    CalSemSpecifier::Cal(CalSemCalSpecifier::Hour(NonYearType::Short));
+pub(crate) const CALSEM_HOUR_ZERO_PADDED: CalSemSpecifier =
+    CalSemSpecifier::Cal(CalSemCalSpecifier::Hour(NonYearType::ZeroPadded));
+pub(crate) const CALSEM_MINUTE_SHORT: CalSemSpecifier =
+    CalSemSpecifier::Cal(CalSemCalSpecifier::Minute(NonYearType::Short));
+pub(crate) const CALSEM_MINUTE_ZERO_PADDED: CalSemSpecifier =
+    CalSemSpecifier::Cal(CalSemCalSpecifier::Minute(NonYearType::ZeroPadded));
 pub(crate) const CALSEM_MINOR: CalSemSpecifier = CalSemSpecifier::Sem(CalSemSemSpecifier::Minor);
 pub(crate) const CALSEM_PATCH: CalSemSpecifier = CalSemSpecifier::Sem(CalSemSemSpecifier::Patch);
-const CALSEM_ALL: &[&CalSemSpecifier] = &[
+pub(crate) const CALSEM_REVISION: CalSemSpecifier =
+    CalSemSpecifier::Sem(CalSemSemSpecifier::Revision);
+pub(crate) const CALSEM_PRE_RELEASE: CalSemSpecifier =
+    CalSemSpecifier::Sem(CalSemSemSpecifier::PreRelease);
+pub(crate) const CALSEM_METADATA: CalSemSpecifier =
+    CalSemSpecifier::Sem(CalSemSemSpecifier::Metadata);
+pub(crate) const CALSEM_BUILD: CalSemSpecifier = CalSemSpecifier::Sem(CalSemSemSpecifier::Build);
+pub(crate) const CALSEM_FIRST: &[&CalSemSpecifier] = &[
+    &CALSEM_EPOCH,
+    &CALSEM_YEAR_FULL,
+    &CALSEM_YEAR_SHORT,
+    &CALSEM_YEAR_ZERO_PADDED,
+    &CALSEM_ISO_YEAR,
+];
+pub(crate) const CALSEM_LAST: &[&CalSemSpecifier] = &[
+    &CALSEM_PATCH,
+    &CALSEM_REVISION,
+    &CALSEM_PRE_RELEASE,
+    &CALSEM_BUILD,
+    &CALSEM_METADATA,
+];
+pub(crate) const CALSEM_ALL: &[&CalSemSpecifier] = &[
+    &CALSEM_EPOCH,
     &CALSEM_YEAR_FULL,
     &CALSEM_YEAR_SHORT,
     &CALSEM_YEAR_ZERO_PADDED,
+    &CALSEM_ISO_YEAR,
+    &CALSEM_QUARTER,
     &CALSEM_MONTH_SHORT,
     &CALSEM_MONTH_ZERO_PADDED,
+    &CALSEM_MONTH_ABBREVIATED,
+    &CALSEM_MONTH_FULL,
     &CALSEM_WEEK_SHORT,
     &CALSEM_WEEK_ZERO_PADDED,
+    &CALSEM_ISO_WEEK_SHORT,
+    &CALSEM_ISO_WEEK_ZERO_PADDED,
     &CALSEM_DAY_SHORT,
     &CALSEM_DAY_ZERO_PADDED,
+    &CALSEM_DAY_OF_YEAR_SHORT,
+    &CALSEM_DAY_OF_YEAR_ZERO_PADDED,
+    &CALSEM_HOUR_SHORT,
+    &CALSEM_HOUR_ZERO_PADDED,
+    &CALSEM_MINUTE_SHORT,
+    &CALSEM_MINUTE_ZERO_PADDED,
     &CALSEM_MINOR,
     &CALSEM_PATCH,
+    &CALSEM_REVISION,
+    &CALSEM_PRE_RELEASE,
+    &CALSEM_BUILD,
+    &CALSEM_METADATA,
 ];
+pub(crate) const CALSEM_RULES: [SpecifierRules<CalSemSpecifier>; CALSEM_ALL.len()] =
+    builtin_rules!(CALSEM_ALL, CALSEM_FIRST, CALSEM_LAST);
 
 /// A semantic-type specifier to increment in a [`CalSem`](crate::CalSem)
 /// [`Version`](crate::Version).
+///
+/// With the `serde` feature, levels are (de)serialized in kebab-case, e.g., `pre-release`.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum CalSemLevel {
-    /// Refers to the minor version specifier, `<MINOR>`. It is greater than `<PATCH>`.
+    /// Refers to the epoch specifier, `<EPOCH>`. It is greater than all other specifiers, and is
+    /// never reset when they are incremented or when the date advances.
+    Epoch,
+    /// Refers to the minor version specifier, `<MINOR>`. It is greater than `<PATCH>` and
+    /// `<REVISION>`.
     Minor,
-    /// Refers to the patch version specifier, `<PATCH>`. It is less than `<MINOR>`.
+    /// Refers to the patch version specifier, `<PATCH>`. It is greater than `<REVISION>` and less
+    /// than `<MINOR>`.
     Patch,
+    /// Refers to the revision version specifier, `<REVISION>`. It is less than `<PATCH>` and
+    /// `<MINOR>`.
+    Revision,
+    /// Refers to the number of the pre-release specifier, `<PRE>`. Incrementing it keeps the
+    /// current label, e.g., `rc.1` → `rc.2`.
+    PreRelease,
+    /// Refers to the label of the pre-release specifier, `<PRE>`. Incrementing it promotes the
+    /// version to the next label and resets the number to zero, e.g., `beta.3` → `rc.0`.
+    PreReleaseLabel,
+    /// Refers to finalizing the pre-release specifier, `<PRE>`. "Incrementing" it removes the
+    /// pre-release from the version, e.g., `2024.10.0-rc.2` → `2024.10.0`.
+    FinalRelease,
 }
 
 impl Level for CalSemLevel {
     type Specifier = CalSemSpecifier;
 
-    fn spec(&self) -> &Self::Specifier {
+    fn spec(&self) -> &'static Self::Specifier {
         match self {
+            Self::Epoch => &CALSEM_EPOCH,
             Self::Minor => &CALSEM_MINOR,
             Self::Patch => &CALSEM_PATCH,
+            Self::Revision => &CALSEM_REVISION,
+            Self::PreRelease | Self::PreReleaseLabel | Self::FinalRelease => &CALSEM_PRE_RELEASE,
+        }
+    }
+
+    fn has_value(&self) -> bool {
+        !matches!(self, Self::PreReleaseLabel | Self::FinalRelease)
+    }
+
+    fn pre_release_bump(&self, step: Step) -> PreReleaseBump {
+        match self {
+            Self::Epoch | Self::Minor | Self::Patch | Self::Revision => PreReleaseBump::Clear,
+            Self::PreRelease => PreReleaseBump::Number(step),
+            Self::PreReleaseLabel => PreReleaseBump::Label,
+            Self::FinalRelease => PreReleaseBump::Final,
         }
     }
 }
@@ -709,6 +2379,145 @@ mod tests {
         assert!(!Patch.can_be_left_adjacent_to(&Major));
         assert!(!Patch.can_be_left_adjacent_to(&Minor));
         assert!(!Patch.can_be_left_adjacent_to(&Patch));
+        assert!(Patch.can_be_left_adjacent_to(&Revision));
+
+        assert!(!Major.can_be_left_adjacent_to(&Revision));
+        assert!(!Minor.can_be_left_adjacent_to(&Revision));
+        assert!(!Revision.can_be_left_adjacent_to(&Major));
+        assert!(!Revision.can_be_left_adjacent_to(&Minor));
+        assert!(!Revision.can_be_left_adjacent_to(&Patch));
+        assert!(!Revision.can_be_left_adjacent_to(&Revision));
+
+        assert!(Major.can_be_left_adjacent_to(&PreRelease));
+        assert!(Minor.can_be_left_adjacent_to(&PreRelease));
+        assert!(Patch.can_be_left_adjacent_to(&PreRelease));
+        assert!(Revision.can_be_left_adjacent_to(&PreRelease));
+        assert!(!PreRelease.can_be_left_adjacent_to(&Major));
+        assert!(!PreRelease.can_be_left_adjacent_to(&Minor));
+        assert!(!PreRelease.can_be_left_adjacent_to(&Patch));
+        assert!(!PreRelease.can_be_left_adjacent_to(&PreRelease));
+
+        assert!(Major.can_be_left_adjacent_to(&Build));
+        assert!(Patch.can_be_left_adjacent_to(&Build));
+        assert!(PreRelease.can_be_left_adjacent_to(&Build));
+        assert!(Revision.can_be_left_adjacent_to(&Build));
+        assert!(Build.can_be_left_adjacent_to(&Metadata));
+        assert!(!Build.can_be_left_adjacent_to(&Patch));
+        assert!(!Build.can_be_left_adjacent_to(&PreRelease));
+        assert!(!Metadata.can_be_left_adjacent_to(&Build));
+    }
+
+    #[test]
+    fn builtin_rules_match_methods() {
+        assert_eq!(SpecifierRules::<SemSpecifier>::derive_all(), SEM_RULES);
+        assert_eq!(SpecifierRules::<CalSpecifier>::derive_all(), CAL_RULES);
+        assert_eq!(
+            SpecifierRules::<CalSemSpecifier>::derive_all(),
+            CALSEM_RULES
+        );
+    }
+
+    #[test]
+    fn valid_labels() {
+        let args = [
+            ("alpha|beta|rc", true),
+            ("rc", true),
+            ("pre-alpha|alpha2", true),
+            ("", false),            // no labels
+            ("alpha||rc", false),   // empty label
+            ("alpha|alpha", false), // repeated label
+            ("alpha.1", false),     // bad character
+        ];
+
+        for (labels, passes) in args {
+            assert_eq!(passes, are_valid_labels(labels.as_bytes()));
+        }
+    }
+
+    #[test]
+    fn format_value_names() {
+        let month = CalSpecifier::MonthName(NameType::Abbreviated);
+        assert_eq!("Jan", month.format_value(1));
+        assert_eq!("Dec", month.format_value(12));
+        // values without names fall back to digits
+        assert_eq!("0", month.format_value(0));
+        assert_eq!("13", month.format_value(13));
+    }
+
+    #[test]
+    fn no_value_next_value() {
+        let no_value = |spec: &str| {
+            Err(NextError::SpecifierHasNoValue {
+                spec: spec.to_string(),
+            })
+        };
+        let date = NaiveDateTime::default();
+
+        assert_eq!(
+            no_value("<META>"),
+            SEM_METADATA.next_value(0, SemLevel::Patch, Step::By(1))
+        );
+        assert_eq!(
+            no_value("<PRE>"),
+            SEM_PRE_RELEASE.prev_value(1, SemLevel::PreRelease)
+        );
+        assert_eq!(no_value("<META>"), CAL_METADATA.next_value(0, date));
+        assert_eq!(
+            no_value("<META>"),
+            CalSemSemSpecifier::Metadata.next_value(0, CalSemLevel::Patch)
+        );
+    }
+
+    #[test]
+    fn pre_release_next_value() {
+        let labels = PreReleaseLabels::default();
+        let pre = |label, number| Some(PreReleaseValue { label, number });
+        let by_one = PreReleaseBump::Number(Step::By(1));
+
+        let args = [
+            (None, PreReleaseBump::Clear, Ok(None)),
+            (pre(1, 3), PreReleaseBump::Clear, Ok(None)),
+            (None, PreReleaseBump::Reset, Ok(pre(0, 0))),
+            (pre(1, 3), PreReleaseBump::Reset, Ok(pre(0, 0))),
+            (pre(1, 3), by_one, Ok(pre(1, 4))),
+            (
+                pre(1, 3),
+                PreReleaseBump::Number(Step::By(5)),
+                Ok(pre(1, 8)),
+            ),
+            (
+                pre(1, 3),
+                PreReleaseBump::Number(Step::To(7)),
+                Ok(pre(1, 7)),
+            ),
+            (pre(1, 3), PreReleaseBump::Label, Ok(pre(2, 0))),
+            (pre(1, 3), PreReleaseBump::Final, Ok(None)),
+            (
+                pre(2, 3),
+                PreReleaseBump::Label,
+                Err(NextError::NoNextPreReleaseLabel {
+                    label: "rc".to_string(),
+                }),
+            ),
+            (None, by_one, Err(NextError::NotPreRelease)),
+            (None, PreReleaseBump::Label, Err(NextError::NotPreRelease)),
+            (None, PreReleaseBump::Final, Err(NextError::NotPreRelease)),
+            (
+                pre(1, SpecValue::MAX),
+                by_one,
+                Err(NextError::ValueOverflow {
+                    spec: "<PRE>".to_string(),
+                    value: SpecValue::MAX,
+                }),
+            ),
+        ];
+
+        for (cur_value, bump, expected) in args {
+            assert_eq!(
+                expected,
+                labels.next_value(cur_value, bump, &SEM_PRE_RELEASE)
+            );
+        }
     }
 
     #[test]
@@ -718,7 +2527,15 @@ mod tests {
         use YearType::{Full as YFull, Short as YShort, ZeroPadded as YZeroPadded};
 
         let years = || [Year(YFull), Year(YShort), Year(YZeroPadded)].iter();
-        let months = || [Month(Short), Month(ZeroPadded)].iter();
+        let months = || {
+            [
+                Month(Short),
+                Month(ZeroPadded),
+                MonthName(NameType::Abbreviated),
+                MonthName(NameType::Full),
+            ]
+            .iter()
+        };
         let weeks = || [Week(Short), Week(ZeroPadded)].iter();
         let days = || [Day(Short), Day(ZeroPadded)].iter();
 
@@ -793,6 +2610,139 @@ mod tests {
         });
     }
 
+    #[test]
+    fn iso_ordering() {
+        use CalSpecifier::*;
+        use NonYearType::*;
+        use YearType::{Full as YFull, Short as YShort, ZeroPadded as YZeroPadded};
+
+        let years = || [Year(YFull), Year(YShort), Year(YZeroPadded)].iter();
+        let non_iso_weeks = || [Month(Short), Week(Short), Day(Short)].iter();
+        let iso_weeks = || [IsoWeek(Short), IsoWeek(ZeroPadded)].iter();
+
+        // iso weeks only follow the iso year
+        iso_weeks().for_each(|iso_week| {
+            assert!(IsoYear.can_be_left_adjacent_to(iso_week));
+            assert!(!iso_week.can_be_left_adjacent_to(&IsoYear));
+        });
+        years()
+            .cartesian_product(iso_weeks())
+            .for_each(|(year, iso_week)| {
+                assert!(!year.can_be_left_adjacent_to(iso_week));
+                assert!(!iso_week.can_be_left_adjacent_to(year));
+            });
+
+        // and the iso year only precedes iso weeks
+        non_iso_weeks().for_each(|other| {
+            assert!(!IsoYear.can_be_left_adjacent_to(other));
+            assert!(!other.can_be_left_adjacent_to(&IsoYear));
+        });
+
+        // iso weeks are not relative to each other or anything else
+        iso_weeks()
+            .cartesian_product(non_iso_weeks().chain(iso_weeks()))
+            .for_each(|(iso_week, other)| {
+                assert!(!iso_week.can_be_left_adjacent_to(other));
+                assert!(!other.can_be_left_adjacent_to(iso_week));
+            });
+
+        // calsem iso year and iso week may both precede minor and patch
+        let calsem_iso = [
+            CALSEM_ISO_YEAR,
+            CALSEM_ISO_WEEK_SHORT,
+            CALSEM_ISO_WEEK_ZERO_PADDED,
+        ];
+        for spec in &calsem_iso {
+            assert!(spec.can_be_left_adjacent_to(&CALSEM_MINOR));
+            assert!(spec.can_be_left_adjacent_to(&CALSEM_PATCH));
+        }
+        assert!(!CALSEM_ISO_YEAR.can_be_left_adjacent_to(&CALSEM_WEEK_SHORT));
+        assert!(!CALSEM_YEAR_FULL.can_be_left_adjacent_to(&CALSEM_ISO_WEEK_SHORT));
+    }
+
+    #[test]
+    fn epoch_ordering() {
+        // the epoch may only be first, followed by the scheme's usual first specifier
+        for spec in SemSpecifier::all() {
+            assert_eq!(**spec == SEM_MAJOR, SEM_EPOCH.can_be_left_adjacent_to(spec));
+            assert!(!spec.can_be_left_adjacent_to(&SEM_EPOCH));
+        }
+        for spec in CalSpecifier::all() {
+            assert_eq!(
+                spec.can_be_first() && **spec != CAL_EPOCH,
+                CAL_EPOCH.can_be_left_adjacent_to(spec)
+            );
+            assert!(!spec.can_be_left_adjacent_to(&CAL_EPOCH));
+        }
+        for spec in CalSemSpecifier::all() {
+            assert_eq!(
+                spec.can_be_first() && **spec != CALSEM_EPOCH,
+                CALSEM_EPOCH.can_be_left_adjacent_to(spec)
+            );
+            assert!(!spec.can_be_left_adjacent_to(&CALSEM_EPOCH));
+        }
+
+        assert!(SEM_EPOCH.can_be_first() && !SEM_EPOCH.can_be_last());
+        assert!(CAL_EPOCH.can_be_first() && !CAL_EPOCH.can_be_last());
+        assert!(CALSEM_EPOCH.can_be_first() && !CALSEM_EPOCH.can_be_last());
+    }
+
+    #[test]
+    fn quarter_and_day_of_year_ordering() {
+        use CalSpecifier::*;
+        use NonYearType::*;
+
+        let years = || {
+            [
+                CAL_YEAR_FULL,
+                CAL_YEAR_SHORT,
+                CAL_YEAR_ZERO_PADDED,
+                CAL_ISO_YEAR,
+            ]
+            .into_iter()
+        };
+        let days_of_year = || [DayOfYear(Short), DayOfYear(ZeroPadded)].into_iter();
+
+        // year -> quarter -> month, but not for the iso year
+        for year in years() {
+            let expected = year != IsoYear;
+            assert_eq!(expected, year.can_be_left_adjacent_to(&Quarter));
+            assert!(!Quarter.can_be_left_adjacent_to(&year));
+        }
+        assert!(Quarter.can_be_left_adjacent_to(&Month(Short)));
+        assert!(Quarter.can_be_left_adjacent_to(&Month(ZeroPadded)));
+        assert!(Quarter.can_be_left_adjacent_to(&MonthName(NameType::Abbreviated)));
+        assert!(Quarter.can_be_left_adjacent_to(&MonthName(NameType::Full)));
+        assert!(!Month(Short).can_be_left_adjacent_to(&Quarter));
+        assert!(!Quarter.can_be_left_adjacent_to(&Week(Short)));
+        assert!(!Quarter.can_be_left_adjacent_to(&Day(Short)));
+        assert!(!Quarter.can_be_left_adjacent_to(&Quarter));
+
+        // year -> day of year, and nothing after but hours, the build number, and metadata
+        years()
+            .cartesian_product(days_of_year())
+            .for_each(|(year, doy)| {
+                assert_eq!(year != IsoYear, year.can_be_left_adjacent_to(&doy));
+                assert!(!doy.can_be_left_adjacent_to(&year));
+            });
+        days_of_year()
+            .cartesian_product(CAL_ALL.iter())
+            .for_each(|(doy, other)| {
+                let expected = matches!(other, Hour(_) | Build | Metadata);
+                assert_eq!(expected, doy.can_be_left_adjacent_to(other));
+            });
+
+        // in calsem, both may precede the semantic specifiers
+        for spec in [
+            CALSEM_QUARTER,
+            CALSEM_DAY_OF_YEAR_SHORT,
+            CALSEM_DAY_OF_YEAR_ZERO_PADDED,
+        ] {
+            assert!(spec.can_be_left_adjacent_to(&CALSEM_MINOR));
+            assert!(spec.can_be_left_adjacent_to(&CALSEM_PATCH));
+        }
+    }
+
     #[test]
     #[allow(clippy::too_many_lines)]
     fn cal_sem_ordering() {
@@ -803,11 +2753,20 @@ mod tests {
         use YearType::{Full as YFull, Short as YShort, ZeroPadded as YZeroPadded};
 
         let years = || [Cal(Year(YFull)), Cal(Year(YShort)), Cal(Year(YZeroPadded))].iter();
-        let months = || [Cal(Month(Short)), Cal(Month(ZeroPadded))].iter();
+        let months = || {
+            [
+                Cal(Month(Short)),
+                Cal(Month(ZeroPadded)),
+                Cal(MonthName(NameType::Abbreviated)),
+                Cal(MonthName(NameType::Full)),
+            ]
+            .iter()
+        };
         let weeks = || [Cal(Week(Short)), Cal(Week(ZeroPadded))].iter();
         let days = || [Cal(Day(Short)), Cal(Day(ZeroPadded))].iter();
         let minors = || [Sem(Minor)].iter();
         let patches = || [Sem(Patch)].iter();
+        let revisions = || [Sem(Revision)].iter();
 
         // year and year
         years()
@@ -964,5 +2923,21 @@ mod tests {
                 assert!(!patch1.can_be_left_adjacent_to(patch2));
                 assert!(!patch2.can_be_left_adjacent_to(patch1));
             });
+
+        // patch and revision
+        patches()
+            .cartesian_product(revisions())
+            .for_each(|(patch, revision)| {
+                assert!(patch.can_be_left_adjacent_to(revision));
+                assert!(!revision.can_be_left_adjacent_to(patch));
+            });
+
+        // minor and revision
+        minors()
+            .cartesian_product(revisions())
+            .for_each(|(minor, revision)| {
+                assert!(!minor.can_be_left_adjacent_to(revision));
+                assert!(!revision.can_be_left_adjacent_to(minor));
+            });
     }
 }
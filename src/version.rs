@@ -1,14 +1,29 @@
 use crate::{
-    format::{Format, FormatToken},
+    format::{unescape_literal, Format, FormatToken},
     scheme::{Cal, CalSem, Scheme, Sem},
-    specifier::{CalSemLevel, CalSemSpecifier, Level, SpecValue, SpecValueResult, Specifier},
+    specifier::{
+        cmp_pre_release, format_metadata, full_year, increment, is_valid_metadata,
+        prev_pre_release, CalSemCalSpecifier, CalSemLevel, CalSemSpecifier, CalSpecifier, Level,
+        PreReleaseBump, PreReleaseLabels, PreReleaseValue, SpecValue, SpecValueResult, Specifier,
+        Step,
+    },
     SemLevel,
 };
-use chrono::{Local, NaiveDate, Utc};
+use alloc::{
+    borrow::Cow,
+    borrow::ToOwned,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+#[cfg(feature = "clock")]
+use chrono::{Local, Utc};
 use core::{
+    borrow::Borrow,
     cmp::Ordering,
     fmt::{self, Display},
-    ptr,
+    hash::{Hash, Hasher},
     str::{self, FromStr},
 };
 
@@ -17,6 +32,7 @@ use core::{
 #[derive(thiserror::Error, Debug, PartialEq)]
 pub enum NextError {
     /// When updating a [`Cal`](crate::Cal) version, the date provided yielded an identical version.
+    /// (This cannot happen if the format has a `<BUILD>` specifier.)
     #[error("date provided should yield a version that is newer/greater than the current version")]
     NoCalendarChange,
 
@@ -40,36 +56,255 @@ pub enum NextError {
     /// version.
     #[error("new date should be after date in version")]
     NewDateIsBefore,
+
+    /// When incrementing the pre-release number or label, or finalizing the pre-release, the
+    /// version was not a pre-release.
+    #[error("version should be a pre-release to increment or finalize its pre-release")]
+    NotPreRelease,
+
+    /// When promoting a pre-release to the next label, the current label was already the last
+    /// (greatest) one.
+    #[error("pre-release label `{label}` should not be the last label to promote it")]
+    NoNextPreReleaseLabel {
+        /// The current label
+        label: String,
+    },
+
+    /// When incrementing a version, the new value of a specifier given an explicit width (as in
+    /// `<PATCH:3>`) has more digits than that width.
+    #[error("value `{value}` of `{spec}` should fit in its width of {width} digit(s)")]
+    ValueOverflowsWidth {
+        /// The specifier
+        spec: String,
+        /// The new value
+        value: SpecValue,
+        /// The width of the specifier
+        width: usize,
+    },
+
+    /// When incrementing a version, a value would be greater than the greatest value a specifier
+    /// can have, [`SpecValue::MAX`](crate::SpecValue).
+    #[error(
+        "value `{value}` of `{spec}` should stay at most {max} when incremented",
+        max = SpecValue::MAX
+    )]
+    ValueOverflow {
+        /// The specifier
+        spec: String,
+        /// The current value
+        value: SpecValue,
+    },
+
+    /// When incrementing a version of a [custom scheme](crate#custom-schemes), the next value of
+    /// a specifier could not be determined.
+    #[error("{message}")]
+    Custom {
+        /// A description of the error
+        message: String,
+    },
+
+    /// When incrementing the epoch of a [`Cal`](crate::Cal) version, the format has no epoch
+    /// specifier.
+    #[error("`<EPOCH>` was not found in format, use one that does to increment the epoch")]
+    EpochNotInFormat,
+
+    /// When incrementing a version, a specifier has no numeric value, like the pre-release and
+    /// build metadata specifiers, `<PRE>` and `<META>`. Their values change separately.
+    #[error("`{spec}` should have a numeric value to increment it")]
+    SpecifierHasNoValue {
+        /// The specifier
+        spec: String,
+    },
+
+    /// When incrementing a [`Sem`](crate::Sem) version by an amount or to a value, the level
+    /// promotes or finalizes a pre-release, which has no value to change.
+    #[error("level should have a value to increment it by an amount or to a value")]
+    LevelHasNoValue,
+
+    /// When starting a pre-release, the level refers to the pre-release specifier instead of a
+    /// value to increment.
+    #[error("level should not refer to the pre-release specifier to start a pre-release")]
+    PreReleaseLevel,
+
+    /// When incrementing a [`Sem`](crate::Sem) version by an amount or to a value, the new version
+    /// was not greater than the current one.
+    #[error("next version `{next}` should be greater than current version `{current}`")]
+    NotGreater {
+        /// The current version
+        current: String,
+        /// The next version
+        next: String,
+    },
+
+    /// When decrementing a version, a value was already zero.
+    #[error("value of `{spec}` should be greater than 0 to decrement it")]
+    ValueUnderflow {
+        /// The specifier
+        spec: String,
+    },
+
+    /// When decrementing a [`Sem`](crate::Sem) version, the value of a lesser specifier in the
+    /// previous version cannot be determined, because incrementing resets it.
+    #[error("previous value of `{spec}` cannot be determined, use a level that does not reset it")]
+    PrevValueUnknown {
+        /// The specifier
+        spec: String,
+    },
+
+    /// When decrementing a [`Cal`](crate::Cal) version, its values do not represent a valid date.
+    #[error("values of version should represent a valid date to find the previous one")]
+    InvalidVersionDate,
+
+    /// When setting the build metadata of a version, the format has no metadata specifier.
+    #[error("`<META>` was not found in format, use one that does to set build metadata")]
+    MetadataNotInFormat,
+
+    /// When setting the build metadata of a version, the metadata was not valid.
+    #[error(
+        "build metadata should be non-empty and contain only ASCII alphanumerics, hyphens, and dots, got `{metadata}`"
+    )]
+    InvalidMetadata {
+        /// The metadata text
+        metadata: String,
+    },
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Eq)]
 pub(crate) enum VersionToken<'vs, S: Scheme> {
+    /// A numeric value. `width` is `Some` when the specifier was given an explicit width.
     Value {
         value: SpecValue,
         spec: &'static S::Specifier,
+        width: Option<usize>,
+    },
+    /// A pre-release value, which is `None` for a final release.
+    PreRelease {
+        value: Option<PreReleaseValue>,
+        spec: &'static S::Specifier,
+        labels: PreReleaseLabels,
+    },
+    /// A build metadata value, which is `None` when there is no metadata.
+    Metadata {
+        value: Option<String>,
+        spec: &'static S::Specifier,
     },
-    Literal(&'vs [u8]),
+    /// The start of an optional segment, which is not rendered when absent (`present` is false).
+    /// Values in an absent segment are zero.
+    OptionalStart { present: bool },
+    /// The end of an optional segment.
+    OptionalEnd,
+    /// Literal text, which is owned when it came from the format for an absent optional segment.
+    Literal(Cow<'vs, [u8]>),
+}
+
+impl<S: Scheme> PartialEq for VersionToken<'_, S> {
+    /// Build metadata is ignored when checking for equality, as it is for ordering. Otherwise,
+    /// tokens are equal when their variants and all fields are.
+    fn eq(&self, other: &Self) -> bool {
+        use VersionToken::{Literal, Metadata, OptionalEnd, OptionalStart, PreRelease, Value};
+        match (self, other) {
+            (
+                Value {
+                    value: val_a,
+                    spec: spec_a,
+                    width: width_a,
+                },
+                Value {
+                    value: val_b,
+                    spec: spec_b,
+                    width: width_b,
+                },
+            ) => val_a == val_b && spec_a == spec_b && width_a == width_b,
+            (
+                PreRelease {
+                    value: val_a,
+                    spec: spec_a,
+                    labels: labels_a,
+                },
+                PreRelease {
+                    value: val_b,
+                    spec: spec_b,
+                    labels: labels_b,
+                },
+            ) => val_a == val_b && spec_a == spec_b && labels_a == labels_b,
+            (Metadata { spec: spec_a, .. }, Metadata { spec: spec_b, .. }) => spec_a == spec_b,
+            // absent optional segments are equal to present ones whose values are zero
+            (OptionalStart { .. }, OptionalStart { .. }) | (OptionalEnd, OptionalEnd) => true,
+            (Literal(a), Literal(b)) => a == b,
+            _ => false,
+        }
+    }
 }
 
-impl<'vs, S: Scheme> Clone for VersionToken<'vs, S> {
+impl<S: Scheme> Clone for VersionToken<'_, S> {
     fn clone(&self) -> Self {
         match self {
-            VersionToken::Value { value, spec } => VersionToken::Value {
+            VersionToken::Value { value, spec, width } => VersionToken::Value {
+                value: *value,
+                spec: *spec,
+                width: *width,
+            },
+            VersionToken::PreRelease {
+                value,
+                spec,
+                labels,
+            } => VersionToken::PreRelease {
                 value: *value,
                 spec: *spec,
+                labels: labels.clone(),
+            },
+            VersionToken::Metadata { value, spec } => VersionToken::Metadata {
+                value: value.clone(),
+                spec: *spec,
+            },
+            VersionToken::OptionalStart { present } => {
+                VersionToken::OptionalStart { present: *present }
+            }
+            VersionToken::OptionalEnd => VersionToken::OptionalEnd,
+            VersionToken::Literal(text) => VersionToken::Literal(text.clone()),
+        }
+    }
+}
+
+impl<S: Scheme> VersionToken<'_, S> {
+    fn into_owned(self) -> VersionToken<'static, S> {
+        match self {
+            VersionToken::Value { value, spec, width } => {
+                VersionToken::Value { value, spec, width }
+            }
+            VersionToken::PreRelease {
+                value,
+                spec,
+                labels,
+            } => VersionToken::PreRelease {
+                value,
+                spec,
+                labels,
             },
-            VersionToken::Literal(text) => VersionToken::Literal(text),
+            VersionToken::Metadata { value, spec } => VersionToken::Metadata { value, spec },
+            VersionToken::OptionalStart { present } => VersionToken::OptionalStart { present },
+            VersionToken::OptionalEnd => VersionToken::OptionalEnd,
+            VersionToken::Literal(text) => VersionToken::Literal(Cow::Owned(text.into_owned())),
         }
     }
 }
 
-impl<'vs, S: Scheme> Display for VersionToken<'vs, S> {
+impl<S: Scheme> Display for VersionToken<'_, S> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            VersionToken::Value { value, spec } => {
-                let formatted = spec.format_value(*value);
+            VersionToken::Value { value, spec, width } => {
+                let formatted = match width {
+                    Some(width) => format!("{value:0width$}"),
+                    None => spec.format_value(*value),
+                };
                 f.write_str(&formatted)
             }
+            VersionToken::PreRelease { value, labels, .. } => {
+                f.write_str(&labels.format_value(*value))
+            }
+            VersionToken::Metadata { value, .. } => f.write_str(&format_metadata(value.as_deref())),
+            // whether an optional segment is rendered is up to the version
+            VersionToken::OptionalStart { .. } | VersionToken::OptionalEnd => Ok(()),
             VersionToken::Literal(text) => {
                 let text_str = unsafe { str::from_utf8_unchecked(text) };
                 f.write_str(text_str)
@@ -78,18 +313,30 @@ impl<'vs, S: Scheme> Display for VersionToken<'vs, S> {
     }
 }
 
-impl<'vs, S: Scheme> PartialOrd for VersionToken<'vs, S> {
+/// Returns true if `a` and `b` are the same specifier. Specifiers are compared and hashed by their
+/// patterns, which are unique in a scheme, rather than by address, since the same specifier may be
+/// in different `const` items, as in formats made by [`format_str!`](crate::format_str).
+fn same_spec<Sp: Specifier>(a: &Sp, b: &Sp) -> bool {
+    a.format_pattern() == b.format_pattern()
+}
+
+impl<S: Scheme> PartialOrd for VersionToken<'_, S> {
     /// Compares two version tokens. This is only a partial ordering it is only meaningful to
     /// compare two version tokens when they come from the equivalent formats.
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         // it only makes sense to compare values if they are the same type, thus, only a partial
         // ordering.
-        use VersionToken::{Literal, Value};
+        use VersionToken::{Literal, Metadata, OptionalEnd, OptionalStart, PreRelease, Value};
         match (self, other) {
+            // absent optional segments have zero values, so they compare as if they were present
+            (OptionalStart { .. }, OptionalStart { .. }) | (OptionalEnd, OptionalEnd) => {
+                Some(Ordering::Equal)
+            }
+
             (Literal(a), Literal(b)) => {
                 // there is no ordering for literals: they're either equal or not
                 if a.eq(b) {
-                    Some(std::cmp::Ordering::Equal)
+                    Some(core::cmp::Ordering::Equal)
                 } else {
                     None
                 }
@@ -99,46 +346,188 @@ impl<'vs, S: Scheme> PartialOrd for VersionToken<'vs, S> {
                 Value {
                     value: val_a,
                     spec: spec_a,
+                    ..
                 },
                 Value {
                     value: val_b,
                     spec: spec_b,
+                    ..
                 },
             ) => {
-                if ptr::eq(*spec_a, *spec_b) {
+                if same_spec(*spec_a, *spec_b) {
                     val_a.partial_cmp(val_b)
                 } else {
                     None
                 }
             }
 
+            (
+                PreRelease {
+                    value: val_a,
+                    spec: spec_a,
+                    labels: labels_a,
+                },
+                PreRelease {
+                    value: val_b,
+                    spec: spec_b,
+                    labels: labels_b,
+                },
+            ) => {
+                // label indices are only comparable when they index into the same labels
+                if same_spec(*spec_a, *spec_b) && labels_a == labels_b {
+                    Some(cmp_pre_release(*val_a, *val_b))
+                } else {
+                    None
+                }
+            }
+
+            (Metadata { spec: spec_a, .. }, Metadata { spec: spec_b, .. }) => {
+                // build metadata is not considered in ordering, as in SemVer
+                if same_spec(*spec_a, *spec_b) {
+                    Some(Ordering::Equal)
+                } else {
+                    None
+                }
+            }
+
             _ => None,
         }
     }
 }
 
+impl<S: Scheme> VersionToken<'_, S> {
+    /// The rank of this token's variant, which orders tokens of different variants.
+    fn rank(&self) -> u8 {
+        match self {
+            VersionToken::Value { .. } => 0,
+            VersionToken::PreRelease { .. } => 1,
+            VersionToken::Metadata { .. } => 2,
+            VersionToken::OptionalStart { .. } => 3,
+            VersionToken::OptionalEnd => 4,
+            VersionToken::Literal(_) => 5,
+        }
+    }
+
+    /// Compares the shapes of two tokens: their variants, specifiers, labels and literal text, but
+    /// not their values. Shapes are equal exactly when `partial_cmp` returns `Some`.
+    fn cmp_shape(&self, other: &Self) -> Ordering {
+        use VersionToken::{Literal, Metadata, PreRelease, Value};
+        match (self, other) {
+            (Value { spec: spec_a, .. }, Value { spec: spec_b, .. })
+            | (Metadata { spec: spec_a, .. }, Metadata { spec: spec_b, .. }) => {
+                spec_a.format_pattern().cmp(spec_b.format_pattern())
+            }
+            (
+                PreRelease {
+                    spec: spec_a,
+                    labels: labels_a,
+                    ..
+                },
+                PreRelease {
+                    spec: spec_b,
+                    labels: labels_b,
+                    ..
+                },
+            ) => spec_a
+                .format_pattern()
+                .cmp(spec_b.format_pattern())
+                .then_with(|| labels_a.cmp(labels_b)),
+            (Literal(a), Literal(b)) => a.cmp(b),
+            _ => self.rank().cmp(&other.rank()),
+        }
+    }
+
+    /// A total ordering of tokens: by value when they are comparable, otherwise by shape.
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        self.partial_cmp(other)
+            .unwrap_or_else(|| self.cmp_shape(other))
+    }
+
+    /// Hashes this token consistently with [`Self::total_cmp`], so widths, the presence of optional
+    /// segments and build metadata are not hashed.
+    fn hash_total<H: Hasher>(&self, state: &mut H) {
+        self.rank().hash(state);
+        match self {
+            VersionToken::Value { value, spec, .. } => {
+                spec.format_pattern().hash(state);
+                value.hash(state);
+            }
+            VersionToken::PreRelease {
+                value,
+                spec,
+                labels,
+            } => {
+                spec.format_pattern().hash(state);
+                labels.hash(state);
+                value.hash(state);
+            }
+            VersionToken::Metadata { spec, .. } => spec.format_pattern().hash(state),
+            VersionToken::Literal(text) => text.hash(state),
+            VersionToken::OptionalStart { .. } | VersionToken::OptionalEnd => {}
+        }
+    }
+}
+
 /// Just like a [`FormatToken`], but holds the literal text unescaped, so it can be quickly matched
 /// against a version string (instead of having to recompute the unescaped text each time).
 enum UnescapedFormatToken<S: Scheme> {
     Specifier(&'static S::Specifier),
+    FixedWidth {
+        spec: &'static S::Specifier,
+        width: usize,
+    },
+    PreRelease {
+        spec: &'static S::Specifier,
+        labels: PreReleaseLabels,
+    },
+    Metadata(&'static S::Specifier),
+    /// The start of an optional segment, followed by its `len` tokens and then an
+    /// [`Self::OptionalEnd`].
+    OptionalStart {
+        len: usize,
+    },
+    OptionalEnd,
     Literal(String),
 }
 
-impl<'fs, S: Scheme> From<&FormatToken<'fs, S>> for UnescapedFormatToken<S> {
-    fn from(token: &FormatToken<'fs, S>) -> Self {
-        match token {
-            FormatToken::Specifier(spec) => UnescapedFormatToken::Specifier(*spec),
-            FormatToken::Literal(literal) => {
-                let unescaped = unsafe { std::str::from_utf8_unchecked(literal) }
-                    .replace("<<", "<")
-                    .replace(">>", ">");
-                UnescapedFormatToken::Literal(unescaped)
-            }
+impl<S: Scheme> UnescapedFormatToken<S> {
+    /// Unescapes `tokens`, giving each [`Self::OptionalStart`] the length of its segment.
+    fn from_format_tokens(tokens: &[FormatToken<'_, S>]) -> Vec<Self> {
+        let mut unescaped_tokens = Vec::with_capacity(tokens.len());
+        for (index, token) in tokens.iter().enumerate() {
+            let unescaped_token = match token {
+                FormatToken::Specifier(spec) if spec.is_metadata() => {
+                    UnescapedFormatToken::Metadata(*spec)
+                }
+                FormatToken::Specifier(spec) => UnescapedFormatToken::Specifier(*spec),
+                FormatToken::FixedWidth { spec, width } => UnescapedFormatToken::FixedWidth {
+                    spec: *spec,
+                    width: *width,
+                },
+                FormatToken::PreRelease { spec, labels } => UnescapedFormatToken::PreRelease {
+                    spec: *spec,
+                    labels: labels.clone(),
+                },
+                FormatToken::OptionalStart => UnescapedFormatToken::OptionalStart {
+                    // segments are not nested, so this one ends at the next end
+                    len: tokens[index + 1..]
+                        .iter()
+                        .position(|token| matches!(token, FormatToken::OptionalEnd))
+                        .expect("optional segment should be terminated"),
+                },
+                FormatToken::OptionalEnd => UnescapedFormatToken::OptionalEnd,
+                FormatToken::Literal(literal) => {
+                    UnescapedFormatToken::Literal(unescape_literal(literal))
+                }
+            };
+            unescaped_tokens.push(unescaped_token);
         }
+        unescaped_tokens
     }
 }
 
-/// An error that occurred while parsing a version string.
+/// An error that occurred while parsing a version string, or building a version with a
+/// [`VersionBuilder`](crate::VersionBuilder).
 #[allow(clippy::module_name_repetitions)]
 #[non_exhaustive]
 #[derive(thiserror::Error, Debug, PartialEq)]
@@ -151,13 +540,101 @@ pub enum VersionError {
         /// The format string
         format_string: String,
     },
+
+    /// A value in the version string is greater than the greatest value its specifier can have,
+    /// which is [`SpecValue::MAX`](crate::SpecValue) for most, `4` for `<Q>`, and `366` for `<DOY>`
+    /// and `<0DOY>`.
+    #[error("values in version `{version_string}` should be at most the greatest values of their specifiers")]
+    ValueOutOfRange {
+        /// The version string
+        version_string: String,
+    },
+
+    /// When building a version, a value was given for a specifier that is not in the format.
+    #[error("`{spec}` was not found in format, use one that is")]
+    SpecifierNotInFormat {
+        /// The specifier
+        spec: String,
+    },
+
+    /// When building a version, the value of a specifier that cannot be zero was zero.
+    #[error("value of `{spec}` should not be zero")]
+    ZeroValue {
+        /// The specifier
+        spec: String,
+    },
+
+    /// When building a version, the value of a specifier given an explicit width (as in
+    /// `<PATCH:3>`) has more digits than that width.
+    #[error("value `{value}` of `{spec}` should fit in its width of {width} digit(s)")]
+    ValueOverflowsWidth {
+        /// The specifier
+        spec: String,
+        /// The value
+        value: SpecValue,
+        /// The width of the specifier
+        width: usize,
+    },
+
+    /// When building a version, the year of the date was negative.
+    #[error("year `{year}` should not be negative when formatted`")]
+    NegativeYearValue {
+        /// The year value
+        year: i32,
+    },
+
+    /// When building a version, the pre-release label is not one of those of the format.
+    #[error("pre-release label `{label}` should be one of the labels in format")]
+    UnknownPreReleaseLabel {
+        /// The label
+        label: String,
+    },
+
+    /// When building a version, the build metadata was not valid.
+    #[error(
+        "build metadata should be non-empty and contain only ASCII alphanumerics, hyphens, and dots, got `{metadata}`"
+    )]
+    InvalidMetadata {
+        /// The metadata text
+        metadata: String,
+    },
+
+    /// When building a version, a value was given for a level that has no value, like
+    /// [`SemLevel::FinalRelease`](crate::SemLevel::FinalRelease). Only the pre-release level sets
+    /// the pre-release number.
+    #[error(
+        "level should have a value to set it, use the pre-release level for the pre-release number"
+    )]
+    LevelHasNoValue,
+
+    /// When building a version, a value could not be computed, as when incrementing one.
+    #[error(transparent)]
+    Next(NextError),
+}
+
+impl From<NextError> for VersionError {
+    fn from(err: NextError) -> Self {
+        match err {
+            NextError::NegativeYearValue { year } => VersionError::NegativeYearValue { year },
+            err => VersionError::Next(err),
+        }
+    }
+}
+
+/// Returns `value` with the ASCII `digit` appended, or `None` if that is greater than
+/// [`SpecValue::MAX`].
+fn push_digit(value: SpecValue, digit: u8) -> Option<SpecValue> {
+    value
+        .checked_mul(10)?
+        .checked_add(SpecValue::from(digit - b'0'))
 }
 
 /// A Version object represents a specific point in a project's development, comprised of *values*
 /// and *literal text*. It's structure is defined by a [`Format`]. Versions can be displayed
 /// (`to_string()`), incremented (`next()`), and compared (`partial_cmp()`).
 ///
-/// Version objects are created with the [`Scheme::new_version`] or [`Format::new_version`] methods.
+/// Version objects are created with the [`Scheme::new_version`] or [`Format::new_version`] methods,
+/// or from values with a [`VersionBuilder`](crate::VersionBuilder).
 ///
 /// # (In)Equality
 ///
@@ -165,6 +642,11 @@ pub enum VersionError {
 /// sense when they have the same format. Therefore, comparisons between versions with different
 /// formats will always return `false`.
 ///
+/// As in [SemVer](https://semver.org/#spec-item-10), build metadata (`<META>`) is ignored when
+/// comparing versions, including for equality. Compare their strings to tell them apart.
+///
+/// Absent [optional segments](crate#optional-segments) compare as if their values were zero.
+///
 /// # Examples
 ///
 /// ```
@@ -193,20 +675,35 @@ impl<'vs, S: Scheme> Version<'vs, S> {
         Self { tokens }
     }
     pub(crate) fn parse(version_str: &'vs str, format: &Format<S>) -> Result<Self, VersionError> {
-        let unescaped_format_tokens: Vec<UnescapedFormatToken<S>> =
-            format.tokens.iter().map(Into::into).collect();
-        Self::parse_rec(version_str.as_bytes(), &unescaped_format_tokens, &[])
-            .map(|tokens| Version::new(tokens))
-            .ok_or(VersionError::VersionFormatMismatch {
-                version_string: version_str.to_owned(),
-                format_string: format.to_string(),
-            })
+        let unescaped_format_tokens = UnescapedFormatToken::from_format_tokens(&format.tokens);
+        // track if a value was too great to parse, so that, if nothing else matches, we can say so
+        let mut overflowed = false;
+        Self::parse_rec(
+            version_str.as_bytes(),
+            &unescaped_format_tokens,
+            &[],
+            &mut overflowed,
+        )
+        .map(|tokens| Version::new(tokens))
+        .ok_or_else(|| {
+            if overflowed {
+                VersionError::ValueOutOfRange {
+                    version_string: version_str.to_owned(),
+                }
+            } else {
+                VersionError::VersionFormatMismatch {
+                    version_string: version_str.to_owned(),
+                    format_string: format.to_string(),
+                }
+            }
+        })
     }
 
     fn parse_rec(
         version_str: &'vs [u8],
         fmt_tokens: &[UnescapedFormatToken<S>],
         ver_tokens: &[VersionToken<'vs, S>],
+        overflowed: &mut bool,
     ) -> Option<Vec<VersionToken<'vs, S>>> {
         if version_str.is_empty() && fmt_tokens.is_empty() {
             return Some(ver_tokens.to_vec());
@@ -219,126 +716,381 @@ impl<'vs, S: Scheme> Version<'vs, S> {
                 if version_str.starts_with(literal.as_bytes()) {
                     let mut new_ver_tokens = ver_tokens.to_vec();
                     let (literal, version_str) = version_str.split_at(literal.len());
-                    new_ver_tokens.push(VersionToken::Literal(literal));
-                    Self::parse_rec(version_str, &fmt_tokens[1..], &new_ver_tokens)
+                    new_ver_tokens.push(VersionToken::Literal(Cow::Borrowed(literal)));
+                    Self::parse_rec(version_str, &fmt_tokens[1..], &new_ver_tokens, overflowed)
                 } else {
                     None
                 }
             }
-            UnescapedFormatToken::Specifier(specifier) => {
-                let min_parse_width = specifier.parse_width().min_width();
-                let max_parse_width = specifier.parse_width().max_width().min(version_str.len());
-                let mut value: SpecValue = 0;
-                let mut continue_iterating = true;
-
-                for idx in 0..max_parse_width {
-                    let next = version_str[idx];
-                    if !next.is_ascii_digit() {
-                        return None; // all specs only match digits, so this idx is unparseable
-                    }
-                    value = value * 10 + u32::from(next - b'0');
+            UnescapedFormatToken::Specifier(specifier) if specifier.value_names().is_some() => {
+                Self::parse_name_rec(
+                    version_str,
+                    specifier,
+                    &fmt_tokens[1..],
+                    ver_tokens,
+                    overflowed,
+                )
+            }
+            UnescapedFormatToken::Specifier(specifier) => Self::parse_value_rec(
+                version_str,
+                specifier,
+                &fmt_tokens[1..],
+                ver_tokens,
+                overflowed,
+            ),
+            UnescapedFormatToken::FixedWidth { spec, width } => Self::parse_fixed_width_rec(
+                version_str,
+                spec,
+                *width,
+                &fmt_tokens[1..],
+                ver_tokens,
+                overflowed,
+            ),
+            UnescapedFormatToken::OptionalStart { len } => Self::parse_optional_rec(
+                version_str,
+                *len,
+                &fmt_tokens[1..],
+                ver_tokens,
+                overflowed,
+            ),
+            UnescapedFormatToken::OptionalEnd => {
+                let mut new_ver_tokens = ver_tokens.to_vec();
+                new_ver_tokens.push(VersionToken::OptionalEnd);
+                Self::parse_rec(version_str, &fmt_tokens[1..], &new_ver_tokens, overflowed)
+            }
+            UnescapedFormatToken::PreRelease { spec, labels } => Self::parse_pre_release_rec(
+                version_str,
+                spec,
+                labels,
+                &fmt_tokens[1..],
+                ver_tokens,
+                overflowed,
+            ),
+            UnescapedFormatToken::Metadata(spec) => Self::parse_metadata_rec(
+                version_str,
+                spec,
+                &fmt_tokens[1..],
+                ver_tokens,
+                overflowed,
+            ),
+        }
+    }
 
-                    let cur_width = idx + 1;
-                    if cur_width < min_parse_width {
-                        // keep going until we have enough characters
-                        continue;
-                    }
+    /// Like [`Self::parse_rec`], but for when the next format token is a specifier whose values
+    /// are digits.
+    fn parse_value_rec(
+        version_str: &'vs [u8],
+        spec: &'static S::Specifier,
+        rest_fmt_tokens: &[UnescapedFormatToken<S>],
+        ver_tokens: &[VersionToken<'vs, S>],
+        overflowed: &mut bool,
+    ) -> Option<Vec<VersionToken<'vs, S>>> {
+        let min_parse_width = spec.parse_width().min_width();
+        let max_parse_width = spec.parse_width().max_width().min(version_str.len());
+        let mut value: SpecValue = 0;
+        let mut continue_iterating = true;
 
-                    if value == 0 {
-                        if !specifier.can_be_zero() {
-                            return None;
-                        }
-                        if !specifier.has_zero_padding() {
-                            // if the value is zero, and this spec has no zero-padding, then the
-                            // only way we could parse a leading zero is if the value is just that
-                            // single '0'. so, do this iteration, but don't continue.
-                            continue_iterating = false;
-                        }
-                    }
+        for idx in 0..max_parse_width {
+            let next = version_str[idx];
+            if !next.is_ascii_digit() {
+                return None; // all specs only match digits, so this idx is unparseable
+            }
+            let Some(next_value) = push_digit(value, next) else {
+                // more digits would only be greater
+                *overflowed = true;
+                return None;
+            };
+            value = next_value;
+            if value > spec.max_value() {
+                // more digits would only be greater
+                *overflowed = true;
+                return None;
+            }
 
-                    let mut new_ver_tokens = ver_tokens.to_vec();
-                    new_ver_tokens.push(VersionToken::Value {
-                        value,
-                        spec: specifier,
-                    });
-                    if let Some(new_ver_tokens) =
-                        Self::parse_rec(&version_str[idx + 1..], &fmt_tokens[1..], &new_ver_tokens)
-                    {
-                        return Some(new_ver_tokens);
-                    }
-                    if !continue_iterating {
-                        break;
-                    }
+            let cur_width = idx + 1;
+            if cur_width < min_parse_width {
+                // keep going until we have enough characters
+                continue;
+            }
+
+            if value == 0 {
+                if !spec.can_be_zero() {
+                    return None;
                 }
-                None
+                if !spec.has_zero_padding() {
+                    // if the value is zero, and this spec has no zero-padding, then the only way we
+                    // could parse a leading zero is if the value is just that single '0'. so, do
+                    // this iteration, but don't continue.
+                    continue_iterating = false;
+                }
+            }
+
+            let mut new_ver_tokens = ver_tokens.to_vec();
+            new_ver_tokens.push(VersionToken::Value {
+                value,
+                spec,
+                width: None,
+            });
+            if let Some(new_ver_tokens) = Self::parse_rec(
+                &version_str[idx + 1..],
+                rest_fmt_tokens,
+                &new_ver_tokens,
+                overflowed,
+            ) {
+                return Some(new_ver_tokens);
+            }
+            if !continue_iterating {
+                break;
             }
         }
+        None
     }
 
-    fn new_map_value_tokens<F>(&self, mut f: F) -> Result<Self, NextError>
-    where
-        F: FnMut((SpecValue, &S::Specifier)) -> SpecValueResult,
-    {
-        let mut new_tokens = Vec::with_capacity(self.tokens.len());
+    /// Like [`Self::parse_rec`], but for when the next format token is a specifier whose values
+    /// are names instead of digits.
+    fn parse_name_rec(
+        version_str: &'vs [u8],
+        spec: &'static S::Specifier,
+        rest_fmt_tokens: &[UnescapedFormatToken<S>],
+        ver_tokens: &[VersionToken<'vs, S>],
+        overflowed: &mut bool,
+    ) -> Option<Vec<VersionToken<'vs, S>>> {
+        let names = spec.value_names()?;
 
-        for token in &self.tokens {
-            let new_token = match token {
-                VersionToken::Value { value, spec } => {
-                    let new_value = f((*value, spec))?;
-                    VersionToken::Value {
-                        value: new_value,
-                        spec: *spec,
-                    }
-                }
-                VersionToken::Literal(_) => token.clone(),
+        for (value, name) in (1..).zip(names) {
+            let Some(rest) = version_str.strip_prefix(name.as_bytes()) else {
+                continue;
             };
-            new_tokens.push(new_token);
+            let mut new_ver_tokens = ver_tokens.to_vec();
+            new_ver_tokens.push(VersionToken::Value {
+                value,
+                spec,
+                width: None,
+            });
+            if let Some(new_ver_tokens) =
+                Self::parse_rec(rest, rest_fmt_tokens, &new_ver_tokens, overflowed)
+            {
+                return Some(new_ver_tokens);
+            }
         }
-
-        Ok(Version::new(new_tokens))
+        None
     }
-}
 
-impl<'vs, S: Scheme> PartialOrd for Version<'vs, S> {
-    /// Compares two versions. This is only a partial ordering it is only meaningful to compare two
-    /// versions when they come from the same format.
-    ///
-    /// Returns `None` when either of the following are true:
-    ///
-    /// - The number of *tokens* in the versions are different. Tokens are either literal text or
-    ///   specifier values.
-    /// - For two given tokens, they are not of the same type. E.g., one is a literal, one is a
-    ///   value.
-    /// - For two given literal tokens, the text is not the same.
-    /// - For two given value tokens, they are not of the same specifier type. E.g., one is a
-    ///  `<YYYY>` value, one is a `<YY>` value.
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        if self.tokens.len() == other.tokens.len() {
-            self.tokens.partial_cmp(&other.tokens)
-        } else {
-            None
+    /// Like [`Self::parse_rec`], but for when the next format token is the start of an optional
+    /// segment, whose `len` tokens are at the start of `rest_fmt_tokens`.
+    fn parse_optional_rec(
+        version_str: &'vs [u8],
+        len: usize,
+        rest_fmt_tokens: &[UnescapedFormatToken<S>],
+        ver_tokens: &[VersionToken<'vs, S>],
+        overflowed: &mut bool,
+    ) -> Option<Vec<VersionToken<'vs, S>>> {
+        // first, try it as present, parsing its tokens like any others.
+        let mut new_ver_tokens = ver_tokens.to_vec();
+        new_ver_tokens.push(VersionToken::OptionalStart { present: true });
+        if let Some(new_ver_tokens) =
+            Self::parse_rec(version_str, rest_fmt_tokens, &new_ver_tokens, overflowed)
+        {
+            return Some(new_ver_tokens);
+        }
+
+        // then, as absent, which takes up no characters. its values are zero, and its literals
+        // come from the format, in case it's rendered later.
+        let mut new_ver_tokens = ver_tokens.to_vec();
+        new_ver_tokens.push(VersionToken::OptionalStart { present: false });
+        for fmt_token in &rest_fmt_tokens[..len] {
+            let ver_token = match fmt_token {
+                UnescapedFormatToken::Specifier(spec) => VersionToken::Value {
+                    value: 0,
+                    spec: *spec,
+                    width: None,
+                },
+                UnescapedFormatToken::FixedWidth { spec, width } => VersionToken::Value {
+                    value: 0,
+                    spec: *spec,
+                    width: Some(*width),
+                },
+                UnescapedFormatToken::Literal(literal) => {
+                    VersionToken::Literal(Cow::Owned(literal.as_bytes().to_vec()))
+                }
+                // formats only allow specifiers that can be zero in optional segments
+                _ => return None,
+            };
+            new_ver_tokens.push(ver_token);
         }
+        // the end marker is pushed by the rest of the parse
+        Self::parse_rec(
+            version_str,
+            &rest_fmt_tokens[len..],
+            &new_ver_tokens,
+            overflowed,
+        )
     }
-}
 
-impl<'vs> Version<'vs, Sem> {
-    /// Returns a new version where the value of specifier given by `level` is incremented, and all
-    /// lesser semantic values are reset to zero. This is similar to how an
-    /// [odometer](https://en.wikipedia.org/wiki/Odometer) works.
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// use nextver::prelude::*;;
+    /// Like [`Self::parse_rec`], but for when the next format token is a specifier with an explicit
+    /// width. Exactly `width` digits are consumed, leading zeros included.
+    fn parse_fixed_width_rec(
+        version_str: &'vs [u8],
+        spec: &'static S::Specifier,
+        width: usize,
+        rest_fmt_tokens: &[UnescapedFormatToken<S>],
+        ver_tokens: &[VersionToken<'vs, S>],
+        overflowed: &mut bool,
+    ) -> Option<Vec<VersionToken<'vs, S>>> {
+        let digits = version_str.get(..width)?;
+        if !digits.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+        let Some(value) = digits
+            .iter()
+            .try_fold(0, |value: SpecValue, &digit| push_digit(value, digit))
+            .filter(|value| *value <= spec.max_value())
+        else {
+            *overflowed = true;
+            return None;
+        };
+        if value == 0 && !spec.can_be_zero() {
+            return None;
+        }
+
+        let mut new_ver_tokens = ver_tokens.to_vec();
+        new_ver_tokens.push(VersionToken::Value {
+            value,
+            spec,
+            width: Some(width),
+        });
+        Self::parse_rec(
+            &version_str[width..],
+            rest_fmt_tokens,
+            &new_ver_tokens,
+            overflowed,
+        )
+    }
+
+    /// Like [`Self::parse_rec`], but for when the next format token is a pre-release specifier.
+    fn parse_pre_release_rec(
+        version_str: &'vs [u8],
+        spec: &'static S::Specifier,
+        labels: &PreReleaseLabels,
+        rest_fmt_tokens: &[UnescapedFormatToken<S>],
+        ver_tokens: &[VersionToken<'vs, S>],
+        overflowed: &mut bool,
+    ) -> Option<Vec<VersionToken<'vs, S>>> {
+        let mut new_ver_tokens = ver_tokens.to_vec();
+
+        // first, try it as a final release, which takes up no characters.
+        new_ver_tokens.push(VersionToken::PreRelease {
+            value: None,
+            spec,
+            labels: labels.clone(),
+        });
+        if let Some(new_ver_tokens) =
+            Self::parse_rec(version_str, rest_fmt_tokens, &new_ver_tokens, overflowed)
+        {
+            return Some(new_ver_tokens);
+        }
+        new_ver_tokens.pop();
+
+        // otherwise, it must be `-<label>.<number>`
+        let version_str = version_str.strip_prefix(b"-")?;
+        for (label_idx, label) in labels.iter().enumerate() {
+            let Some(number_str) = version_str
+                .strip_prefix(label.as_bytes())
+                .and_then(|rest| rest.strip_prefix(b"."))
+            else {
+                continue;
+            };
+
+            let mut number: SpecValue = 0;
+            for (idx, &next) in number_str.iter().enumerate() {
+                if !next.is_ascii_digit() || (idx > 0 && number == 0) {
+                    // not a digit, or a leading zero
+                    break;
+                }
+                let Some(next_number) = push_digit(number, next) else {
+                    // more digits would only be greater
+                    *overflowed = true;
+                    break;
+                };
+                number = next_number;
+
+                new_ver_tokens.push(VersionToken::PreRelease {
+                    value: Some(PreReleaseValue {
+                        label: label_idx,
+                        number,
+                    }),
+                    spec,
+                    labels: labels.clone(),
+                });
+                if let Some(new_ver_tokens) = Self::parse_rec(
+                    &number_str[idx + 1..],
+                    rest_fmt_tokens,
+                    &new_ver_tokens,
+                    overflowed,
+                ) {
+                    return Some(new_ver_tokens);
+                }
+                new_ver_tokens.pop();
+            }
+        }
+        None
+    }
+
+    /// Like [`Self::parse_rec`], but for when the next format token is a metadata specifier.
+    fn parse_metadata_rec(
+        version_str: &'vs [u8],
+        spec: &'static S::Specifier,
+        rest_fmt_tokens: &[UnescapedFormatToken<S>],
+        ver_tokens: &[VersionToken<'vs, S>],
+        overflowed: &mut bool,
+    ) -> Option<Vec<VersionToken<'vs, S>>> {
+        let mut new_ver_tokens = ver_tokens.to_vec();
+
+        // first, try it without metadata, which takes up no characters.
+        new_ver_tokens.push(VersionToken::Metadata { value: None, spec });
+        if let Some(new_ver_tokens) =
+            Self::parse_rec(version_str, rest_fmt_tokens, &new_ver_tokens, overflowed)
+        {
+            return Some(new_ver_tokens);
+        }
+        new_ver_tokens.pop();
+
+        // otherwise, it must be `+<metadata>`
+        let version_str = version_str.strip_prefix(b"+")?;
+        for width in 1..=version_str.len() {
+            let (metadata, rest) = version_str.split_at(width);
+            if !is_valid_metadata(metadata) {
+                break;
+            }
+            new_ver_tokens.push(VersionToken::Metadata {
+                // metadata is only ascii, so this is valid utf-8
+                value: Some(unsafe { str::from_utf8_unchecked(metadata) }.to_owned()),
+                spec,
+            });
+            if let Some(new_ver_tokens) =
+                Self::parse_rec(rest, rest_fmt_tokens, &new_ver_tokens, overflowed)
+            {
+                return Some(new_ver_tokens);
+            }
+            new_ver_tokens.pop();
+        }
+        None
+    }
+
+    /// Returns a new version with its build metadata set to `metadata`, or removed if `None`.
     ///
-    /// let cur = Sem::new_version("<MAJOR>.<MINOR>.<PATCH>", "1.2.3")?;
-    /// let next = cur.next(SemLevel::Major)?;
-    /// assert_eq!("2.0.0", &next.to_string());
-    /// assert!(cur < next);
+    /// Build metadata is not carried over by `next` methods, because it describes a specific
+    /// build. Give it to the next version with `next_with_metadata`, or call this on the next
+    /// version.
     ///
-    /// let next_next = next.next(SemLevel::Patch)?;
-    /// assert_eq!("2.0.1", &next_next.to_string());
-    /// assert!(next < next_next);
+    /// # Example
+    ///
+    /// ```
+    /// use nextver::prelude::*;
+    ///
+    /// let cur = Sem::new_version("<MAJOR>.<MINOR>.<PATCH><META>", "1.2.3+ci.41")?;
+    /// let next = cur.next(SemLevel::Patch)?.with_metadata(Some("ci.42"))?;
+    /// assert_eq!("1.2.4+ci.42", &next.to_string());
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     ///
@@ -346,283 +1098,601 @@ impl<'vs> Version<'vs, Sem> {
     ///
     /// Returns a [`Result::Err`] of...
     ///
-    /// - [`NextError::SemLevelNotInFormat`] if the specifier of `level` is not in format.
-    pub fn next(&self, level: SemLevel) -> Result<Self, NextError> {
-        let mut spec_found = false;
-        let level_spec = level.as_ref().spec();
+    /// - [`NextError::MetadataNotInFormat`] if the format has no `<META>` specifier.
+    /// - [`NextError::InvalidMetadata`] if `metadata` is empty or contains characters other than
+    ///   ASCII alphanumerics, hyphens, and dots.
+    pub fn with_metadata(&self, metadata: Option<&str>) -> Result<Self, NextError> {
+        if let Some(metadata) = metadata {
+            if !is_valid_metadata(metadata.as_bytes()) {
+                return Err(NextError::InvalidMetadata {
+                    metadata: metadata.to_owned(),
+                });
+            }
+        }
 
-        let next_version = self.new_map_value_tokens(|(cur_value, this_spec)| {
-            if level_spec == this_spec {
+        let mut new_version = Version::new(self.tokens.clone());
+        let mut spec_found = false;
+        for token in &mut new_version.tokens {
+            if let VersionToken::Metadata { value, .. } = token {
                 spec_found = true;
-            };
-            let next_value = this_spec.next_value(cur_value, level);
-            Ok(next_value)
-        })?;
+                *value = metadata.map(str::to_owned);
+            }
+        }
 
-        if !spec_found {
+        if spec_found {
+            Ok(new_version)
+        } else {
+            Err(NextError::MetadataNotInFormat)
+        }
+    }
+
+    /// Returns a new version where each value is replaced with the result of `f`, which is given
+    /// the current value and its specifier. This is how to increment versions of
+    /// [custom schemes](crate#custom-schemes). (The `next` methods of the built-in schemes work
+    /// this way too.)
+    ///
+    /// Like those methods, build metadata is cleared, and [optional
+    /// segments](crate#optional-segments) are only rendered if they have a non-zero value. Pre-
+    /// release values are left as-is.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nextver::prelude::*;
+    ///
+    /// let cur = Sem::new_version("<MAJOR>.<MINOR>.<PATCH>", "1.2.3")?;
+    /// let next = cur.next_with(|value, _| Ok(value * 2))?;
+    /// assert_eq!("2.4.6", &next.to_string());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result::Err`] of...
+    ///
+    /// - any error returned by `f`. For custom errors, see [`NextError::Custom`].
+    /// - [`NextError::ValueOverflowsWidth`] if a new value has more digits than its specifier's
+    ///   [fixed width](crate#fixed-widths).
+    pub fn next_with<F>(&self, mut f: F) -> Result<Self, NextError>
+    where
+        F: FnMut(SpecValue, &S::Specifier) -> Result<SpecValue, NextError>,
+    {
+        self.new_map_value_tokens(|(value, spec)| f(value, spec))
+    }
+
+    /// Returns a new version where the value of the specifier `level` is incremented, and every
+    /// other value changes as its specifier's [`Specifier::bump_value`] says. This is the `next`
+    /// method of [custom schemes](crate#custom-schemes). For the built-in schemes, it changes
+    /// values like their `next` methods do.
+    ///
+    /// Like those methods, build metadata is cleared, [optional
+    /// segments](crate#optional-segments) are only rendered if they have a non-zero value, and a
+    /// pre-release becomes a final release.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nextver::{prelude::*, VersionPart};
+    ///
+    /// let cur = Sem::new_version("<MAJOR>.<MINOR>.<PATCH>", "1.2.3")?;
+    /// let Some(VersionPart::Value { spec: minor, .. }) = cur.parts().nth(2) else {
+    ///     unreachable!()
+    /// };
+    /// assert_eq!("1.3.0", &cur.bump(minor)?.to_string());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result::Err`] of...
+    ///
+    /// - [`NextError::SpecifierHasNoValue`] if `level` is a pre-release or build metadata
+    ///   specifier.
+    /// - [`NextError::SemLevelNotInFormat`] if `level` is not in the version's format.
+    /// - any error returned by [`Specifier::bump_value`].
+    /// - [`NextError::ValueOverflowsWidth`] if a new value has more digits than its specifier's
+    ///   [fixed width](crate#fixed-widths).
+    pub fn bump(&self, level: &S::Specifier) -> Result<Self, NextError> {
+        if level.is_pre_release() || level.is_metadata() {
+            return Err(NextError::SpecifierHasNoValue {
+                spec: level.to_string(),
+            });
+        }
+        if !self.has_spec(level) {
             return Err(NextError::SemLevelNotInFormat {
-                spec: level_spec.to_string(),
+                spec: level.to_string(),
             });
         }
 
+        let mut next_version =
+            self.new_map_value_tokens(|(value, spec)| spec.bump_value(value, level))?;
+        next_version.map_pre_release_tokens(|value, spec, labels| {
+            labels.next_value(value, PreReleaseBump::Clear, spec)
+        })?;
         Ok(next_version)
     }
-}
-
-/// Errors around dates, as used to update [`Cal`](crate::Cal) and [`CalSem`](crate::CalSem)
-/// versions.
-#[non_exhaustive]
-#[derive(thiserror::Error, Debug, PartialEq)]
-pub enum DateError {
-    /// Arguments to [`Date::explicit`](crate::Date::explicit) do not represent a valid date.
-    #[error("year ({year}), month ({month}), and day ({day}) should represent a valid date")]
-    InvalidDateArguments {
-        /// The year value
-        year: i32,
-        /// The month value
-        month: u32,
-        /// The day value
-        day: u32,
-    },
 
-    /// The date string could not be parsed.
+    /// Returns an iterator over the parts of this version, in the order they are rendered. Parts
+    /// in an absent [optional segment](crate#optional-segments) are skipped.
     ///
-    /// See [`chrono::NaiveDate::from_str`] and [`chrono::ParseError`].
-    #[error(transparent)]
-    UnparseableDate(#[from] chrono::ParseError),
-}
-
-/// Ways to specify a date.
-///
-/// ```
-/// use nextver::Date;
-///
-/// let utc_now = Date::utc_now();
-/// let local_now = Date::local_now();
-/// let explicit = Date::explicit(2021, 2, 3).unwrap();
-/// ```
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct Date(NaiveDate);
+    /// # Example
+    ///
+    /// ```
+    /// use nextver::{prelude::*, VersionPart};
+    ///
+    /// let version = Sem::new_version("<MAJOR>.<MINOR><PRE>", "1.2-rc.3")?;
+    /// let values = version
+    ///     .parts()
+    ///     .filter_map(|part| match part {
+    ///         VersionPart::Value { spec, value } => Some(format!("{spec}={value}")),
+    ///         _ => None,
+    ///     })
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(vec!["<MAJOR>=1", "<MINOR>=2"], values);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parts(&self) -> impl Iterator<Item = VersionPart<'_, S::Specifier>> {
+        // as when rendering, track if we're in an absent optional segment
+        let mut absent = false;
+        self.tokens.iter().filter_map(move |token| match token {
+            VersionToken::OptionalStart { present } => {
+                absent = !present;
+                None
+            }
+            VersionToken::OptionalEnd => {
+                absent = false;
+                None
+            }
+            _ if absent => None,
+            VersionToken::Value { value, spec, .. } => Some(VersionPart::Value {
+                spec: *spec,
+                value: *value,
+            }),
+            VersionToken::PreRelease {
+                value,
+                spec,
+                labels,
+            } => Some(VersionPart::PreRelease {
+                spec: *spec,
+                value: value.map(|value| (labels.get(value.label), value.number)),
+            }),
+            VersionToken::Metadata { value, spec } => Some(VersionPart::Metadata {
+                spec: *spec,
+                value: value.as_deref(),
+            }),
+            VersionToken::Literal(text) => Some(VersionPart::Literal(unsafe {
+                str::from_utf8_unchecked(text)
+            })),
+        })
+    }
 
-impl Date {
-    /// Returns a new [`Date`] representing the current date in UTC at the time of this call.
+    /// Returns the label and number of the pre-release, or `None` if this is a final release or
+    /// the format has no pre-release specifier.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nextver::prelude::*;
+    ///
+    /// let version = Sem::new_version("<MAJOR>.<MINOR><PRE>", "1.2-rc.3")?;
+    /// assert_eq!(Some(("rc", 3)), version.pre_release());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
     #[must_use]
-    pub fn utc_now() -> Self {
-        Self(Utc::now().date_naive())
+    pub fn pre_release(&self) -> Option<(&str, SpecValue)> {
+        self.tokens.iter().find_map(|token| match token {
+            VersionToken::PreRelease {
+                value: Some(value),
+                labels,
+                ..
+            } => Some((labels.get(value.label), value.number)),
+            _ => None,
+        })
     }
 
-    /// Returns a new [`Date`] representing the current date in the system's local timezone at the
-    /// time of this call.
+    /// Returns the build metadata, or `None` if this version has none or the format has no
+    /// metadata specifier.
     #[must_use]
-    pub fn local_now() -> Self {
-        Self(Local::now().date_naive())
+    pub fn metadata(&self) -> Option<&str> {
+        self.tokens.iter().find_map(|token| match token {
+            VersionToken::Metadata { value, .. } => value.as_deref(),
+            _ => None,
+        })
     }
 
-    /// Returns result of a new [`Date`] representing the given Date.
+    /// Compares two versions with a total ordering. Versions of the same format are ordered as they
+    /// are by [`PartialOrd`], which never returns `None` for them. Versions of different formats
+    /// are ordered arbitrarily, but consistently.
     ///
-    /// # Errors
+    /// # Example
     ///
-    /// Returns [`DateError::InvalidDateArguments`] if the date values do not represent a valid
-    /// date.
-    pub fn explicit(year: i32, month: u32, day: u32) -> Result<Self, DateError> {
-        NaiveDate::from_ymd_opt(year, month, day)
-            .map(Self)
-            .ok_or(DateError::InvalidDateArguments { year, month, day })
-    }
-
-    pub(crate) fn as_naive_date(self) -> NaiveDate {
-        self.0
+    /// ```
+    /// use nextver::prelude::*;
+    ///
+    /// let format = Sem::new_format("<MAJOR>.<MINOR>.<PATCH>")?;
+    /// let mut versions = vec![format.new_version("1.10.0")?, format.new_version("1.2.0")?];
+    /// versions.sort_by(Version::total_cmp);
+    /// assert_eq!("1.2.0", versions[0].to_string());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn total_cmp(&self, other: &Self) -> Ordering {
+        self.tokens
+            .iter()
+            .zip(&other.tokens)
+            .map(|(a, b)| a.total_cmp(b))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or_else(|| self.tokens.len().cmp(&other.tokens.len()))
     }
-}
 
-impl FromStr for Date {
-    type Err = DateError;
-
-    /// Parses a date string into a [`Date`]. The string must be in the format `YYYY-MM-DD`, where
-    /// `YYYY` is the year zero-padded to 4 digits, `MM` is the month zero-padded to 2 digits, and
-    /// `DD` is the day zero-padded to 2 digits.
+    /// Returns the greatest of `versions` by [`Version::total_cmp`], e.g., the latest release, or
+    /// `None` if there are no versions. If several are greatest, the last is returned.
     ///
-    /// See [`NaiveDate::from_str`].
+    /// # Example
     ///
-    /// # Errors
+    /// ```
+    /// use nextver::prelude::*;
     ///
-    /// Returns a [`DateError::UnparseableDate`] if the date string is not parseable.
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self(NaiveDate::from_str(s)?))
-    }
-}
-
-impl Display for Date {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+    /// let format = Sem::new_format("<MAJOR>.<MINOR>.<PATCH>")?;
+    /// let versions = vec![format.new_version("1.10.0")?, format.new_version("1.2.0")?];
+    /// assert_eq!(Some(&versions[0]), Version::max(&versions));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn max<I>(versions: I) -> Option<I::Item>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Self>,
+    {
+        versions
+            .into_iter()
+            .max_by(|a, b| a.borrow().total_cmp(b.borrow()))
     }
-}
 
-impl<'vs> Version<'vs, Cal> {
-    /// Returns a new version where the values of all date specifiers is advanced to those in
-    /// `date`.
-    ///
-    /// If `date` is before the date in this version, an error is returned. (See
-    /// [`Self::next_unchecked`] to skip this check.)
+    /// Returns the least of `versions` by [`Version::total_cmp`], or `None` if there are no
+    /// versions. If several are least, the first is returned.
     ///
     /// # Example
     ///
     /// ```
-    /// use nextver::prelude::*;;
+    /// use nextver::prelude::*;
     ///
-    /// let date = Date::utc_now();  // assume today is 2024-02-23
-    /// # let date = Date::explicit(2024, 2, 23)?;
-    ///
-    /// let cur = Cal::new_version("<YYYY>.<MM>", "2024.1")?;
-    /// let next = cur.next(date)?;
-    /// assert_eq!("2024.2", &next.to_string());
-    /// assert!(cur < next);
+    /// let format = Sem::new_format("<MAJOR>.<MINOR>.<PATCH>")?;
+    /// let versions = vec![format.new_version("1.10.0")?, format.new_version("1.2.0")?];
+    /// assert_eq!(Some(&versions[1]), Version::min(&versions));
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
+    pub fn min<I>(versions: I) -> Option<I::Item>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Self>,
+    {
+        versions
+            .into_iter()
+            .min_by(|a, b| a.borrow().total_cmp(b.borrow()))
+    }
+
+    /// Returns this version with its literal text owned instead of borrowed from the version
+    /// string, so that it can outlive that string.
     ///
-    /// # Errors
+    /// # Example
     ///
-    /// Returns a [`Result::Err`] of...
+    /// ```
+    /// use nextver::prelude::*;
     ///
-    /// - [`NextError::NoCalendarChange`] if the date does not change the calendar values.
-    /// - [`NextError::NewDateIsBefore`] if `date` is before the date in this version.
-    /// - [`NextError::NegativeYearValue`] if the year value would be negative. (Year specifiers
-    ///   have lower bounds. See the [table](crate#table) for more information.)
-    pub fn next(&self, date: Date) -> Result<Self, NextError> {
-        // track if the calendar was moved forward in time, so we can error if not
-        let mut cal_moved_fwd = false;
+    /// fn read_version() -> Version<'static, Sem> {
+    ///     let version_str = String::from("v1.2.3");
+    ///     Sem::new_version("v<MAJOR>.<MINOR>.<PATCH>", &version_str)
+    ///         .unwrap()
+    ///         .into_owned()
+    /// }
+    ///
+    /// assert_eq!("v1.2.3", read_version().to_string());
+    /// ```
+    #[must_use]
+    pub fn into_owned(self) -> Version<'static, S> {
+        Version::new(
+            self.tokens
+                .into_iter()
+                .map(VersionToken::into_owned)
+                .collect(),
+        )
+    }
 
-        let next_version = self.new_map_value_tokens(|(cur_value, this_spec)| {
-            let next_value = this_spec.next_value(date.as_naive_date())?;
+    /// Returns the first `Some` result of `f` for the values of this version, which is given the
+    /// specifier and value.
+    pub(crate) fn find_value<F>(&self, f: F) -> Option<SpecValue>
+    where
+        F: Fn(&S::Specifier, SpecValue) -> Option<SpecValue>,
+    {
+        self.tokens.iter().find_map(|token| match token {
+            VersionToken::Value { value, spec, .. } => f(spec, *value),
+            _ => None,
+        })
+    }
 
-            if !cal_moved_fwd {
-                match next_value.cmp(&cur_value) {
-                    Ordering::Greater => cal_moved_fwd = true,
-                    Ordering::Less => {
-                        return Err(NextError::NewDateIsBefore);
-                    }
-                    Ordering::Equal => {}
-                }
+    /// Returns whether `spec` has a value or pre-release token in this version.
+    fn has_spec(&self, spec: &S::Specifier) -> bool {
+        self.tokens.iter().any(|token| match token {
+            VersionToken::Value {
+                spec: this_spec, ..
             }
+            | VersionToken::PreRelease {
+                spec: this_spec, ..
+            } => *this_spec == spec,
+            _ => false,
+        })
+    }
 
-            Ok(next_value)
-        })?;
+    fn new_map_value_tokens<F>(&self, mut f: F) -> Result<Self, NextError>
+    where
+        F: FnMut((SpecValue, &S::Specifier)) -> SpecValueResult,
+    {
+        let mut new_tokens = Vec::with_capacity(self.tokens.len());
 
-        if !cal_moved_fwd {
-            return Err(NextError::NoCalendarChange);
+        for token in &self.tokens {
+            let new_token = match token {
+                VersionToken::Value { value, spec, width } => {
+                    let new_value = f((*value, spec))?;
+                    if let Some(width) = width {
+                        if new_value.to_string().len() > *width {
+                            return Err(NextError::ValueOverflowsWidth {
+                                spec: spec.to_string(),
+                                value: new_value,
+                                width: *width,
+                            });
+                        }
+                    }
+                    VersionToken::Value {
+                        value: new_value,
+                        spec: *spec,
+                        width: *width,
+                    }
+                }
+                VersionToken::Metadata { spec, .. } => {
+                    // metadata describes one build, so it doesn't carry over to the next version
+                    VersionToken::Metadata {
+                        value: None,
+                        spec: *spec,
+                    }
+                }
+                VersionToken::PreRelease { .. }
+                | VersionToken::OptionalStart { .. }
+                | VersionToken::OptionalEnd
+                | VersionToken::Literal(_) => token.clone(),
+            };
+            new_tokens.push(new_token);
         }
 
-        Ok(next_version)
+        let mut new_version = Version::new(new_tokens);
+        new_version.update_optional_presence();
+        Ok(new_version)
     }
 
-    /// Same as [`next`](struct.Version.html#method.next-1), but without checking if `date` is after
-    /// the date in this version.
+    /// Sets whether each optional segment is rendered: a segment is present if any of its values
+    /// are non-zero, or if any later optional segment is present (so that values don't shift
+    /// places).
+    pub(crate) fn update_optional_presence(&mut self) {
+        let mut later_present = false;
+        let mut segment_non_zero = false;
+        for token in self.tokens.iter_mut().rev() {
+            match token {
+                VersionToken::OptionalEnd => segment_non_zero = false,
+                VersionToken::Value { value, .. } if *value != 0 => segment_non_zero = true,
+                VersionToken::OptionalStart { present } => {
+                    *present = segment_non_zero || later_present;
+                    later_present = *present;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Changes the value of any pre-release tokens in place with `f`, which is given the current
+    /// value, the specifier, and the labels.
+    fn map_pre_release_tokens<F>(&mut self, mut f: F) -> Result<(), NextError>
+    where
+        F: FnMut(
+            Option<PreReleaseValue>,
+            &S::Specifier,
+            &PreReleaseLabels,
+        ) -> Result<Option<PreReleaseValue>, NextError>,
+    {
+        for token in &mut self.tokens {
+            if let VersionToken::PreRelease {
+                value,
+                spec,
+                labels,
+            } = token
+            {
+                *value = f(*value, spec, labels)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<S: Scheme> PartialOrd for Version<'_, S> {
+    /// Compares two versions. This is only a partial ordering it is only meaningful to compare two
+    /// versions when they come from the same format.
     ///
-    /// # Errors
+    /// Returns `None` when either of the following are true:
     ///
-    /// Same as [`next`](struct.Version.html#method.next-1), but without
-    /// [`NextError::NewDateIsBefore`].
-    pub fn next_unchecked(&self, date: Date) -> Result<Self, NextError> {
-        let new_version =
-            self.new_map_value_tokens(|(_, this_spec)| this_spec.next_value(date.as_naive_date()))?;
-        Ok(new_version)
+    /// - The number of *tokens* in the versions are different. Tokens are either literal text or
+    ///   specifier values.
+    /// - For two given tokens, they are not of the same type. E.g., one is a literal, one is a
+    ///   value.
+    /// - For two given literal tokens, the text is not the same.
+    /// - For two given value tokens, they are not of the same specifier type. E.g., one is a
+    ///   `<YYYY>` value, one is a `<YY>` value.
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        if self.tokens.len() == other.tokens.len() {
+            self.tokens.partial_cmp(&other.tokens)
+        } else {
+            None
+        }
     }
 }
 
-impl<'vs> Version<'vs, CalSem> {
-    fn next_base(
-        &self,
-        date: Date,
-        level: CalSemLevel,
-        err_on_date_before: bool,
-    ) -> Result<Self, NextError> {
-        // track if the semantic level was found in the format string.
-        let mut sem_spec_found = false;
-        let level_spec = level.spec();
+/// A wrapper of a [`Version`] with a total ordering, so that versions can be sorted, put in a
+/// [`BTreeSet`](std::collections::BTreeSet), or used as [`HashMap`](std::collections::HashMap)
+/// keys.
+///
+/// Versions are compared with [`Version::total_cmp`]. So, versions of the same format are ordered
+/// as they are by [`PartialOrd`], and are equal when they compare as equal, ignoring build
+/// metadata.
+///
+/// # Example
+///
+/// ```
+/// use nextver::prelude::*;
+/// use std::collections::BTreeSet;
+///
+/// let format = Sem::new_format("<MAJOR>.<MINOR>.<PATCH>")?;
+/// let versions = ["1.10.0", "1.2.0", "1.2.0", "0.9.9"]
+///     .into_iter()
+///     .map(|version_str| format.new_version(version_str).map(OrdVersion))
+///     .collect::<Result<BTreeSet<_>, _>>()?;
+/// let version_strs = versions.iter().map(|version| version.0.to_string()).collect::<Vec<_>>();
+/// assert_eq!(vec!["0.9.9", "1.2.0", "1.10.0"], version_strs);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct OrdVersion<'vs, S: Scheme>(pub Version<'vs, S>);
 
-        // track if the calendar was updated, so we know if we need to do semantic updates
-        let mut cal_moved_fwd = false;
+impl<S: Scheme> PartialEq for OrdVersion<'_, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
 
-        let next_version = self.new_map_value_tokens(|(cur_value, this_spec)| {
-            let next_value = match this_spec {
-                CalSemSpecifier::Cal(cal_spec) => {
-                    let new_value = cal_spec.next_value(date.as_naive_date())?;
-                    if !cal_moved_fwd {
-                        match new_value.cmp(&cur_value) {
-                            Ordering::Greater => cal_moved_fwd = true,
-                            Ordering::Less if err_on_date_before => {
-                                return Err(NextError::NewDateIsBefore);
-                            }
-                            _ => {}
-                        }
-                    }
-                    new_value
-                }
-                CalSemSpecifier::Sem(sem_spec) => {
-                    if level_spec == this_spec {
-                        sem_spec_found = true;
-                    }
-                    if cal_moved_fwd {
-                        0
-                    } else {
-                        sem_spec.next_value(cur_value, level)
-                    }
-                }
-            };
+impl<S: Scheme> Eq for OrdVersion<'_, S> {}
 
-            Ok(next_value)
-        })?;
+impl<S: Scheme> PartialOrd for OrdVersion<'_, S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
 
-        if !sem_spec_found {
-            return Err(NextError::SemLevelNotInFormat {
-                spec: level_spec.to_string(),
-            });
+impl<S: Scheme> Ord for OrdVersion<'_, S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl<S: Scheme> Hash for OrdVersion<'_, S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.tokens.len().hash(state);
+        for token in &self.0.tokens {
+            token.hash_total(state);
         }
+    }
+}
 
-        Ok(next_version)
+impl<'vs, S: Scheme> From<Version<'vs, S>> for OrdVersion<'vs, S> {
+    fn from(version: Version<'vs, S>) -> Self {
+        Self(version)
     }
+}
 
-    /// Returns a new version where the following are done in order:
-    ///
-    /// 1. The values of all calendar specifiers are changed to those in `date`.
-    /// 2. A check is performed to see if the date has advanced. Then, one of the following is
-    ///    performed:
-    ///    - (*date-is-different*) Iff the date has advanced, all semantic values are reset to zero.
-    ///    - (*date-is-same*) Otherwise, the value of semantic specifier given by `level` is
-    ///      incremented, and all lesser semantic values are reset to zero. This is similar to how
-    ///      an [odometer](https://en.wikipedia.org/wiki/Odometer) works.
+impl Version<'_, Sem> {
+    /// Returns the value of the specifier of `level`, or `None` if it is not in the format. The
+    /// pre-release levels give the pre-release number, which is `None` for a final release.
     ///
-    /// If `date` is before the date in this version, an error is returned. (See
-    /// [`next_unchecked`](struct.Version.html#method.next_unchecked-1) to skip this check.)
+    /// An absent [optional segment](crate#optional-segments) has values of zero.
     ///
     /// # Example
     ///
-    /// In the *date-is-different* case:
+    /// ```
+    /// use nextver::prelude::*;
     ///
+    /// let version = Sem::new_version("<MAJOR>.<MINOR>[.<PATCH>]", "1.2")?;
+    /// assert_eq!(Some(1), version.get(SemLevel::Major));
+    /// assert_eq!(Some(0), version.get(SemLevel::Patch));
+    /// assert_eq!(None, version.get(SemLevel::Revision));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    /// use nextver::prelude::*;;
+    #[must_use]
+    pub fn get(&self, level: SemLevel) -> Option<SpecValue> {
+        let level_spec = level.spec();
+        if level_spec.is_pre_release() {
+            self.pre_release().map(|(_, number)| number)
+        } else {
+            self.find_value(|spec, value| (spec == level_spec).then_some(value))
+        }
+    }
+
+    /// Returns a new version where the value of specifier given by `level` is incremented, and all
+    /// lesser semantic values are reset to zero. This is similar to how an
+    /// [odometer](https://en.wikipedia.org/wiki/Odometer) works.
     ///
-    /// let date = Date::utc_now();  // assume today is 2024-02-23
-    /// # let date = Date::explicit(2024, 2, 23)?;
+    /// The epoch, `<EPOCH>`, is the greatest value, and only changes when `level` is
+    /// [`SemLevel::Epoch`]. If the format has a pre-release specifier, `<PRE>`, incrementing a
+    /// value makes a final release. (See [`Self::next_pre_release`] to make a pre-release.)
     ///
-    /// let cur = CalSem::new_version("<YYYY>.<MM>.<PATCH>", "2024.1.123")?;
-    /// let next = cur.next(date, CalSemLevel::Patch)?;
-    /// assert_eq!("2024.2.0", &next.to_string());
+    /// # Example
+    ///
+    /// ```
+    /// use nextver::prelude::*;
+    ///
+    /// let cur = Sem::new_version("<MAJOR>.<MINOR>.<PATCH>", "1.2.3")?;
+    /// let next = cur.next(SemLevel::Major)?;
+    /// assert_eq!("2.0.0", &next.to_string());
     /// assert!(cur < next);
+    ///
+    /// let next_next = next.next(SemLevel::Patch)?;
+    /// assert_eq!("2.0.1", &next_next.to_string());
+    /// assert!(next < next_next);
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     ///
-    /// In the *date-is-same* case:
+    /// # Errors
+    ///
+    /// Returns a [`Result::Err`] of...
+    ///
+    /// - [`NextError::SemLevelNotInFormat`] if the specifier of `level` is not in format.
+    /// - [`NextError::NotPreRelease`] if `level` is [`SemLevel::PreRelease`],
+    ///   [`SemLevel::PreReleaseLabel`], or [`SemLevel::FinalRelease`] and this version is not a
+    ///   pre-release.
+    /// - [`NextError::NoNextPreReleaseLabel`] if `level` is [`SemLevel::PreReleaseLabel`] and this
+    ///   version already has the last pre-release label.
+    /// - [`NextError::ValueOverflow`] if an incremented value would be greater than
+    ///   [`SpecValue::MAX`](crate::SpecValue).
+    pub fn next(&self, level: SemLevel) -> Result<Self, NextError> {
+        self.next_step(level, Step::By(1), false)
+    }
+
+    /// Like [`Self::next`], but the new version has the build metadata `metadata`.
+    ///
+    /// # Example
     ///
     /// ```
-    /// use nextver::prelude::*;;
+    /// use nextver::prelude::*;
     ///
-    /// let date = Date::utc_now();  // assume today is 2024-02-23
-    /// # let date = Date::explicit(2024, 2, 23)?;
+    /// let cur = Sem::new_version("<MAJOR>.<MINOR>.<PATCH><META>", "1.2.3+ci.41")?;
+    /// let next = cur.next_with_metadata(SemLevel::Patch, "ci.42")?;
+    /// assert_eq!("1.2.4+ci.42", &next.to_string());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
     ///
-    /// let cur = CalSem::new_version("<YYYY>.<MM>.<PATCH>", "2024.2.123")?;
-    /// let next = cur.next(date, CalSemLevel::Patch)?;
-    /// assert_eq!("2024.2.124", &next.to_string());
+    /// # Errors
+    ///
+    /// Returns a [`Result::Err`] of any error that [`Self::next`] or [`Self::with_metadata`]
+    /// returns.
+    pub fn next_with_metadata(&self, level: SemLevel, metadata: &str) -> Result<Self, NextError> {
+        self.next(level)?.with_metadata(Some(metadata))
+    }
+
+    /// Like [`Self::next`], but the new version is a pre-release of it, starting at the first
+    /// label with a number of zero. See [pre-releases](crate#pre-releases).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nextver::prelude::*;
+    ///
+    /// let cur = Sem::new_version("<MAJOR>.<MINOR>.<PATCH><PRE>", "1.2.3")?;
+    /// let next = cur.next_pre_release(SemLevel::Minor)?;
+    /// assert_eq!("1.3.0-alpha.0", &next.to_string());
     /// assert!(cur < next);
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
@@ -631,41 +1701,1203 @@ impl<'vs> Version<'vs, CalSem> {
     ///
     /// Returns a [`Result::Err`] of...
     ///
-    /// - [`NextError::NoCalendarChange`] if the date does not change the calendar values.
-    /// - [`NextError::NewDateIsBefore`] if `date` is before the date in this version.
-    /// - [`NextError::NegativeYearValue`] if the year value would be negative. (Year specifiers
-    ///   have lower bounds. See the [table](crate#table) for more information.)
-    /// - [`NextError::SemLevelNotInFormat`] if the specifier of `level` is not in format.
-    pub fn next(&self, date: Date, level: CalSemLevel) -> Result<Self, NextError> {
-        self.next_base(date, level, true)
+    /// - [`NextError::PreReleaseLevel`] if `level` is [`SemLevel::PreRelease`],
+    ///   [`SemLevel::PreReleaseLabel`], or [`SemLevel::FinalRelease`].
+    /// - [`NextError::SemLevelNotInFormat`] if the specifier of `level` or the pre-release
+    ///   specifier is not in format.
+    /// - Any error that [`Self::next`] returns.
+    pub fn next_pre_release(&self, level: SemLevel) -> Result<Self, NextError> {
+        if level.spec().is_pre_release() {
+            return Err(NextError::PreReleaseLevel);
+        }
+        self.next_step(level, Step::By(1), true)
     }
 
-    /// Same as [`next`](struct.Version.html#method.next-2), but without checking if `date` is after
-    /// the date in this version.
+    /// Like [`Self::next`], but the value of the specifier given by `level` is incremented by
+    /// `amount` instead of one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nextver::prelude::*;
+    ///
+    /// let cur = Sem::new_version("<MAJOR>.<MINOR>.<PATCH>", "3.2.1")?;
+    /// let next = cur.next_by(SemLevel::Major, 2)?;
+    /// assert_eq!("5.0.0", &next.to_string());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
     ///
     /// # Errors
     ///
-    /// Same as [`next`](struct.Version.html#method.next-2), but without
-    /// [`NextError::NewDateIsBefore`].
-    pub fn next_unchecked(&self, date: Date, level: CalSemLevel) -> Result<Self, NextError> {
-        self.next_base(date, level, false)
+    /// Returns a [`Result::Err`] of...
+    ///
+    /// - [`NextError::LevelHasNoValue`] if `level` is [`SemLevel::PreReleaseLabel`] or
+    ///   [`SemLevel::FinalRelease`].
+    /// - [`NextError::NotGreater`] if `amount` is zero.
+    /// - Any error that [`Self::next`] returns.
+    pub fn next_by(&self, level: SemLevel, amount: SpecValue) -> Result<Self, NextError> {
+        self.check_greater(self.next_with_value(level, Step::By(amount))?)
     }
-}
 
-impl<'vs, S: Scheme> Display for Version<'vs, S> {
-    /// Returns the rendered version string
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for token in &self.tokens {
-            write!(f, "{token}")?;
+    /// Like [`Self::next`], but the value of the specifier given by `level` is set to `value`
+    /// instead of incremented. Lesser semantic values are still reset to zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nextver::prelude::*;
+    ///
+    /// let cur = Sem::new_version("<MAJOR>.<MINOR>.<PATCH>", "3.2.1")?;
+    /// let next = cur.next_to(SemLevel::Major, 5)?;
+    /// assert_eq!("5.0.0", &next.to_string());
+    ///
+    /// // the new version must be greater
+    /// assert!(cur.next_to(SemLevel::Minor, 1).is_err());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result::Err`] of...
+    ///
+    /// - [`NextError::LevelHasNoValue`] if `level` is [`SemLevel::PreReleaseLabel`] or
+    ///   [`SemLevel::FinalRelease`].
+    /// - [`NextError::NotGreater`] if the new version is not greater than this one.
+    /// - Any error that [`Self::next`] returns.
+    pub fn next_to(&self, level: SemLevel, value: SpecValue) -> Result<Self, NextError> {
+        self.check_greater(self.next_to_unchecked(level, value)?)
+    }
+
+    /// Like [`Self::next_to`], but the new version is not required to be greater than this one.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result::Err`] of...
+    ///
+    /// - [`NextError::LevelHasNoValue`] if `level` is [`SemLevel::PreReleaseLabel`] or
+    ///   [`SemLevel::FinalRelease`].
+    /// - Any error that [`Self::next`] returns.
+    pub fn next_to_unchecked(&self, level: SemLevel, value: SpecValue) -> Result<Self, NextError> {
+        self.next_with_value(level, Step::To(value))
+    }
+
+    /// Returns the version that [`Self::next`] would have been called on with `level` to return
+    /// this one. That is, the value of the specifier given by `level` is decremented.
+    ///
+    /// Incrementing resets all lesser semantic values, so their previous values are unknown. So,
+    /// this only works when the format has none of them. (The build number is decremented too, and
+    /// the epoch is only decremented when `level` is [`SemLevel::Epoch`].)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nextver::prelude::*;
+    ///
+    /// let cur = Sem::new_version("<MAJOR>.<MINOR>.<PATCH>", "1.2.3")?;
+    /// let prev = cur.prev(SemLevel::Patch)?;
+    /// assert_eq!("1.2.2", &prev.to_string());
+    ///
+    /// // the patch of the version before `1.2.0` is unknown
+    /// assert!(cur.prev(SemLevel::Minor).is_err());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result::Err`] of...
+    ///
+    /// - [`NextError::SemLevelNotInFormat`] if the specifier of `level` is not in format.
+    /// - [`NextError::PrevValueUnknown`] if the format has a value that is reset when `level` is
+    ///   incremented. This is always the case for [`SemLevel::PreReleaseLabel`] and
+    ///   [`SemLevel::FinalRelease`].
+    /// - [`NextError::NotPreRelease`] if `level` is [`SemLevel::PreRelease`] and this version is
+    ///   not a pre-release.
+    /// - [`NextError::ValueUnderflow`] if a decremented value is already zero.
+    pub fn prev(&self, level: SemLevel) -> Result<Self, NextError> {
+        let level_spec = level.spec();
+        if !self.has_spec(level_spec) {
+            return Err(NextError::SemLevelNotInFormat {
+                spec: level_spec.to_string(),
+            });
         }
 
-        Ok(())
+        let mut prev_version = self.new_map_value_tokens(|(cur_value, this_spec)| {
+            this_spec.prev_value(cur_value, level)
+        })?;
+
+        prev_version.map_pre_release_tokens(|cur_value, this_spec, _| {
+            prev_pre_release(cur_value, level.pre_release_bump(Step::By(1)), this_spec)
+        })?;
+
+        Ok(prev_version)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Like [`Self::next_step`], but `level` must refer to a value.
+    fn next_with_value(&self, level: SemLevel, step: Step) -> Result<Self, NextError> {
+        if matches!(level, SemLevel::PreReleaseLabel | SemLevel::FinalRelease) {
+            return Err(NextError::LevelHasNoValue);
+        }
+        self.next_step(level, step, false)
+    }
+
+    /// Returns `next` if it is greater than this version, or a [`NextError::NotGreater`]
+    /// otherwise.
+    fn check_greater(&self, next: Self) -> Result<Self, NextError> {
+        if next > *self {
+            Ok(next)
+        } else {
+            Err(NextError::NotGreater {
+                current: self.to_string(),
+                next: next.to_string(),
+            })
+        }
+    }
+
+    /// Returns a new version where the value of the specifier given by `level` changes by `step`,
+    /// and other values change as in [`Self::next`]. If `start_pre_release`, the new version is a
+    /// pre-release, as in [`Self::next_pre_release`].
+    fn next_step(
+        &self,
+        level: SemLevel,
+        step: Step,
+        start_pre_release: bool,
+    ) -> Result<Self, NextError> {
+        let mut spec_found = false;
+        let mut pre_release_found = false;
+        let level_spec = level.as_ref().spec();
+
+        let mut next_version = self.new_map_value_tokens(|(cur_value, this_spec)| {
+            if level_spec == this_spec {
+                spec_found = true;
+            }
+            this_spec.next_value(cur_value, level, step)
+        })?;
+
+        next_version.map_pre_release_tokens(|cur_value, this_spec, labels| {
+            pre_release_found = true;
+            if level_spec == this_spec {
+                spec_found = true;
+            }
+            let bump = if start_pre_release {
+                PreReleaseBump::Reset
+            } else {
+                level.pre_release_bump(step)
+            };
+            labels.next_value(cur_value, bump, this_spec)
+        })?;
+
+        if !spec_found {
+            return Err(NextError::SemLevelNotInFormat {
+                spec: level_spec.to_string(),
+            });
+        }
+        if start_pre_release && !pre_release_found {
+            return Err(NextError::SemLevelNotInFormat {
+                spec: SemLevel::PreRelease.spec().to_string(),
+            });
+        }
+
+        Ok(next_version)
+    }
+}
+
+/// A part of a [`Version`], as given by [`Version::parts`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum VersionPart<'v, Sp: Specifier> {
+    /// A numeric value.
+    Value {
+        /// The specifier
+        spec: &'static Sp,
+        /// The value
+        value: SpecValue,
+    },
+    /// A pre-release.
+    PreRelease {
+        /// The specifier
+        spec: &'static Sp,
+        /// The label and number, or `None` for a final release
+        value: Option<(&'v str, SpecValue)>,
+    },
+    /// Build metadata.
+    Metadata {
+        /// The specifier
+        spec: &'static Sp,
+        /// The metadata text, or `None` if there is none
+        value: Option<&'v str>,
+    },
+    /// Literal text.
+    Literal(&'v str),
+}
+
+/// Returns the first day of `week` in `year`, where weeks start on Sunday, and week 0 is the days
+/// before the first Sunday (as with `<WW>`).
+fn week_start(year: i32, week: SpecValue) -> Option<NaiveDate> {
+    let jan_1 = NaiveDate::from_ymd_opt(year, 1, 1)?;
+    if week == 0 {
+        return Some(jan_1);
+    }
+    let first_sunday = 1 + (7 - jan_1.weekday().num_days_from_sunday()) % 7;
+    let ordinal = (week - 1).checked_mul(7)?.checked_add(first_sunday)?;
+    NaiveDate::from_yo_opt(year, ordinal)
+}
+
+/// Errors around dates, as used to update [`Cal`](crate::Cal) and [`CalSem`](crate::CalSem)
+/// versions.
+#[non_exhaustive]
+#[derive(thiserror::Error, Debug, PartialEq)]
+pub enum DateError {
+    /// Arguments to [`Date::explicit`](crate::Date::explicit) do not represent a valid date.
+    #[error("year ({year}), month ({month}), and day ({day}) should represent a valid date")]
+    InvalidDateArguments {
+        /// The year value
+        year: i32,
+        /// The month value
+        month: u32,
+        /// The day value
+        day: u32,
+    },
+
+    /// Arguments to [`DateTime::explicit`](crate::DateTime::explicit) do not represent a valid
+    /// date and time.
+    #[error(
+        "year ({year}), month ({month}), day ({day}), hour ({hour}), and minute ({minute}) should \
+         represent a valid date and time"
+    )]
+    InvalidDateTimeArguments {
+        /// The year value
+        year: i32,
+        /// The month value
+        month: u32,
+        /// The day value
+        day: u32,
+        /// The hour value
+        hour: u32,
+        /// The minute value
+        minute: u32,
+    },
+
+    /// The date or date-time string could not be parsed.
+    ///
+    /// See [`chrono::NaiveDate::from_str`], [`chrono::NaiveDateTime::from_str`], and
+    /// [`chrono::ParseError`].
+    // not `transparent`, as `chrono::ParseError` only implements `Error` with `std`
+    #[error("{0}")]
+    UnparseableDate(chrono::ParseError),
+}
+
+impl From<chrono::ParseError> for DateError {
+    fn from(err: chrono::ParseError) -> Self {
+        DateError::UnparseableDate(err)
+    }
+}
+
+/// Ways to specify a date.
+///
+/// ```
+/// use nextver::Date;
+///
+/// let utc_now = Date::utc_now();
+/// let local_now = Date::local_now();
+/// let explicit = Date::explicit(2021, 2, 3).unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Date(NaiveDate);
+
+impl Date {
+    /// Returns a new [`Date`] representing the current date in UTC at the time of this call.
+    ///
+    /// Requires the `clock` feature, which is enabled by default.
+    #[cfg(feature = "clock")]
+    #[must_use]
+    pub fn utc_now() -> Self {
+        Self(Utc::now().date_naive())
+    }
+
+    /// Returns a new [`Date`] representing the current date in the system's local timezone at the
+    /// time of this call.
+    ///
+    /// Requires the `clock` feature, which is enabled by default.
+    #[cfg(feature = "clock")]
+    #[must_use]
+    pub fn local_now() -> Self {
+        Self(Local::now().date_naive())
+    }
+
+    /// Returns result of a new [`Date`] representing the given Date.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DateError::InvalidDateArguments`] if the date values do not represent a valid
+    /// date.
+    pub fn explicit(year: i32, month: u32, day: u32) -> Result<Self, DateError> {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .map(Self)
+            .ok_or(DateError::InvalidDateArguments { year, month, day })
+    }
+
+    pub(crate) fn as_naive_date(self) -> NaiveDate {
+        self.0
+    }
+}
+
+impl FromStr for Date {
+    type Err = DateError;
+
+    /// Parses a date string into a [`Date`]. The string must be in the format `YYYY-MM-DD`, where
+    /// `YYYY` is the year zero-padded to 4 digits, `MM` is the month zero-padded to 2 digits, and
+    /// `DD` is the day zero-padded to 2 digits.
+    ///
+    /// See [`NaiveDate::from_str`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DateError::UnparseableDate`] if the date string is not parseable.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(NaiveDate::from_str(s)?))
+    }
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Date {
+    /// Serializes a date as an ISO 8601 string, `YYYY-MM-DD`.
+    fn serialize<Ser: serde::Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Date {
+    /// Deserializes a date from an ISO 8601 string, `YYYY-MM-DD`. See [`Date::from_str`].
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let date_str = String::deserialize(deserializer)?;
+        date_str.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Ways to specify a date and time of day, for formats with sub-day specifiers like `<HH>` and
+/// `<MI>`.
+///
+/// Anywhere a [`DateTime`] is accepted, a [`Date`] may be given instead, which refers to the start
+/// (midnight) of that day.
+///
+/// ```
+/// use nextver::DateTime;
+///
+/// let utc_now = DateTime::utc_now();
+/// let local_now = DateTime::local_now();
+/// let explicit = DateTime::explicit(2021, 2, 3, 14, 0).unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DateTime(NaiveDateTime);
+
+impl DateTime {
+    /// Returns a new [`DateTime`] representing the current date and time in UTC at the time of this
+    /// call.
+    ///
+    /// Requires the `clock` feature, which is enabled by default.
+    #[cfg(feature = "clock")]
+    #[must_use]
+    pub fn utc_now() -> Self {
+        Self(Utc::now().naive_utc())
+    }
+
+    /// Returns a new [`DateTime`] representing the current date and time in the system's local
+    /// timezone at the time of this call.
+    ///
+    /// Requires the `clock` feature, which is enabled by default.
+    #[cfg(feature = "clock")]
+    #[must_use]
+    pub fn local_now() -> Self {
+        Self(Local::now().naive_local())
+    }
+
+    /// Returns result of a new [`DateTime`] representing the given date, hour, and minute.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DateError::InvalidDateTimeArguments`] if the values do not represent a valid date
+    /// and time.
+    pub fn explicit(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+    ) -> Result<Self, DateError> {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .and_then(|date| date.and_hms_opt(hour, minute, 0))
+            .map(Self)
+            .ok_or(DateError::InvalidDateTimeArguments {
+                year,
+                month,
+                day,
+                hour,
+                minute,
+            })
+    }
+
+    pub(crate) fn as_naive_date_time(self) -> NaiveDateTime {
+        self.0
+    }
+}
+
+impl From<Date> for DateTime {
+    fn from(date: Date) -> Self {
+        Self(date.as_naive_date().and_time(NaiveTime::MIN))
+    }
+}
+
+impl FromStr for DateTime {
+    type Err = DateError;
+
+    /// Parses a date-time string into a [`DateTime`]. The string must be in the format
+    /// `YYYY-MM-DDTHH:MM` or `YYYY-MM-DDTHH:MM:SS`, where the date part is the same as for
+    /// [`Date::from_str`], and the hour, minute, and second are zero-padded to 2 digits.
+    ///
+    /// See [`NaiveDateTime::from_str`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DateError::UnparseableDate`] if the date-time string is not parseable.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M")
+            .or_else(|_| NaiveDateTime::from_str(s))
+            .map(Self)
+            .map_err(DateError::from)
+    }
+}
+
+impl Display for DateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.format("%Y-%m-%dT%H:%M"))
+    }
+}
+
+impl Version<'_, Cal> {
+    /// Returns the year, from `<YYYY>`, `<YY>`, or `<0Y>`, or `None` if the format has none of
+    /// them. Short years are given as full years, so `24` is `2024`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nextver::prelude::*;
+    ///
+    /// let version = Cal::new_version("<YY>.<0M>", "24.09")?;
+    /// assert_eq!(Some(2024), version.year());
+    /// assert_eq!(Some(9), version.month());
+    /// assert_eq!(None, version.day());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn year(&self) -> Option<SpecValue> {
+        self.find_value(|spec, value| match spec {
+            CalSpecifier::Year(type_) => full_year(type_, value),
+            _ => None,
+        })
+    }
+
+    /// Returns the ISO 8601 week-numbering year, from `<GGGG>`, or `None` if it is not in the
+    /// format.
+    #[must_use]
+    pub fn iso_year(&self) -> Option<SpecValue> {
+        self.find_value(|spec, value| matches!(spec, CalSpecifier::IsoYear).then_some(value))
+    }
+
+    /// Returns the quarter of the year, from `<Q>`, or `None` if it is not in the format.
+    #[must_use]
+    pub fn quarter(&self) -> Option<SpecValue> {
+        self.find_value(|spec, value| matches!(spec, CalSpecifier::Quarter).then_some(value))
+    }
+
+    /// Returns the month of the year, from `<MM>`, `<0M>`, `<MON>`, or `<Month>`, or `None` if the
+    /// format has none of them.
+    #[must_use]
+    pub fn month(&self) -> Option<SpecValue> {
+        self.find_value(|spec, value| {
+            matches!(spec, CalSpecifier::Month(_) | CalSpecifier::MonthName(_)).then_some(value)
+        })
+    }
+
+    /// Returns the week of the year, from `<WW>` or `<0W>`, or `None` if the format has none of
+    /// them.
+    #[must_use]
+    pub fn week(&self) -> Option<SpecValue> {
+        self.find_value(|spec, value| matches!(spec, CalSpecifier::Week(_)).then_some(value))
+    }
+
+    /// Returns the ISO 8601 week of the week-numbering year, from `<IW>` or `<0IW>`, or `None` if
+    /// the format has none of them.
+    #[must_use]
+    pub fn iso_week(&self) -> Option<SpecValue> {
+        self.find_value(|spec, value| matches!(spec, CalSpecifier::IsoWeek(_)).then_some(value))
+    }
+
+    /// Returns the day of the month, from `<DD>` or `<0D>`, or `None` if the format has none of
+    /// them.
+    #[must_use]
+    pub fn day(&self) -> Option<SpecValue> {
+        self.find_value(|spec, value| matches!(spec, CalSpecifier::Day(_)).then_some(value))
+    }
+
+    /// Returns the day of the year, from `<DOY>` or `<0DOY>`, or `None` if the format has none of
+    /// them.
+    #[must_use]
+    pub fn day_of_year(&self) -> Option<SpecValue> {
+        self.find_value(|spec, value| matches!(spec, CalSpecifier::DayOfYear(_)).then_some(value))
+    }
+
+    /// Returns the hour of the day, from `<HH>` or `<0H>`, or `None` if the format has none of
+    /// them.
+    #[must_use]
+    pub fn hour(&self) -> Option<SpecValue> {
+        self.find_value(|spec, value| matches!(spec, CalSpecifier::Hour(_)).then_some(value))
+    }
+
+    /// Returns the minute of the hour, from `<MI>` or `<0MI>`, or `None` if the format has none of
+    /// them.
+    #[must_use]
+    pub fn minute(&self) -> Option<SpecValue> {
+        self.find_value(|spec, value| matches!(spec, CalSpecifier::Minute(_)).then_some(value))
+    }
+
+    fn next_base(&self, date: DateTime, increment_epoch: bool) -> Result<Self, NextError> {
+        let date = date.as_naive_date_time();
+
+        // track if the epoch was found in the format string, if it is to be incremented.
+        let mut epoch_found = false;
+
+        // track if the calendar was moved forward in time, so we can error if not. a build number,
+        // which always increments, or an incremented epoch count as moving forward.
+        let mut cal_moved_fwd = false;
+
+        let next_version = self.new_map_value_tokens(|(cur_value, this_spec)| {
+            let next_value = if increment_epoch && this_spec.is_epoch() {
+                epoch_found = true;
+                increment(cur_value, this_spec)?
+            } else {
+                this_spec.next_value(cur_value, date)?
+            };
+
+            if !cal_moved_fwd {
+                match next_value.cmp(&cur_value) {
+                    Ordering::Greater => cal_moved_fwd = true,
+                    Ordering::Less => {
+                        return Err(NextError::NewDateIsBefore);
+                    }
+                    Ordering::Equal => {}
+                }
+            }
+
+            Ok(next_value)
+        })?;
+
+        if increment_epoch && !epoch_found {
+            return Err(NextError::EpochNotInFormat);
+        }
+
+        if !cal_moved_fwd {
+            return Err(NextError::NoCalendarChange);
+        }
+
+        Ok(next_version)
+    }
+
+    /// Returns a new version where the values of all date specifiers is advanced to those in
+    /// `date`, which may be a [`Date`] or a [`DateTime`]. (Only a [`DateTime`] can advance
+    /// sub-day specifiers like `<HH>`.)
+    ///
+    /// If `date` is before the date in this version, an error is returned. (See
+    /// [`Self::next_unchecked`] to skip this check.)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nextver::prelude::*;
+    ///
+    /// let date = Date::utc_now();  // assume today is 2024-02-23
+    /// # let date = Date::explicit(2024, 2, 23)?;
+    ///
+    /// let cur = Cal::new_version("<YYYY>.<MM>", "2024.1")?;
+    /// let next = cur.next(date)?;
+    /// assert_eq!("2024.2", &next.to_string());
+    /// assert!(cur < next);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result::Err`] of...
+    ///
+    /// - [`NextError::NoCalendarChange`] if the date does not change the calendar values.
+    /// - [`NextError::NewDateIsBefore`] if `date` is before the date in this version.
+    /// - [`NextError::NegativeYearValue`] if the year value would be negative. (Year specifiers
+    ///   have lower bounds. See the [table](crate#table) for more information.)
+    /// - [`NextError::ValueOverflow`] if an incremented value would be greater than
+    ///   [`SpecValue::MAX`](crate::SpecValue).
+    pub fn next(&self, date: impl Into<DateTime>) -> Result<Self, NextError> {
+        self.next_base(date.into(), false)
+    }
+
+    /// Same as [`next`](struct.Version.html#method.next-1), but the new version has the build
+    /// metadata `metadata`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nextver::prelude::*;
+    ///
+    /// let date = Date::explicit(2024, 2, 23)?;
+    /// let cur = Cal::new_version("<YYYY>.<MM>.<DD><META>", "2024.2.22+ci.41")?;
+    /// let next = cur.next_with_metadata(date, "ci.42")?;
+    /// assert_eq!("2024.2.23+ci.42", &next.to_string());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result::Err`] of any error that [`next`](struct.Version.html#method.next-1) or
+    /// [`Self::with_metadata`] returns.
+    pub fn next_with_metadata(
+        &self,
+        date: impl Into<DateTime>,
+        metadata: &str,
+    ) -> Result<Self, NextError> {
+        self.next(date)?.with_metadata(Some(metadata))
+    }
+
+    /// Same as [`next`](struct.Version.html#method.next-1), but the epoch, `<EPOCH>`, is also
+    /// incremented. (Calendar formats have no semantic levels, so this is the only way to change
+    /// the epoch.) Because the epoch is the greatest value, the next version is greater even if
+    /// `date` is before the date in this version.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nextver::prelude::*;
+    ///
+    /// let date = Date::explicit(2024, 9, 3)?;
+    ///
+    /// let cur = Cal::new_version("[<EPOCH>!]<YYYY>.<MM>.<DD>", "2024.9.3")?;
+    /// let next = cur.next_epoch(date)?;
+    /// assert_eq!("1!2024.9.3", &next.to_string());
+    /// assert!(cur < next);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result::Err`] of...
+    ///
+    /// - [`NextError::EpochNotInFormat`] if the format has no `<EPOCH>` specifier.
+    /// - [`NextError::NegativeYearValue`] if the year value would be negative. (Year specifiers
+    ///   have lower bounds. See the [table](crate#table) for more information.)
+    /// - [`NextError::ValueOverflow`] if an incremented value would be greater than
+    ///   [`SpecValue::MAX`](crate::SpecValue).
+    pub fn next_epoch(&self, date: impl Into<DateTime>) -> Result<Self, NextError> {
+        self.next_base(date.into(), true)
+    }
+
+    /// Returns a new version where the values of all date specifiers are moved back to the
+    /// preceding period of the least significant one. For example, the version before `2024.03`
+    /// is `2024.02` when the format is `<YYYY>.<0M>`. A build number, `<BUILD>`, is decremented
+    /// too, and the epoch is left as-is.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nextver::prelude::*;
+    ///
+    /// let cur = Cal::new_version("<YYYY>.<0M>.<0D>", "2024.03.01")?;
+    /// let prev = cur.prev()?;
+    /// assert_eq!("2024.02.29", &prev.to_string());
+    /// assert!(prev < cur);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result::Err`] of...
+    ///
+    /// - [`NextError::InvalidVersionDate`] if the values of this version do not represent a valid
+    ///   date, like `2024.02.30`.
+    /// - [`NextError::NegativeYearValue`] if the year value would be negative. (Year specifiers
+    ///   have lower bounds. See the [table](crate#table) for more information.)
+    /// - [`NextError::ValueUnderflow`] if the build number is already zero.
+    pub fn prev(&self) -> Result<Self, NextError> {
+        // the minute before the start of this version's period is in the preceding period
+        let date = self
+            .period_start()?
+            .checked_sub_signed(Duration::minutes(1))
+            .ok_or(NextError::InvalidVersionDate)?;
+
+        self.new_map_value_tokens(|(cur_value, this_spec)| this_spec.prev_value(cur_value, date))
+    }
+
+    /// Returns the earliest date and time that has the date values of this version.
+    fn period_start(&self) -> Result<NaiveDateTime, NextError> {
+        let year = self.year().and_then(|year| i32::try_from(year).ok());
+        let iso_year = self.iso_year().and_then(|year| i32::try_from(year).ok());
+        let (month, day, hour, minute) = (self.month(), self.day(), self.hour(), self.minute());
+
+        let date = if let Some(iso_year) = iso_year {
+            NaiveDate::from_isoywd_opt(iso_year, self.iso_week().unwrap_or(1), Weekday::Mon)
+        } else if let Some(day_of_year) = self.day_of_year() {
+            year.and_then(|year| NaiveDate::from_yo_opt(year, day_of_year))
+        } else if let Some(week) = self.week() {
+            year.and_then(|year| week_start(year, week))
+        } else {
+            // a quarter alone starts at its first month
+            let month = month.or_else(|| {
+                self.quarter()
+                    .and_then(|quarter| quarter.checked_sub(1)?.checked_mul(3)?.checked_add(1))
+            });
+            year.and_then(|year| {
+                NaiveDate::from_ymd_opt(year, month.unwrap_or(1), day.unwrap_or(1))
+            })
+        };
+
+        date.and_then(|date| date.and_hms_opt(hour.unwrap_or(0), minute.unwrap_or(0), 0))
+            .ok_or(NextError::InvalidVersionDate)
+    }
+
+    /// Same as [`next`](struct.Version.html#method.next-1), but without checking if `date` is after
+    /// the date in this version.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`next`](struct.Version.html#method.next-1), but without
+    /// [`NextError::NewDateIsBefore`].
+    pub fn next_unchecked(&self, date: impl Into<DateTime>) -> Result<Self, NextError> {
+        let date = date.into().as_naive_date_time();
+        let new_version = self
+            .new_map_value_tokens(|(cur_value, this_spec)| this_spec.next_value(cur_value, date))?;
+        Ok(new_version)
+    }
+}
+
+impl Version<'_, CalSem> {
+    /// Returns the value of the specifier of `level`, or `None` if it is not in the format. The
+    /// pre-release levels give the pre-release number, which is `None` for a final release.
+    ///
+    /// An absent [optional segment](crate#optional-segments) has values of zero.
+    #[must_use]
+    pub fn get(&self, level: CalSemLevel) -> Option<SpecValue> {
+        let level_spec = level.spec();
+        if level_spec.is_pre_release() {
+            self.pre_release().map(|(_, number)| number)
+        } else {
+            self.find_value(|spec, value| (spec == level_spec).then_some(value))
+        }
+    }
+
+    /// Returns the year, from `<YYYY>`, `<YY>`, or `<0Y>`, or `None` if the format has none of
+    /// them. Short years are given as full years, so `24` is `2024`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nextver::prelude::*;
+    ///
+    /// let version = CalSem::new_version("<YYYY>.<MM>.<PATCH>", "2024.9.3")?;
+    /// assert_eq!(Some(2024), version.year());
+    /// assert_eq!(Some(9), version.month());
+    /// assert_eq!(Some(3), version.get(CalSemLevel::Patch));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn year(&self) -> Option<SpecValue> {
+        self.find_value(|spec, value| match spec {
+            CalSemSpecifier::Cal(CalSemCalSpecifier::Year(type_)) => full_year(type_, value),
+            _ => None,
+        })
+    }
+
+    /// Returns the ISO 8601 week-numbering year, from `<GGGG>`, or `None` if it is not in the
+    /// format.
+    #[must_use]
+    pub fn iso_year(&self) -> Option<SpecValue> {
+        self.find_value(|spec, value| {
+            matches!(spec, CalSemSpecifier::Cal(CalSemCalSpecifier::IsoYear)).then_some(value)
+        })
+    }
+
+    /// Returns the quarter of the year, from `<Q>`, or `None` if it is not in the format.
+    #[must_use]
+    pub fn quarter(&self) -> Option<SpecValue> {
+        self.find_value(|spec, value| {
+            matches!(spec, CalSemSpecifier::Cal(CalSemCalSpecifier::Quarter)).then_some(value)
+        })
+    }
+
+    /// Returns the month of the year, from `<MM>`, `<0M>`, `<MON>`, or `<Month>`, or `None` if the
+    /// format has none of them.
+    #[must_use]
+    pub fn month(&self) -> Option<SpecValue> {
+        self.find_value(|spec, value| {
+            matches!(
+                spec,
+                CalSemSpecifier::Cal(
+                    CalSemCalSpecifier::Month(_) | CalSemCalSpecifier::MonthName(_)
+                )
+            )
+            .then_some(value)
+        })
+    }
+
+    /// Returns the week of the year, from `<WW>` or `<0W>`, or `None` if the format has none of
+    /// them.
+    #[must_use]
+    pub fn week(&self) -> Option<SpecValue> {
+        self.find_value(|spec, value| {
+            matches!(spec, CalSemSpecifier::Cal(CalSemCalSpecifier::Week(_))).then_some(value)
+        })
+    }
+
+    /// Returns the ISO 8601 week of the week-numbering year, from `<IW>` or `<0IW>`, or `None` if
+    /// the format has none of them.
+    #[must_use]
+    pub fn iso_week(&self) -> Option<SpecValue> {
+        self.find_value(|spec, value| {
+            matches!(spec, CalSemSpecifier::Cal(CalSemCalSpecifier::IsoWeek(_))).then_some(value)
+        })
+    }
+
+    /// Returns the day of the month, from `<DD>` or `<0D>`, or `None` if the format has none of
+    /// them.
+    #[must_use]
+    pub fn day(&self) -> Option<SpecValue> {
+        self.find_value(|spec, value| {
+            matches!(spec, CalSemSpecifier::Cal(CalSemCalSpecifier::Day(_))).then_some(value)
+        })
+    }
+
+    /// Returns the day of the year, from `<DOY>` or `<0DOY>`, or `None` if the format has none of
+    /// them.
+    #[must_use]
+    pub fn day_of_year(&self) -> Option<SpecValue> {
+        self.find_value(|spec, value| {
+            matches!(spec, CalSemSpecifier::Cal(CalSemCalSpecifier::DayOfYear(_))).then_some(value)
+        })
+    }
+
+    /// Returns the hour of the day, from `<HH>` or `<0H>`, or `None` if the format has none of
+    /// them.
+    #[must_use]
+    pub fn hour(&self) -> Option<SpecValue> {
+        self.find_value(|spec, value| {
+            matches!(spec, CalSemSpecifier::Cal(CalSemCalSpecifier::Hour(_))).then_some(value)
+        })
+    }
+
+    /// Returns the minute of the hour, from `<MI>` or `<0MI>`, or `None` if the format has none of
+    /// them.
+    #[must_use]
+    pub fn minute(&self) -> Option<SpecValue> {
+        self.find_value(|spec, value| {
+            matches!(spec, CalSemSpecifier::Cal(CalSemCalSpecifier::Minute(_))).then_some(value)
+        })
+    }
+
+    fn next_base(
+        &self,
+        date: DateTime,
+        level: CalSemLevel,
+        err_on_date_before: bool,
+        start_pre_release: bool,
+    ) -> Result<Self, NextError> {
+        let date = date.as_naive_date_time();
+
+        // track if the semantic level was found in the format string.
+        let mut sem_spec_found = false;
+        let mut pre_release_found = false;
+        let level_spec = level.spec();
+
+        // track if the calendar was updated, so we know if we need to do semantic updates
+        let mut cal_moved_fwd = false;
+
+        let mut next_version = self.new_map_value_tokens(|(cur_value, this_spec)| {
+            let next_value = match this_spec {
+                CalSemSpecifier::Cal(cal_spec) => {
+                    let new_value = cal_spec.next_value(date)?;
+                    if !cal_moved_fwd {
+                        match new_value.cmp(&cur_value) {
+                            Ordering::Greater => cal_moved_fwd = true,
+                            Ordering::Less if err_on_date_before => {
+                                return Err(NextError::NewDateIsBefore);
+                            }
+                            _ => {}
+                        }
+                    }
+                    new_value
+                }
+                CalSemSpecifier::Sem(sem_spec) => {
+                    if level_spec == this_spec {
+                        sem_spec_found = true;
+                    }
+                    if cal_moved_fwd && !this_spec.is_build() && !this_spec.is_epoch() {
+                        0
+                    } else {
+                        sem_spec.next_value(cur_value, level)?
+                    }
+                }
+            };
+
+            Ok(next_value)
+        })?;
+
+        next_version.map_pre_release_tokens(|cur_value, this_spec, labels| {
+            pre_release_found = true;
+            if level_spec == this_spec {
+                sem_spec_found = true;
+            }
+            let bump = if start_pre_release {
+                PreReleaseBump::Reset
+            } else if cal_moved_fwd {
+                // a new date is a new version, so only the levels that make a pre-release start
+                // one for it
+                match level {
+                    CalSemLevel::PreRelease | CalSemLevel::PreReleaseLabel => PreReleaseBump::Reset,
+                    _ => PreReleaseBump::Clear,
+                }
+            } else {
+                level.pre_release_bump(Step::By(1))
+            };
+            labels.next_value(cur_value, bump, this_spec)
+        })?;
+
+        if !sem_spec_found {
+            return Err(NextError::SemLevelNotInFormat {
+                spec: level_spec.to_string(),
+            });
+        }
+        if start_pre_release && !pre_release_found {
+            return Err(NextError::SemLevelNotInFormat {
+                spec: CalSemLevel::PreRelease.spec().to_string(),
+            });
+        }
+
+        Ok(next_version)
+    }
+
+    /// Returns a new version where the following are done in order:
+    ///
+    /// 1. The values of all calendar specifiers are changed to those in `date`, which may be a
+    ///    [`Date`] or a [`DateTime`]. (Only a [`DateTime`] can advance sub-day specifiers like
+    ///    `<HH>`.)
+    /// 2. A check is performed to see if the date has advanced. Then, one of the following is
+    ///    performed:
+    ///    - (*date-is-different*) Iff the date has advanced, all semantic values are reset to zero,
+    ///      except for the epoch, `<EPOCH>`, which is only changed when `level` is
+    ///      [`CalSemLevel::Epoch`].
+    ///    - (*date-is-same*) Otherwise, the value of semantic specifier given by `level` is
+    ///      incremented, and all lesser semantic values are reset to zero. This is similar to how
+    ///      an [odometer](https://en.wikipedia.org/wiki/Odometer) works.
+    ///
+    /// If the format has a pre-release specifier, `<PRE>`, the new version is a final release,
+    /// unless `level` refers to the pre-release. (See
+    /// [`next_pre_release`](struct.Version.html#method.next_pre_release-1) to make a pre-release.)
+    ///
+    /// If `date` is before the date in this version, an error is returned. (See
+    /// [`next_unchecked`](struct.Version.html#method.next_unchecked-1) to skip this check.)
+    ///
+    /// # Example
+    ///
+    /// In the *date-is-different* case:
+    ///
+    /// ```
+    /// use nextver::prelude::*;
+    ///
+    /// let date = Date::utc_now();  // assume today is 2024-02-23
+    /// # let date = Date::explicit(2024, 2, 23)?;
+    ///
+    /// let cur = CalSem::new_version("<YYYY>.<MM>.<PATCH>", "2024.1.123")?;
+    /// let next = cur.next(date, CalSemLevel::Patch)?;
+    /// assert_eq!("2024.2.0", &next.to_string());
+    /// assert!(cur < next);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// In the *date-is-same* case:
+    ///
+    /// ```
+    /// use nextver::prelude::*;
+    ///
+    /// let date = Date::utc_now();  // assume today is 2024-02-23
+    /// # let date = Date::explicit(2024, 2, 23)?;
+    ///
+    /// let cur = CalSem::new_version("<YYYY>.<MM>.<PATCH>", "2024.2.123")?;
+    /// let next = cur.next(date, CalSemLevel::Patch)?;
+    /// assert_eq!("2024.2.124", &next.to_string());
+    /// assert!(cur < next);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result::Err`] of...
+    ///
+    /// - [`NextError::NoCalendarChange`] if the date does not change the calendar values.
+    /// - [`NextError::NewDateIsBefore`] if `date` is before the date in this version.
+    /// - [`NextError::NegativeYearValue`] if the year value would be negative. (Year specifiers
+    ///   have lower bounds. See the [table](crate#table) for more information.)
+    /// - [`NextError::SemLevelNotInFormat`] if the specifier of `level` is not in format.
+    /// - [`NextError::NotPreRelease`] or [`NextError::NoNextPreReleaseLabel`] if `level` refers to
+    ///   the pre-release specifier and the date has not advanced. (See [`next`](struct.Version.html#method.next)
+    ///   for details.)
+    /// - [`NextError::ValueOverflow`] if an incremented value would be greater than
+    ///   [`SpecValue::MAX`](crate::SpecValue).
+    pub fn next(&self, date: impl Into<DateTime>, level: CalSemLevel) -> Result<Self, NextError> {
+        self.next_base(date.into(), level, true, false)
+    }
+
+    /// Same as [`next`](struct.Version.html#method.next-2), but the new version has the build
+    /// metadata `metadata`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nextver::prelude::*;
+    ///
+    /// let date = Date::explicit(2024, 2, 23)?;
+    /// let cur = CalSem::new_version("<YYYY>.<MM>.<PATCH><META>", "2024.2.3+ci.41")?;
+    /// let next = cur.next_with_metadata(date, CalSemLevel::Patch, "ci.42")?;
+    /// assert_eq!("2024.2.4+ci.42", &next.to_string());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result::Err`] of any error that [`next`](struct.Version.html#method.next-2) or
+    /// [`Self::with_metadata`] returns.
+    pub fn next_with_metadata(
+        &self,
+        date: impl Into<DateTime>,
+        level: CalSemLevel,
+        metadata: &str,
+    ) -> Result<Self, NextError> {
+        self.next(date, level)?.with_metadata(Some(metadata))
+    }
+
+    /// Like [`next`](struct.Version.html#method.next-2), but the new version is a pre-release of
+    /// it, starting at the first label with a number of zero. See
+    /// [pre-releases](crate#pre-releases).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nextver::prelude::*;
+    ///
+    /// let date = Date::explicit(2024, 10, 1)?;
+    /// let cur = CalSem::new_version("<YYYY>.<0M>.<PATCH><PRE>", "2024.09.3")?;
+    /// let next = cur.next_pre_release(date, CalSemLevel::Patch)?;
+    /// assert_eq!("2024.10.0-alpha.0", &next.to_string());
+    /// assert!(cur < next);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result::Err`] of...
+    ///
+    /// - [`NextError::PreReleaseLevel`] if `level` is [`CalSemLevel::PreRelease`],
+    ///   [`CalSemLevel::PreReleaseLabel`], or [`CalSemLevel::FinalRelease`].
+    /// - [`NextError::SemLevelNotInFormat`] if the specifier of `level` or the pre-release
+    ///   specifier is not in format.
+    /// - Any error that [`next`](struct.Version.html#method.next-2) returns.
+    pub fn next_pre_release(
+        &self,
+        date: impl Into<DateTime>,
+        level: CalSemLevel,
+    ) -> Result<Self, NextError> {
+        if level.spec().is_pre_release() {
+            return Err(NextError::PreReleaseLevel);
+        }
+        self.next_base(date.into(), level, true, true)
+    }
+
+    /// Same as [`next`](struct.Version.html#method.next-2), but without checking if `date` is after
+    /// the date in this version.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`next`](struct.Version.html#method.next-2), but without
+    /// [`NextError::NewDateIsBefore`].
+    pub fn next_unchecked(
+        &self,
+        date: impl Into<DateTime>,
+        level: CalSemLevel,
+    ) -> Result<Self, NextError> {
+        self.next_base(date.into(), level, false, false)
+    }
+}
+
+impl<S: Scheme> Display for Version<'_, S> {
+    /// Returns the rendered version string
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // tokens between the start and end of an absent optional segment are not rendered
+        let mut absent = false;
+        for token in &self.tokens {
+            match token {
+                VersionToken::OptionalStart { present } => absent = !present,
+                VersionToken::OptionalEnd => absent = false,
+                _ if absent => {}
+                _ => write!(f, "{token}")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<S: Scheme> serde::Serialize for Version<'_, S> {
+    /// Serializes a version as its version string.
+    fn serialize<Ser: serde::Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// A version string can only be parsed with a format, so a [`Format`] reference is the seed for
+/// deserializing a [`Version`].
+///
+/// # Example
+///
+/// ```
+/// use nextver::prelude::*;
+/// use serde::de::DeserializeSeed;
+///
+/// let format = Sem::new_format("<MAJOR>.<MINOR>.<PATCH>")?;
+/// let mut deserializer = serde_json::Deserializer::from_str(r#""1.2.3""#);
+/// let version = (&format).deserialize(&mut deserializer)?;
+/// assert_eq!(format.new_version("1.2.3")?, version);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "serde")]
+impl<'de, S: Scheme> serde::de::DeserializeSeed<'de> for &Format<'_, S> {
+    type Value = Version<'static, S>;
+
+    fn deserialize<D: serde::Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        let version_str = <String as serde::Deserialize>::deserialize(deserializer)?;
+        self.new_version(&version_str)
+            .map(Version::into_owned)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::specifier::SemSpecifier;
 
     #[test]
     fn test_major_minor_patch_parse() {
@@ -705,7 +2937,183 @@ mod tests {
             ("100", true),
         ];
 
-        for (version_str, passes) in &args {
+        for (version_str, passes) in &args {
+            let format = Cal::new_format(format_str).unwrap();
+            let version = Version::parse(version_str, &format);
+            if *passes {
+                assert!(version.is_ok());
+            } else {
+                assert!(matches!(
+                    version,
+                    Err(VersionError::VersionFormatMismatch { .. })
+                ));
+            }
+        }
+    }
+
+    /// test short year
+    #[test]
+    fn test_short_year_parse() {
+        let format_str = "<YY>";
+        let args = [
+            ("01", false),  // zero-padding disallowed
+            ("001", false), // zero-padding disallowed
+            ("0", true),
+            ("1", true),
+            ("10", true),
+            ("100", true),
+        ];
+
+        for (version_str, passes) in &args {
+            let format = Cal::new_format(format_str).unwrap();
+            let version = Version::parse(version_str, &format);
+            if *passes {
+                assert!(version.is_ok());
+            } else {
+                assert!(matches!(
+                    version,
+                    Err(VersionError::VersionFormatMismatch { .. })
+                ));
+            }
+        }
+    }
+
+    /// test zero-padded year
+    #[test]
+    fn test_zp_year_parse() {
+        let format_str = "<0Y>";
+        let args = [
+            ("0", false), // must be 2-digits
+            ("1", false), // must be 2-digits
+            ("00", true),
+            ("01", true),
+            ("10", true),
+            ("100", true),
+        ];
+
+        for (version_str, passes) in &args {
+            let format = Cal::new_format(format_str).unwrap();
+            let version = Version::parse(version_str, &format);
+            if *passes {
+                assert!(version.is_ok());
+            } else {
+                assert!(matches!(
+                    version,
+                    Err(VersionError::VersionFormatMismatch { .. })
+                ));
+            }
+        }
+    }
+
+    // test zero-padded week
+    #[test]
+    fn test_zp_week_parse() {
+        let args = [
+            ("<YYYY>.<0W>", "2024.0", false),   // must be two-digit
+            ("<YYYY>.<0W>", "2024.122", false), // must be two-digit
+            ("<YYYY>.<0W>", "2024.00", true),   // there is a week zero
+            ("<YYYY>.<0W>", "2024.01", true),
+            ("<YYYY>.<0W>", "2024.10", true),
+            ("<YYYY>.<0W>", "2024.12", true),
+        ];
+
+        for (format_str, version_str, passes) in &args {
+            let format = Cal::new_format(format_str).unwrap();
+            let version = Version::parse(version_str, &format);
+            if *passes {
+                assert!(version.is_ok());
+            } else {
+                assert!(matches!(
+                    version,
+                    Err(VersionError::VersionFormatMismatch { .. })
+                ));
+            }
+        }
+    }
+
+    // test short week
+    #[test]
+    fn test_short_week_parse() {
+        let args = [
+            ("<YYYY>.<WW>", "2024.01", false),  // zero-padding disallowed
+            ("<YYYY>.<WW>", "2024.00", false),  // zero-padding disallowed
+            ("<YYYY>.<WW>", "2024.122", false), // must be two-digit
+            ("<YYYY>.<WW>", "2024.0", true),
+            ("<YYYY>.<WW>", "2024.10", true),
+            ("<YYYY>.<WW>", "2024.12", true),
+        ];
+
+        for (format_str, version_str, passes) in &args {
+            let format = Cal::new_format(format_str).unwrap();
+            let version = Version::parse(version_str, &format);
+            if *passes {
+                assert!(version.is_ok());
+            } else {
+                assert!(matches!(
+                    version,
+                    Err(VersionError::VersionFormatMismatch { .. })
+                ));
+            }
+        }
+    }
+
+    /// test zero-padded month and day (same characteristics: no month/day 0, 2 digits).
+    #[test]
+    fn test_zp_month_day_parse() {
+        let args = [
+            // month
+            ("<YYYY>.<0M>", "2024.0", false),   // must be two-digit
+            ("<YYYY>.<0M>", "2024.00", false),  // no month 0
+            ("<YYYY>.<0M>", "2024.122", false), // must be two-digit
+            ("<YYYY>.<0M>", "2024.01", true),
+            ("<YYYY>.<0M>", "2024.10", true),
+            ("<YYYY>.<0M>", "2024.12", true),
+            // day
+            ("<YYYY>.<MM>.<0D>", "2024.1.0", false), // must be two-digit
+            ("<YYYY>.<MM>.<0D>", "2024.1.00", false), // no day 0
+            ("<YYYY>.<MM>.<0D>", "2024.1.122", false), // must be two-digit
+            ("<YYYY>.<MM>.<0D>", "2024.1.01", true),
+            ("<YYYY>.<MM>.<0D>", "2024.1.10", true),
+            ("<YYYY>.<MM>.<0D>", "2024.1.12", true),
+        ];
+
+        for (format_str, version_str, passes) in &args {
+            let format = Cal::new_format(format_str).unwrap();
+            let version = Version::parse(version_str, &format);
+            if *passes {
+                assert!(version.is_ok());
+            } else {
+                assert!(matches!(
+                    version,
+                    Err(VersionError::VersionFormatMismatch { .. })
+                ));
+            }
+        }
+    }
+
+    /// test short month, day, and week. (same characteristics: no month/day 0, 1-2 digits).
+    #[test]
+    fn test_short_mdw_parse() {
+        let args = [
+            // month
+            ("<YYYY>.<MM>", "2024.0", false),   // no month 0
+            ("<YYYY>.<MM>", "2024.00", false),  // zero-padding disallowed
+            ("<YYYY>.<MM>", "2024.01", false),  // zero-padding disallowed
+            ("<YYYY>.<MM>", "2024.122", false), // must be two-digit
+            ("<YYYY>.<MM>", "2024.1", true),
+            ("<YYYY>.<MM>", "2024.10", true),
+            ("<YYYY>.<MM>", "2024.12", true),
+            // day
+            ("<YYYY>.<MM>.<DD>", "2024.1.0", false), // no month 0
+            ("<YYYY>.<MM>.<DD>", "2024.1.00", false), // zero-padding disallowed
+            ("<YYYY>.<MM>.<DD>", "2024.1.01", false), // zero-padding disallowed
+            ("<YYYY>.<MM>.<DD>", "2024.1.122", false), // must be two-digit
+            ("<YYYY>.<MM>.<DD>", "2024.1.1", true),
+            ("<YYYY>.<MM>.<DD>", "2024.1.10", true),
+            ("<YYYY>.<MM>.<DD>", "2024.1.12", true),
+        ];
+
+        for (format_str, version_str, passes) in &args {
             let format = Cal::new_format(format_str).unwrap();
             let version = Version::parse(version_str, &format);
             if *passes {
@@ -719,235 +3127,878 @@ mod tests {
         }
     }
 
-    /// test short year
     #[test]
-    fn test_short_year_parse() {
-        let format_str = "<YY>";
+    fn test_month_name_parse() {
         let args = [
-            ("01", false),  // zero-padding disallowed
-            ("001", false), // zero-padding disallowed
-            ("0", true),
-            ("1", true),
-            ("10", true),
-            ("100", true),
+            ("<YYYY>-<MON>", "2024-Jan", Some(1)),
+            ("<YYYY>-<MON>", "2024-Oct", Some(10)),
+            ("<YYYY>-<MON>", "2024-Dec", Some(12)),
+            ("<YYYY>-<MON>", "2024-oct", None), // case-sensitive
+            ("<YYYY>-<MON>", "2024-October", None),
+            ("<YYYY>-<MON>", "2024-10", None),
+            ("<YYYY>-<Month>", "2024-May", Some(5)),
+            ("<YYYY>-<Month>", "2024-September", Some(9)),
+            ("<YYYY>-<Month>", "2024-Sep", None),
+            ("<YYYY><Month><DD>", "2024June3", Some(6)),
         ];
 
-        for (version_str, passes) in &args {
+        for (format_str, version_str, expected_month) in args {
             let format = Cal::new_format(format_str).unwrap();
             let version = Version::parse(version_str, &format);
-            if *passes {
-                assert!(version.is_ok());
-            } else {
-                assert!(matches!(
+            match expected_month {
+                Some(month) => {
+                    let version = version.unwrap();
+                    let value = version.tokens.iter().find_map(|token| match token {
+                        VersionToken::Value { value, spec, .. } if spec.value_names().is_some() => {
+                            Some(*value)
+                        }
+                        _ => None,
+                    });
+                    assert_eq!(Some(month), value);
+                    assert_eq!(version_str, version.to_string());
+                }
+                None => assert!(matches!(
                     version,
                     Err(VersionError::VersionFormatMismatch { .. })
-                ));
+                )),
             }
         }
     }
 
-    /// test zero-padded year
     #[test]
-    fn test_zp_year_parse() {
-        let format_str = "<0Y>";
-        let args = [
-            ("0", false), // must be 2-digits
-            ("1", false), // must be 2-digits
-            ("00", true),
-            ("01", true),
-            ("10", true),
-            ("100", true),
+    fn test_month_name_ordering() {
+        let format = CalSem::new_format("<YY>.<MON>.<PATCH>").unwrap();
+        let sep = Version::parse("24.Sep.3", &format).unwrap();
+        let oct = Version::parse("24.Oct.0", &format).unwrap();
+        let aug = Version::parse("24.Aug.9", &format).unwrap();
+        assert!(aug < sep);
+        assert!(sep < oct);
+    }
+
+    #[test]
+    fn test_epoch_ordering() {
+        let format = Sem::new_format("[<EPOCH>!]<MAJOR>.<MINOR>.<PATCH>").unwrap();
+        let old = Version::parse("2024.9.3", &format).unwrap();
+        let new = Version::parse("1!1.0.0", &format).unwrap();
+        let newer = Version::parse("1!1.0.1", &format).unwrap();
+        assert!(old < new);
+        assert!(new < newer);
+        assert_eq!(old, Version::parse("0!2024.9.3", &format).unwrap());
+    }
+
+    #[test]
+    fn test_unicode_literal() {
+        let format_str = "👍<MAJOR>👯‍♀️";
+        let version_str = "👍1👯‍♀️";
+        let format = Sem::new_format(format_str).unwrap();
+        let version = Version::parse(version_str, &format);
+        assert!(version.is_ok());
+    }
+
+    #[test]
+    fn test_date_from_str() {
+        let date_strs = [
+            ("2021-02-03", true),
+            ("2021-2-3", true),
+            ("2021-02-30", false), // February 30th doesn't exist
         ];
 
-        for (version_str, passes) in &args {
-            let format = Cal::new_format(format_str).unwrap();
-            let version = Version::parse(version_str, &format);
+        for (date_str, passes) in &date_strs {
+            let date = Date::from_str(date_str);
             if *passes {
-                assert!(version.is_ok());
+                assert!(date.is_ok());
             } else {
-                assert!(matches!(
-                    version,
-                    Err(VersionError::VersionFormatMismatch { .. })
-                ));
+                assert!(matches!(date, Err(DateError::UnparseableDate { .. })));
             }
         }
     }
 
-    // test zero-padded week
     #[test]
-    fn test_zp_week_parse() {
-        let args = [
-            ("<YYYY>.<0W>", "2024.0", false),   // must be two-digit
-            ("<YYYY>.<0W>", "2024.122", false), // must be two-digit
-            ("<YYYY>.<0W>", "2024.00", true),   // there is a week zero
-            ("<YYYY>.<0W>", "2024.01", true),
-            ("<YYYY>.<0W>", "2024.10", true),
-            ("<YYYY>.<0W>", "2024.12", true),
+    fn test_date_explicit() {
+        let date_strs = [
+            (2021i32, 2u32, 3u32, true),
+            (2021i32, 2u32, 30u32, false), // February 30th doesn't exist
         ];
 
-        for (format_str, version_str, passes) in &args {
-            let format = Cal::new_format(format_str).unwrap();
-            let version = Version::parse(version_str, &format);
+        for (year, month, day, passes) in date_strs {
+            let date = Date::explicit(year, month, day);
+            if passes {
+                assert!(date.is_ok());
+            } else {
+                assert!(matches!(date, Err(DateError::InvalidDateArguments { .. })));
+            }
+        }
+    }
+
+    #[test]
+    fn test_date_time_from_str() {
+        let date_time_strs = [
+            ("2021-02-03T14:05", true),
+            ("2021-02-03T14:05:59", true),
+            ("2021-02-03T24:00", false), // hour 24 doesn't exist
+            ("2021-02-03", false),       // no time
+        ];
+
+        for (date_time_str, passes) in &date_time_strs {
+            let date_time = DateTime::from_str(date_time_str);
             if *passes {
-                assert!(version.is_ok());
+                assert!(date_time.is_ok());
+            } else {
+                assert!(matches!(date_time, Err(DateError::UnparseableDate { .. })));
+            }
+        }
+    }
+
+    #[test]
+    fn test_date_time_explicit() {
+        let args = [
+            (2021, 2, 3, 14, 5, true),
+            (2021, 2, 30, 14, 5, false), // February 30th doesn't exist
+            (2021, 2, 3, 24, 0, false),  // hour 24 doesn't exist
+            (2021, 2, 3, 14, 60, false), // minute 60 doesn't exist
+        ];
+
+        for (year, month, day, hour, minute, passes) in args {
+            let date_time = DateTime::explicit(year, month, day, hour, minute);
+            if passes {
+                assert!(date_time.is_ok());
             } else {
                 assert!(matches!(
-                    version,
-                    Err(VersionError::VersionFormatMismatch { .. })
+                    date_time,
+                    Err(DateError::InvalidDateTimeArguments { .. })
                 ));
             }
         }
     }
 
-    // test short week
     #[test]
-    fn test_short_week_parse() {
+    fn test_date_time_from_date() {
+        let date = Date::explicit(2021, 2, 3).unwrap();
+        let expected = DateTime::explicit(2021, 2, 3, 0, 0).unwrap();
+        assert_eq!(expected, DateTime::from(date));
+    }
+
+    #[test]
+    fn test_cal_next_date_time() {
         let args = [
-            ("<YYYY>.<WW>", "2024.01", false),  // zero-padding disallowed
-            ("<YYYY>.<WW>", "2024.00", false),  // zero-padding disallowed
-            ("<YYYY>.<WW>", "2024.122", false), // must be two-digit
-            ("<YYYY>.<WW>", "2024.0", true),
-            ("<YYYY>.<WW>", "2024.10", true),
-            ("<YYYY>.<WW>", "2024.12", true),
+            (
+                "<YYYY>.<0M>.<0D>.<0H><0MI>",
+                "2024.10.18.0900",
+                DateTime::explicit(2024, 10, 18, 14, 0),
+                Ok("2024.10.18.1400"),
+            ),
+            (
+                "<YY>.<DOY>.<HH>",
+                "24.291.9",
+                DateTime::explicit(2024, 10, 18, 14, 30),
+                Ok("24.292.14"),
+            ),
+            (
+                "<YYYY>.<0M>.<0D>.<0H><0MI>",
+                "2024.10.18.1400",
+                DateTime::explicit(2024, 10, 18, 14, 0),
+                Err(NextError::NoCalendarChange),
+            ),
+            (
+                "<YYYY>.<0M>.<0D>.<0H><0MI>",
+                "2024.10.18.1400",
+                DateTime::explicit(2024, 10, 18, 13, 59),
+                Err(NextError::NewDateIsBefore),
+            ),
+        ];
+
+        for (format_str, version_str, date_time, expected) in args {
+            let format = Cal::new_format(format_str).unwrap();
+            let version = Version::parse(version_str, &format).unwrap();
+            let next = version.next(date_time.unwrap()).map(|v| v.to_string());
+            assert_eq!(expected.map(str::to_string), next);
+        }
+    }
+
+    #[test]
+    fn test_calsem_next_date_time() {
+        let format = CalSem::new_format("<YYYY>.<0M>.<0D>.<0H>.<PATCH>").unwrap();
+        let version = Version::parse("2024.10.18.09.3", &format).unwrap();
+
+        // a new hour resets the patch
+        let date_time = DateTime::explicit(2024, 10, 18, 14, 0).unwrap();
+        let next = version.next(date_time, CalSemLevel::Patch).unwrap();
+        assert_eq!("2024.10.18.14.0", next.to_string());
+
+        // the same hour increments it
+        let date_time = DateTime::explicit(2024, 10, 18, 14, 59).unwrap();
+        let next = next.next(date_time, CalSemLevel::Patch).unwrap();
+        assert_eq!("2024.10.18.14.1", next.to_string());
+
+        // a date alone refers to midnight
+        let date = Date::explicit(2024, 10, 19).unwrap();
+        let next = next.next(date, CalSemLevel::Patch).unwrap();
+        assert_eq!("2024.10.19.00.0", next.to_string());
+    }
+
+    #[test]
+    fn test_sem_next() {
+        let args = [
+            ("<MAJOR>.<MINOR>.<PATCH>", "1.2.3", SemLevel::Major, "2.0.0"),
+            ("<MAJOR>.<MINOR>.<PATCH>", "1.2.3", SemLevel::Minor, "1.3.0"),
+            ("<MAJOR>.<MINOR>.<PATCH>", "1.2.3", SemLevel::Patch, "1.2.4"),
+            (
+                "<MAJOR>.<MINOR>.<PATCH>.<REVISION>",
+                "1.2.3.4",
+                SemLevel::Major,
+                "2.0.0.0",
+            ),
+            (
+                "<MAJOR>.<MINOR>.<PATCH>.<REVISION>",
+                "1.2.3.4",
+                SemLevel::Minor,
+                "1.3.0.0",
+            ),
+            (
+                "<MAJOR>.<MINOR>.<PATCH>.<REVISION>",
+                "1.2.3.4",
+                SemLevel::Patch,
+                "1.2.4.0",
+            ),
+            (
+                "<MAJOR>.<MINOR>.<PATCH>.<REVISION>",
+                "1.2.3.4",
+                SemLevel::Revision,
+                "1.2.3.5",
+            ),
+            ("<MAJOR>.<MINOR>", "1.2", SemLevel::Major, "2.0"),
+            ("<MAJOR>.<MINOR>", "1.2", SemLevel::Minor, "1.3"),
+            ("<MAJOR>", "1", SemLevel::Major, "2"),
+        ];
+
+        for (format_str, version_str, level, expected_str) in args {
+            let format = Sem::new_format(format_str).unwrap();
+            let version = Version::parse(version_str, &format).unwrap();
+            let next = version.next(level).unwrap();
+            assert_eq!(expected_str, next.to_string());
+        }
+    }
+
+    #[test]
+    fn test_sem_next_by_and_to() {
+        let format = Sem::new_format("<MAJOR>.<MINOR>.<PATCH>").unwrap();
+        let version = Version::parse("3.2.1", &format).unwrap();
+        let not_greater = |next: &str| {
+            Err(NextError::NotGreater {
+                current: "3.2.1".to_string(),
+                next: next.to_string(),
+            })
+        };
+
+        let next = |result: Result<Version<Sem>, NextError>| result.map(|v| v.to_string());
+        assert_eq!(
+            Ok("5.0.0".to_string()),
+            next(version.next_by(SemLevel::Major, 2))
+        );
+        assert_eq!(
+            Ok("3.2.11".to_string()),
+            next(version.next_by(SemLevel::Patch, 10))
+        );
+        assert_eq!(
+            not_greater("3.2.1"),
+            next(version.next_by(SemLevel::Patch, 0))
+        );
+        assert_eq!(
+            Ok("5.0.0".to_string()),
+            next(version.next_to(SemLevel::Major, 5))
+        );
+        assert_eq!(
+            not_greater("3.1.0"),
+            next(version.next_to(SemLevel::Minor, 1))
+        );
+        assert_eq!(
+            Ok("3.1.0".to_string()),
+            next(version.next_to_unchecked(SemLevel::Minor, 1))
+        );
+        assert_eq!(
+            Err(NextError::SemLevelNotInFormat {
+                spec: "<REVISION>".to_string()
+            }),
+            next(version.next_to(SemLevel::Revision, 1))
+        );
+
+        let format = Sem::new_format("<MAJOR>.<MINOR>.<PATCH><PRE>").unwrap();
+        let version = Version::parse("3.2.1-beta.2", &format).unwrap();
+        assert_eq!(
+            Ok("3.2.1-beta.5".to_string()),
+            next(version.next_by(SemLevel::PreRelease, 3))
+        );
+        assert_eq!(
+            Ok("3.2.1-beta.0".to_string()),
+            next(version.next_to_unchecked(SemLevel::PreRelease, 0))
+        );
+        for level in [SemLevel::PreReleaseLabel, SemLevel::FinalRelease] {
+            assert_eq!(
+                Err(NextError::LevelHasNoValue),
+                next(version.next_by(level, 1))
+            );
+        }
+    }
+
+    #[test]
+    fn test_sem_prev() {
+        let unknown = |spec: &str| {
+            Err(NextError::PrevValueUnknown {
+                spec: spec.to_string(),
+            })
+        };
+        let underflow = |spec: &str| {
+            Err(NextError::ValueUnderflow {
+                spec: spec.to_string(),
+            })
+        };
+
+        let args = [
+            (
+                "<MAJOR>.<MINOR>.<PATCH>",
+                "1.2.3",
+                SemLevel::Patch,
+                Ok("1.2.2"),
+            ),
+            (
+                "<MAJOR>.<MINOR>.<PATCH>",
+                "1.2.0",
+                SemLevel::Patch,
+                underflow("<PATCH>"),
+            ),
+            (
+                "<MAJOR>.<MINOR>.<PATCH>",
+                "1.3.0",
+                SemLevel::Minor,
+                unknown("<PATCH>"),
+            ),
+            ("<MAJOR>.<MINOR>", "1.3", SemLevel::Minor, Ok("1.2")),
+            (
+                "<MAJOR>.<MINOR>+<BUILD>",
+                "1.3+7",
+                SemLevel::Minor,
+                Ok("1.2+6"),
+            ),
+            ("<EPOCH>!<MAJOR>", "2!3", SemLevel::Major, Ok("2!2")),
+            (
+                "<EPOCH>!<MAJOR>",
+                "2!0",
+                SemLevel::Epoch,
+                unknown("<MAJOR>"),
+            ),
+            (
+                "<MAJOR>.<MINOR>.<PATCH>",
+                "1.2.3",
+                SemLevel::Revision,
+                Err(NextError::SemLevelNotInFormat {
+                    spec: "<REVISION>".to_string(),
+                }),
+            ),
+            (
+                "<MAJOR><PRE>",
+                "1-beta.3",
+                SemLevel::PreRelease,
+                Ok("1-beta.2"),
+            ),
+            (
+                "<MAJOR><PRE>",
+                "1",
+                SemLevel::PreRelease,
+                Err(NextError::NotPreRelease),
+            ),
+            (
+                "<MAJOR><PRE>",
+                "1-rc.0",
+                SemLevel::PreReleaseLabel,
+                unknown("<PRE>"),
+            ),
+            (
+                "<MAJOR><PRE>",
+                "1",
+                SemLevel::FinalRelease,
+                unknown("<PRE>"),
+            ),
+            (
+                "<MAJOR><PRE>",
+                "2-alpha.0",
+                SemLevel::Major,
+                unknown("<PRE>"),
+            ),
+        ];
+
+        for (format_str, version_str, level, expected) in args {
+            let format = Sem::new_format(format_str).unwrap();
+            let version = Version::parse(version_str, &format).unwrap();
+            let prev = version.prev(level).map(|prev| prev.to_string());
+            assert_eq!(expected.map(str::to_string), prev);
+        }
+    }
+
+    #[test]
+    fn test_cal_prev() {
+        let args = [
+            ("<YYYY>", "2024", Ok("2023")),
+            ("<YY>.<Q>", "24.1", Ok("23.4")),
+            ("<YYYY>.<0M>.<0D>", "2024.03.01", Ok("2024.02.29")),
+            ("<YYYY>.<MON>", "2024.Jan", Ok("2023.Dec")),
+            ("<YYYY>.<DOY>", "2024.1", Ok("2023.365")),
+            // 2024-01-07 is the first sunday of 2024
+            ("<YYYY>.<WW>", "2024.1", Ok("2024.0")),
+            ("<YYYY>.<WW>", "2024.0", Ok("2023.53")), // 2023-12-31 is a sunday
+            ("<GGGG>.<IW>", "2025.1", Ok("2024.52")),
+            (
+                "<YYYY>.<0M>.<0D>.<0H><0MI>",
+                "2024.10.18.0000",
+                Ok("2024.10.17.2359"),
+            ),
+            ("[<EPOCH>!]<YYYY>.<BUILD>", "1!2024.5", Ok("1!2023.4")),
+            (
+                "<YYYY>.<BUILD>",
+                "2024.0",
+                Err(NextError::ValueUnderflow {
+                    spec: "<BUILD>".to_string(),
+                }),
+            ),
+            (
+                "<YYYY>.<0M>.<0D>",
+                "2024.02.30",
+                Err(NextError::InvalidVersionDate),
+            ),
+            (
+                "<YYYY>",
+                "0",
+                Err(NextError::NegativeYearValue { year: -1 }),
+            ),
+        ];
+
+        for (format_str, version_str, expected) in args {
+            let format = Cal::new_format(format_str).unwrap();
+            let version = Version::parse(version_str, &format).unwrap();
+            let prev = version.prev().map(|prev| prev.to_string());
+            assert_eq!(expected.map(str::to_string), prev);
+        }
+    }
+
+    #[test]
+    fn test_accessors() {
+        let format = Sem::new_format("<MAJOR>.<MINOR>[.<PATCH>]<PRE><META>").unwrap();
+        let version = Version::parse("1.2-beta.3+ci.42", &format).unwrap();
+        assert_eq!(Some(2), version.get(SemLevel::Minor));
+        assert_eq!(Some(0), version.get(SemLevel::Patch));
+        assert_eq!(Some(3), version.get(SemLevel::PreRelease));
+        assert_eq!(None, version.get(SemLevel::Epoch));
+        assert_eq!(Some(("beta", 3)), version.pre_release());
+        assert_eq!(Some("ci.42"), version.metadata());
+
+        let parts = version.parts().collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                VersionPart::Value {
+                    spec: &SemSpecifier::Major,
+                    value: 1
+                },
+                VersionPart::Literal("."),
+                VersionPart::Value {
+                    spec: &SemSpecifier::Minor,
+                    value: 2
+                },
+                VersionPart::PreRelease {
+                    spec: &SemSpecifier::PreRelease,
+                    value: Some(("beta", 3))
+                },
+                VersionPart::Metadata {
+                    spec: &SemSpecifier::Metadata,
+                    value: Some("ci.42")
+                },
+            ],
+            parts
+        );
+
+        let format = Cal::new_format("<0Y>.<Q>.<0M>.<0D>.<HH>").unwrap();
+        let version = Version::parse("24.3.09.03.14", &format).unwrap();
+        assert_eq!(
+            (Some(2024), Some(3), Some(9), Some(3), Some(14), None),
+            (
+                version.year(),
+                version.quarter(),
+                version.month(),
+                version.day(),
+                version.hour(),
+                version.minute()
+            )
+        );
+
+        let format = CalSem::new_format("<GGGG>.<0IW>.<PATCH>").unwrap();
+        let version = Version::parse("2025.01.4", &format).unwrap();
+        assert_eq!(
+            (None, Some(2025), Some(1), Some(4)),
+            (
+                version.year(),
+                version.iso_year(),
+                version.iso_week(),
+                version.get(CalSemLevel::Patch)
+            )
+        );
+    }
+
+    #[test]
+    fn test_into_owned() {
+        let (format, version) = {
+            let format_str = String::from("v<MAJOR>.<MINOR>[-<PATCH>]");
+            let version_str = String::from("v1.2-3");
+            let format = Sem::new_format(&format_str).unwrap().into_owned();
+            let version = format.new_version(&version_str).unwrap().into_owned();
+            (format, version)
+        };
+        assert_eq!("v<MAJOR>.<MINOR>[-<PATCH>]", format.to_string());
+        assert_eq!(format.new_version("v1.2-3").unwrap(), version);
+        assert_eq!("v1.3", version.next(SemLevel::Minor).unwrap().to_string());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        use serde::de::DeserializeSeed;
+
+        let format: Format<Sem> = serde_json::from_str(r#""v<MAJOR>.<MINOR>[-<PATCH>]""#).unwrap();
+        assert_eq!(
+            r#""v<MAJOR>.<MINOR>[-<PATCH>]""#,
+            serde_json::to_string(&format).unwrap()
+        );
+        assert!(serde_json::from_str::<Format<Sem>>(r#""<YYYY>""#).is_err());
+
+        let mut deserializer = serde_json::Deserializer::from_str(r#""v1.2-3""#);
+        let version = (&format).deserialize(&mut deserializer).unwrap();
+        assert_eq!(format.new_version("v1.2-3").unwrap(), version);
+        assert_eq!(r#""v1.2-3""#, serde_json::to_string(&version).unwrap());
+        let mut deserializer = serde_json::Deserializer::from_str(r#""1.2.3""#);
+        assert!((&format).deserialize(&mut deserializer).is_err());
+
+        let date: Date = serde_json::from_str(r#""2024-02-03""#).unwrap();
+        assert_eq!(Date::explicit(2024, 2, 3).unwrap(), date);
+        assert_eq!(r#""2024-02-03""#, serde_json::to_string(&date).unwrap());
+        assert!(serde_json::from_str::<Date>(r#""2024-02-30""#).is_err());
+
+        assert_eq!(
+            r#""pre-release-label""#,
+            serde_json::to_string(&SemLevel::PreReleaseLabel).unwrap()
+        );
+        assert_eq!(
+            CalSemLevel::FinalRelease,
+            serde_json::from_str(r#""final-release""#).unwrap()
+        );
+    }
+    #[test]
+    fn test_cal_next() {
+        let args = [
+            (
+                "<YYYY>.<0M>.<0D>",
+                "2023.12.04",
+                Date::explicit(2024, 1, 1),
+                "2024.01.01",
+            ),
+            ("<YYYY>", "2023", Date::explicit(2024, 1, 1), "2024"),
+            (
+                "<YYYY>.<0W>",
+                "2023.01",
+                Date::explicit(2024, 1, 1),
+                "2024.00",
+            ),
+            (
+                "<YYYY>.<WW>",
+                "2023.1",
+                Date::explicit(2024, 1, 1),
+                "2024.0",
+            ),
+            // 2024-12-30 is in the first ISO week of 2025
+            (
+                "<GGGG>.<0IW>",
+                "2024.51",
+                Date::explicit(2024, 12, 30),
+                "2025.01",
+            ),
+            ("<YYYY>.<Q>", "2024.2", Date::explicit(2024, 7, 1), "2024.3"),
+            (
+                "<YYYY>-<MON>",
+                "2024-Sep",
+                Date::explicit(2024, 10, 2),
+                "2024-Oct",
+            ),
+            (
+                "<YYYY>-<Month>",
+                "2023-December",
+                Date::explicit(2024, 1, 1),
+                "2024-January",
+            ),
+            ("<YY>.<DOY>", "24.186", Date::explicit(2024, 7, 5), "24.187"),
+            (
+                "<YY>.<0DOY>",
+                "23.365",
+                Date::explicit(2024, 1, 1),
+                "24.001",
+            ),
+            // 2021-01-03 is in the last ISO week of 2020
+            (
+                "<GGGG>.<IW>",
+                "2020.52",
+                Date::explicit(2021, 1, 3),
+                "2020.53",
+            ),
         ];
 
-        for (format_str, version_str, passes) in &args {
+        for (format_str, version_str, date, expected_str) in args {
             let format = Cal::new_format(format_str).unwrap();
-            let version = Version::parse(version_str, &format);
-            if *passes {
-                assert!(version.is_ok());
-            } else {
-                assert!(matches!(
-                    version,
-                    Err(VersionError::VersionFormatMismatch { .. })
-                ));
-            }
+            let version = Version::parse(version_str, &format).unwrap();
+            let next = version.next(date.unwrap()).unwrap();
+            assert_eq!(expected_str, next.to_string());
         }
     }
 
-    /// test zero-padded month and day (same characteristics: no month/day 0, 2 digits).
     #[test]
-    fn test_zp_month_day_parse() {
+    fn test_calsem_next() {
         let args = [
-            // month
-            ("<YYYY>.<0M>", "2024.0", false),   // must be two-digit
-            ("<YYYY>.<0M>", "2024.00", false),  // no month 0
-            ("<YYYY>.<0M>", "2024.122", false), // must be two-digit
-            ("<YYYY>.<0M>", "2024.01", true),
-            ("<YYYY>.<0M>", "2024.10", true),
-            ("<YYYY>.<0M>", "2024.12", true),
-            // day
-            ("<YYYY>.<MM>.<0D>", "2024.1.0", false), // must be two-digit
-            ("<YYYY>.<MM>.<0D>", "2024.1.00", false), // no day 0
-            ("<YYYY>.<MM>.<0D>", "2024.1.122", false), // must be two-digit
-            ("<YYYY>.<MM>.<0D>", "2024.1.01", true),
-            ("<YYYY>.<MM>.<0D>", "2024.1.10", true),
-            ("<YYYY>.<MM>.<0D>", "2024.1.12", true),
+            (
+                "<YY>.<MON>.<PATCH>",
+                "24.Oct.1",
+                Date::explicit(2024, 10, 20),
+                CalSemLevel::Patch,
+                "24.Oct.2",
+            ),
+            (
+                "<YY>.<MON>.<PATCH>",
+                "24.Oct.1",
+                Date::explicit(2024, 11, 1),
+                CalSemLevel::Patch,
+                "24.Nov.0",
+            ),
+            (
+                "<YYYY>.<0M>.<0D>.<PATCH>",
+                "2023.12.04.123",
+                Date::explicit(2024, 1, 1),
+                CalSemLevel::Patch,
+                "2024.01.01.0",
+            ),
+            (
+                "<YYYY>.<0M>.<0D>.<PATCH>",
+                "2023.12.04.123",
+                Date::explicit(2023, 12, 4),
+                CalSemLevel::Patch,
+                "2023.12.04.124",
+            ),
+            (
+                "<YYYY>.<0M>.<DD>.<MINOR>.<PATCH>",
+                "2023.12.4.5.123",
+                Date::explicit(2024, 1, 1),
+                CalSemLevel::Minor,
+                "2024.01.1.0.0",
+            ),
+            (
+                "<YYYY>.<0M>.<DD>.<MINOR>.<PATCH>",
+                "2023.12.4.5.123",
+                Date::explicit(2023, 12, 4),
+                CalSemLevel::Minor,
+                "2023.12.4.6.0",
+            ),
+            (
+                "<YYYY>.<0M>.<DD>.<MINOR>.<PATCH>",
+                "2023.12.4.5.123",
+                Date::explicit(2023, 12, 4),
+                CalSemLevel::Patch,
+                "2023.12.4.5.124",
+            ),
+            (
+                "<YYYY>.Q<Q>.<PATCH>",
+                "2024.Q2.4",
+                Date::explicit(2024, 7, 1),
+                CalSemLevel::Patch,
+                "2024.Q3.0",
+            ),
+            (
+                "<YYYY>.Q<Q>.<PATCH>",
+                "2024.Q3.0",
+                Date::explicit(2024, 9, 30),
+                CalSemLevel::Patch,
+                "2024.Q3.1",
+            ),
+            (
+                "<GGGG>.<0IW>.<PATCH>",
+                "2024.52.3",
+                Date::explicit(2024, 12, 30),
+                CalSemLevel::Patch,
+                "2025.01.0",
+            ),
+            (
+                "<GGGG>.<0IW>.<PATCH>",
+                "2025.01.0",
+                Date::explicit(2025, 1, 5),
+                CalSemLevel::Patch,
+                "2025.01.1",
+            ),
         ];
 
-        for (format_str, version_str, passes) in &args {
-            let format = Cal::new_format(format_str).unwrap();
-            let version = Version::parse(version_str, &format);
-            if *passes {
-                assert!(version.is_ok());
-            } else {
-                assert!(matches!(
-                    version,
-                    Err(VersionError::VersionFormatMismatch { .. })
-                ));
-            }
+        for (format_str, version_str, date, level, expected_str) in args {
+            let format = CalSem::new_format(format_str).unwrap();
+            let version = Version::parse(version_str, &format).unwrap();
+            let next = version.next(date.unwrap(), level).unwrap();
+            assert_eq!(next.to_string(), expected_str);
         }
     }
 
-    /// test short month, day, and week. (same characteristics: no month/day 0, 1-2 digits).
     #[test]
-    fn test_short_mdw_parse() {
+    fn test_sem_not_in_format() {
         let args = [
-            // month
-            ("<YYYY>.<MM>", "2024.0", false),   // no month 0
-            ("<YYYY>.<MM>", "2024.00", false),  // zero-padding disallowed
-            ("<YYYY>.<MM>", "2024.01", false),  // zero-padding disallowed
-            ("<YYYY>.<MM>", "2024.122", false), // must be two-digit
-            ("<YYYY>.<MM>", "2024.1", true),
-            ("<YYYY>.<MM>", "2024.10", true),
-            ("<YYYY>.<MM>", "2024.12", true),
-            // day
-            ("<YYYY>.<MM>.<DD>", "2024.1.0", false), // no month 0
-            ("<YYYY>.<MM>.<DD>", "2024.1.00", false), // zero-padding disallowed
-            ("<YYYY>.<MM>.<DD>", "2024.1.01", false), // zero-padding disallowed
-            ("<YYYY>.<MM>.<DD>", "2024.1.122", false), // must be two-digit
-            ("<YYYY>.<MM>.<DD>", "2024.1.1", true),
-            ("<YYYY>.<MM>.<DD>", "2024.1.10", true),
-            ("<YYYY>.<MM>.<DD>", "2024.1.12", true),
+            ("<MAJOR>.<MINOR>", "1.2", SemLevel::Patch),
+            ("<MAJOR>", "1", SemLevel::Minor),
+            ("<MAJOR>", "1", SemLevel::Patch),
         ];
 
-        for (format_str, version_str, passes) in &args {
-            let format = Cal::new_format(format_str).unwrap();
-            let version = Version::parse(version_str, &format);
-            if *passes {
-                assert!(version.is_ok());
-            } else {
-                assert!(matches!(
-                    version,
-                    Err(VersionError::VersionFormatMismatch { .. })
-                ));
-            }
+        for (format_str, version_str, level) in args {
+            let format = Sem::new_format(format_str).unwrap();
+            let version = Version::parse(version_str, &format).unwrap();
+            let next = version.next(level);
+            assert!(matches!(next, Err(NextError::SemLevelNotInFormat { .. })));
         }
     }
 
     #[test]
-    fn test_unicode_literal() {
-        let format_str = "👍<MAJOR>👯‍♀️";
-        let version_str = "👍1👯‍♀️";
-        let format = Sem::new_format(format_str).unwrap();
-        let version = Version::parse(version_str, &format);
-        assert!(version.is_ok());
+    fn test_calsem_not_in_format() {
+        let format = CalSem::new_format("<YYYY>.<0M>.<0D>.<PATCH>").unwrap();
+        let version = Version::parse("2023.12.04.2", &format).unwrap();
+        let next = version.next(Date::explicit(2024, 1, 1).unwrap(), CalSemLevel::Minor);
+        assert!(matches!(next, Err(NextError::SemLevelNotInFormat { .. })));
     }
 
     #[test]
-    fn test_date_from_str() {
-        let date_strs = [
-            ("2021-02-03", true),
-            ("2021-2-3", true),
-            ("2021-02-30", false), // February 30th doesn't exist
+    fn test_calsem_revision_next() {
+        let args = [
+            (
+                "<YYYY>.<MINOR>.<PATCH>.<REVISION>",
+                "2024.1.2.3",
+                Date::explicit(2024, 6, 1),
+                CalSemLevel::Minor,
+                "2024.2.0.0",
+            ),
+            (
+                "<YYYY>.<MINOR>.<PATCH>.<REVISION>",
+                "2024.1.2.3",
+                Date::explicit(2024, 6, 1),
+                CalSemLevel::Patch,
+                "2024.1.3.0",
+            ),
+            (
+                "<YYYY>.<MINOR>.<PATCH>.<REVISION>",
+                "2024.1.2.3",
+                Date::explicit(2024, 6, 1),
+                CalSemLevel::Revision,
+                "2024.1.2.4",
+            ),
+            (
+                "<YYYY>.<MINOR>.<PATCH>.<REVISION>",
+                "2024.1.2.3",
+                Date::explicit(2025, 6, 1),
+                CalSemLevel::Revision,
+                "2025.0.0.0",
+            ),
         ];
 
-        for (date_str, passes) in &date_strs {
-            let date = Date::from_str(date_str);
-            if *passes {
-                assert!(date.is_ok());
-            } else {
-                assert!(matches!(date, Err(DateError::UnparseableDate { .. })));
-            }
+        for (format_str, version_str, date, level, expected_str) in args {
+            let format = CalSem::new_format(format_str).unwrap();
+            let version = Version::parse(version_str, &format).unwrap();
+            let next = version.next(date.unwrap(), level).unwrap();
+            assert_eq!(next.to_string(), expected_str);
         }
     }
 
     #[test]
-    fn test_date_explicit() {
-        let date_strs = [
-            (2021i32, 2u32, 3u32, true),
-            (2021i32, 2u32, 30u32, false), // February 30th doesn't exist
-        ];
+    fn test_epoch_next() {
+        let format = Sem::new_format("<EPOCH>!<MAJOR>.<MINOR>").unwrap();
+        let version = Version::parse("1!2.3", &format).unwrap();
+        assert_eq!("1!3.0", version.next(SemLevel::Major).unwrap().to_string());
+        assert_eq!("1!2.4", version.next(SemLevel::Minor).unwrap().to_string());
+        assert_eq!("2!0.0", version.next(SemLevel::Epoch).unwrap().to_string());
 
-        for (year, month, day, passes) in date_strs {
-            let date = Date::explicit(year, month, day);
-            if passes {
-                assert!(date.is_ok());
-            } else {
-                assert!(matches!(date, Err(DateError::InvalidDateArguments { .. })));
-            }
-        }
+        let format = Sem::new_format("<MAJOR>.<MINOR>").unwrap();
+        let version = Version::parse("2.3", &format).unwrap();
+        assert_eq!(
+            Err(NextError::SemLevelNotInFormat {
+                spec: "<EPOCH>".to_string()
+            }),
+            version.next(SemLevel::Epoch)
+        );
+
+        let date = Date::explicit(2024, 10, 1).unwrap();
+        let format = CalSem::new_format("<EPOCH>!<YYYY>.<MM>.<PATCH>").unwrap();
+        let version = Version::parse("1!2024.9.3", &format).unwrap();
+        assert_eq!(
+            "1!2024.10.0",
+            version.next(date, CalSemLevel::Patch).unwrap().to_string()
+        );
+        assert_eq!(
+            "2!2024.10.0",
+            version.next(date, CalSemLevel::Epoch).unwrap().to_string()
+        );
+        let same_date = Date::explicit(2024, 9, 3).unwrap();
+        assert_eq!(
+            "2!2024.9.0",
+            version
+                .next(same_date, CalSemLevel::Epoch)
+                .unwrap()
+                .to_string()
+        );
+
+        let format = Cal::new_format("<EPOCH>!<YYYY>.<MM>").unwrap();
+        let version = Version::parse("1!2024.9", &format).unwrap();
+        assert_eq!("1!2024.10", version.next(date).unwrap().to_string());
+        assert_eq!("2!2024.10", version.next_epoch(date).unwrap().to_string());
+        // incrementing the epoch counts as a change, even on the same date or an earlier one
+        let earlier_date = Date::explicit(2023, 1, 1).unwrap();
+        assert_eq!(
+            "2!2024.9",
+            version.next_epoch(same_date).unwrap().to_string()
+        );
+        assert_eq!(
+            "2!2023.1",
+            version.next_epoch(earlier_date).unwrap().to_string()
+        );
+
+        let format = Cal::new_format("<YYYY>.<MM>").unwrap();
+        let version = Version::parse("2024.9", &format).unwrap();
+        assert_eq!(Err(NextError::EpochNotInFormat), version.next_epoch(date));
     }
 
     #[test]
-    fn test_sem_next() {
+    fn test_sem_build_next() {
         let args = [
-            ("<MAJOR>.<MINOR>.<PATCH>", "1.2.3", SemLevel::Major, "2.0.0"),
-            ("<MAJOR>.<MINOR>.<PATCH>", "1.2.3", SemLevel::Minor, "1.3.0"),
-            ("<MAJOR>.<MINOR>.<PATCH>", "1.2.3", SemLevel::Patch, "1.2.4"),
-            ("<MAJOR>.<MINOR>", "1.2", SemLevel::Major, "2.0"),
-            ("<MAJOR>.<MINOR>", "1.2", SemLevel::Minor, "1.3"),
-            ("<MAJOR>", "1", SemLevel::Major, "2"),
+            (
+                "<MAJOR>.<MINOR>.<PATCH>.<BUILD>",
+                "1.2.3.41",
+                SemLevel::Major,
+                "2.0.0.42",
+            ),
+            (
+                "<MAJOR>.<MINOR>.<PATCH>.<BUILD>",
+                "1.2.3.41",
+                SemLevel::Patch,
+                "1.2.4.42",
+            ),
+            (
+                "<MAJOR>.<MINOR>.<PATCH><PRE>.<BUILD>",
+                "1.2.3-rc.0.41",
+                SemLevel::PreRelease,
+                "1.2.3-rc.1.42",
+            ),
+            (
+                "<MAJOR>.<MINOR>.<PATCH><PRE>.<BUILD>",
+                "1.2.3-rc.0.41",
+                SemLevel::FinalRelease,
+                "1.2.3.42",
+            ),
+            (
+                "<MAJOR>.<MINOR>.<PATCH>+<BUILD><META>",
+                "1.2.3+41+ci",
+                SemLevel::Minor,
+                "1.3.0+42",
+            ),
         ];
 
         for (format_str, version_str, level, expected_str) in args {
@@ -959,107 +4010,332 @@ mod tests {
     }
 
     #[test]
-    fn test_cal_next() {
+    fn test_optional_parse() {
         let args = [
+            ("<MAJOR>.<MINOR>[.<PATCH>]", "2.1", true),
+            ("<MAJOR>.<MINOR>[.<PATCH>]", "2.1.3", true),
+            ("<MAJOR>.<MINOR>[.<PATCH>]", "2.1.0", true),
+            ("<MAJOR>.<MINOR>[.<PATCH>]", "2.1.", false),
+            ("<MAJOR>.<MINOR>[.<PATCH>]", "2", false),
+            ("<MAJOR>[.<MINOR>][.<PATCH>]", "2", true),
+            ("<MAJOR>[.<MINOR>][.<PATCH>]", "2.1.3", true),
+            ("<MAJOR>[.<MINOR>].<PATCH>", "2.3", true),
+            ("[[<MAJOR>]", "[2]", true),
+            ("[[<MAJOR>]]", "[2]", true),
+            ("<MAJOR>[.<MINOR>]]]", "2.1]", true),
+            ("<MAJOR>[.<MINOR>]]]", "2", true),
+            ("<MAJOR>[.<MINOR>]]]", "2.1", false),
+        ];
+
+        for (format_str, version_str, passes) in args {
+            let format = Sem::new_format(format_str).unwrap();
+            let version = Version::parse(version_str, &format);
+            if passes {
+                // absent segments stay absent, and present ones stay present
+                assert_eq!(version_str, version.unwrap().to_string());
+            } else {
+                assert!(matches!(
+                    version,
+                    Err(VersionError::VersionFormatMismatch { .. })
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn test_optional_next() {
+        let args = [
+            // segments are rendered when they have a non-zero value...
+            ("<MAJOR>.<MINOR>[.<PATCH>]", "2.1", SemLevel::Patch, "2.1.1"),
             (
-                "<YYYY>.<0M>.<0D>",
-                "2023.12.04",
-                Date::explicit(2024, 1, 1),
-                "2024.01.01",
-            ),
-            ("<YYYY>", "2023", Date::explicit(2024, 1, 1), "2024"),
-            (
-                "<YYYY>.<0W>",
-                "2023.01",
-                Date::explicit(2024, 1, 1),
-                "2024.00",
+                "<MAJOR>.<MINOR>[.<PATCH>]",
+                "2.1.3",
+                SemLevel::Patch,
+                "2.1.4",
             ),
+            // ...and omitted when they're zero
+            ("<MAJOR>.<MINOR>[.<PATCH>]", "2.1.3", SemLevel::Minor, "2.2"),
+            ("<MAJOR>.<MINOR>[.<PATCH>]", "2.1.0", SemLevel::Major, "3.0"),
+            // ...unless a later segment is rendered
+            ("<MAJOR>[.<MINOR>][.<PATCH>]", "2", SemLevel::Patch, "2.0.1"),
             (
-                "<YYYY>.<WW>",
-                "2023.1",
-                Date::explicit(2024, 1, 1),
-                "2024.0",
+                "<MAJOR>[.<MINOR>][.<PATCH>]",
+                "2.0.1",
+                SemLevel::Minor,
+                "2.1",
             ),
+            ("<MAJOR>[.<MINOR>][.<PATCH>]", "2.1", SemLevel::Major, "3"),
+            ("<MAJOR>[.<MINOR:2>]", "2", SemLevel::Minor, "2.01"),
         ];
 
-        for (format_str, version_str, date, expected_str) in args {
-            let format = Cal::new_format(format_str).unwrap();
+        for (format_str, version_str, level, expected_str) in args {
+            let format = Sem::new_format(format_str).unwrap();
             let version = Version::parse(version_str, &format).unwrap();
-            let next = version.next(date.unwrap()).unwrap();
+            let next = version.next(level).unwrap();
             assert_eq!(expected_str, next.to_string());
         }
+
+        let format = Cal::new_format("<YYYY>.<0M>[.<BUILD>]").unwrap();
+        let version = Version::parse("2024.09", &format).unwrap();
+        let next = version.next(Date::explicit(2024, 9, 2).unwrap()).unwrap();
+        assert_eq!("2024.09.1", next.to_string());
     }
 
     #[test]
-    fn test_calsem_next() {
+    fn test_optional_ordering() {
+        let format = Sem::new_format("<MAJOR>.<MINOR>[.<PATCH>]").unwrap();
+        let v2_1 = Version::parse("2.1", &format).unwrap();
+        let v2_1_0 = Version::parse("2.1.0", &format).unwrap();
+        let v2_1_3 = Version::parse("2.1.3", &format).unwrap();
+        let v2_2 = Version::parse("2.2", &format).unwrap();
+
+        // an absent segment is treated as zero
+        assert_eq!(v2_1, v2_1_0);
+        assert_eq!(Some(Ordering::Equal), v2_1.partial_cmp(&v2_1_0));
+        assert!(v2_1 < v2_1_3);
+        assert!(v2_1_3 < v2_2);
+    }
+
+    #[test]
+    fn test_total_ordering() {
+        use std::collections::{BTreeSet, HashSet};
+
+        let format = Sem::new_format("<MAJOR>.<MINOR>[.<PATCH>]<PRE><META>").unwrap();
+        let parse = |version_str| Version::parse(version_str, &format).unwrap();
+        let version_strs = [
+            "2.1.3",
+            "2.1",
+            "2.1.0+ci",
+            "2.1.0-rc.1",
+            "2.10",
+            "2.2",
+            "2.1.0",
+            "2.1.0-alpha.2",
+        ];
+        let versions = version_strs.map(parse);
+
+        let mut sorted = versions.iter().collect::<Vec<_>>();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        let sorted_strs = sorted.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                "2.1.0-alpha.2",
+                "2.1.0-rc.1",
+                "2.1",
+                "2.1.0+ci",
+                "2.1.0",
+                "2.1.3",
+                "2.2",
+                "2.10"
+            ],
+            sorted_strs
+        );
+        assert_eq!(Some(&versions[4]), Version::max(&versions));
+        assert_eq!(Some(&versions[7]), Version::min(&versions));
+        assert_eq!(None, Version::max(Vec::<Version<Sem>>::new()));
+
+        // absent segments are zero and metadata is ignored, so 2.1, 2.1.0+ci and 2.1.0 are equal
+        let btree_set = version_strs
+            .map(|version_str| OrdVersion(parse(version_str)))
+            .into_iter()
+            .collect::<BTreeSet<_>>();
+        let hash_set = version_strs
+            .map(|version_str| OrdVersion(parse(version_str)))
+            .into_iter()
+            .collect::<HashSet<_>>();
+        assert_eq!(6, btree_set.len());
+        assert_eq!(6, hash_set.len());
+        assert_eq!(
+            btree_set.iter().collect::<HashSet<_>>(),
+            hash_set.iter().collect::<HashSet<_>>()
+        );
+
+        // versions of different formats have no partial ordering, but are still totally ordered
+        let other_format = Sem::new_format("v<MAJOR>").unwrap();
+        let other = Version::parse("v1", &other_format).unwrap();
+        assert_eq!(None, versions[0].partial_cmp(&other));
+        let ordering = versions[0].total_cmp(&other);
+        assert!(ordering.is_ne());
+        assert_eq!(ordering.reverse(), other.total_cmp(&versions[0]));
+    }
+
+    #[test]
+    fn test_next_with() {
+        let format = Sem::new_format("<MAJOR>.<MINOR>.<PATCH>+<BUILD><META>").unwrap();
+        let version = Version::parse("1.2.3+4+ci", &format).unwrap();
+
+        let next = version
+            .next_with(|value, spec| Ok(if spec.is_build() { value + 1 } else { value }))
+            .unwrap();
+        assert_eq!("1.2.3+5", next.to_string());
+
+        let custom_error = || NextError::Custom {
+            message: "no".to_string(),
+        };
+        assert_eq!(
+            Err(custom_error()),
+            version.next_with(|_, _| Err(custom_error()))
+        );
+    }
+
+    #[test]
+    fn test_version_error_from_next_error() {
+        assert_eq!(
+            VersionError::NegativeYearValue { year: -1 },
+            VersionError::from(NextError::NegativeYearValue { year: -1 })
+        );
+        assert_eq!(
+            VersionError::Next(NextError::NoCalendarChange),
+            VersionError::from(NextError::NoCalendarChange)
+        );
+    }
+
+    #[test]
+    fn test_bump() {
+        use crate::specifier::{
+            CALSEM_MINOR, CALSEM_PATCH, CALSEM_YEAR_FULL, SEM_BUILD, SEM_MAJOR, SEM_MINOR,
+            SEM_PATCH, SEM_PRE_RELEASE, SEM_REVISION,
+        };
+
+        let format = Sem::new_format("<MAJOR>.<MINOR>.<PATCH><PRE>+<BUILD><META>").unwrap();
+        let version = Version::parse("1.2.3-rc.1+4+ci", &format).unwrap();
         let args = [
-            (
-                "<YYYY>.<0M>.<0D>.<PATCH>",
-                "2023.12.04.123",
-                Date::explicit(2024, 1, 1),
-                CalSemLevel::Patch,
-                "2024.01.01.0",
-            ),
-            (
-                "<YYYY>.<0M>.<0D>.<PATCH>",
-                "2023.12.04.123",
-                Date::explicit(2023, 12, 4),
-                CalSemLevel::Patch,
-                "2023.12.04.124",
-            ),
-            (
-                "<YYYY>.<0M>.<DD>.<MINOR>.<PATCH>",
-                "2023.12.4.5.123",
-                Date::explicit(2024, 1, 1),
-                CalSemLevel::Minor,
-                "2024.01.1.0.0",
-            ),
-            (
-                "<YYYY>.<0M>.<DD>.<MINOR>.<PATCH>",
-                "2023.12.4.5.123",
-                Date::explicit(2023, 12, 4),
-                CalSemLevel::Minor,
-                "2023.12.4.6.0",
-            ),
-            (
-                "<YYYY>.<0M>.<DD>.<MINOR>.<PATCH>",
-                "2023.12.4.5.123",
-                Date::explicit(2023, 12, 4),
-                CalSemLevel::Patch,
-                "2023.12.4.5.124",
-            ),
+            (&SEM_MAJOR, "2.0.0+5"),
+            (&SEM_MINOR, "1.3.0+5"),
+            (&SEM_PATCH, "1.2.4+5"),
+            (&SEM_BUILD, "1.2.3+5"),
         ];
+        for (level, expected) in args {
+            assert_eq!(expected, version.bump(level).unwrap().to_string());
+        }
+        assert_eq!(
+            Err(NextError::SpecifierHasNoValue {
+                spec: "<PRE>".to_string()
+            }),
+            version.bump(&SEM_PRE_RELEASE)
+        );
+        assert_eq!(
+            Err(NextError::SemLevelNotInFormat {
+                spec: "<REVISION>".to_string()
+            }),
+            version.bump(&SEM_REVISION)
+        );
 
-        for (format_str, version_str, date, level, expected_str) in args {
-            let format = CalSem::new_format(format_str).unwrap();
-            let version = Version::parse(version_str, &format).unwrap();
-            let next = version.next(date.unwrap(), level).unwrap();
-            assert_eq!(next.to_string(), expected_str);
+        let version = CalSem::new_version("<YYYY>.<MINOR>.<PATCH>", "2024.2.3").unwrap();
+        let args = [
+            (&CALSEM_YEAR_FULL, "2025.2.3"),
+            (&CALSEM_MINOR, "2024.3.0"),
+            (&CALSEM_PATCH, "2024.2.4"),
+        ];
+        for (level, expected) in args {
+            assert_eq!(expected, version.bump(level).unwrap().to_string());
         }
     }
 
     #[test]
-    fn test_sem_not_in_format() {
+    fn test_fixed_width_parse() {
         let args = [
-            ("<MAJOR>.<MINOR>", "1.2", SemLevel::Patch),
-            ("<MAJOR>", "1", SemLevel::Minor),
-            ("<MAJOR>", "1", SemLevel::Patch),
+            ("<MAJOR>.<MINOR:2>.<PATCH:3>", "1.04.007", true),
+            ("<MAJOR>.<MINOR:2>.<PATCH:3>", "1.00.000", true),
+            ("<MAJOR>.<MINOR:2>.<PATCH:3>", "1.4.007", false), // too narrow
+            ("<MAJOR>.<MINOR:2>.<PATCH:3>", "1.04.0007", false), // too wide
+            ("<MAJOR>.<MINOR:2>.<PATCH:3>", "1.04.07", false),
+            ("<MAJOR:2><MINOR:2>", "0104", true), // no separator needed
         ];
 
-        for (format_str, version_str, level) in args {
+        for (format_str, version_str, passes) in args {
             let format = Sem::new_format(format_str).unwrap();
-            let version = Version::parse(version_str, &format).unwrap();
-            let next = version.next(level);
-            assert!(matches!(next, Err(NextError::SemLevelNotInFormat { .. })));
+            let version = Version::parse(version_str, &format);
+            if passes {
+                assert_eq!(version_str, version.unwrap().to_string());
+            } else {
+                assert!(matches!(
+                    version,
+                    Err(VersionError::VersionFormatMismatch { .. })
+                ));
+            }
         }
+
+        // calendar values still can't be zero
+        let format = Cal::new_format("<YYYY>.<MM:2>").unwrap();
+        assert!(Version::parse("2024.03", &format).is_ok());
+        assert!(Version::parse("2024.00", &format).is_err());
     }
 
     #[test]
-    fn test_calsem_not_in_format() {
-        let format = CalSem::new_format("<YYYY>.<0M>.<0D>.<PATCH>").unwrap();
-        let version = Version::parse("2023.12.04.2", &format).unwrap();
-        let next = version.next(Date::explicit(2024, 1, 1).unwrap(), CalSemLevel::Minor);
-        assert!(matches!(next, Err(NextError::SemLevelNotInFormat { .. })));
+    fn test_fixed_width_next() {
+        let format = Sem::new_format("<MAJOR>.<MINOR:2>.<PATCH:3>").unwrap();
+        let version = Version::parse("1.04.007", &format).unwrap();
+        assert_eq!(
+            "1.04.008",
+            version.next(SemLevel::Patch).unwrap().to_string()
+        );
+        assert_eq!(
+            "1.05.000",
+            version.next(SemLevel::Minor).unwrap().to_string()
+        );
+        assert_eq!(
+            "2.00.000",
+            version.next(SemLevel::Major).unwrap().to_string()
+        );
+
+        let version = Version::parse("1.99.007", &format).unwrap();
+        assert_eq!(
+            Err(NextError::ValueOverflowsWidth {
+                spec: "<MINOR>".to_string(),
+                value: 100,
+                width: 2,
+            }),
+            version.next(SemLevel::Minor)
+        );
+
+        let format = Cal::new_format("<YY:2>.<MM:2>.<BUILD:3>").unwrap();
+        let version = Version::parse("24.09.041", &format).unwrap();
+        assert_eq!(
+            "24.10.042",
+            version
+                .next(Date::explicit(2024, 10, 1).unwrap())
+                .unwrap()
+                .to_string()
+        );
+        let version = Version::parse("24.09.999", &format).unwrap();
+        assert!(matches!(
+            version.next(Date::explicit(2024, 10, 1).unwrap()),
+            Err(NextError::ValueOverflowsWidth { width: 3, .. })
+        ));
+    }
+
+    #[test]
+    fn test_cal_build_next() {
+        let format = Cal::new_format("<YYYY>.<0M>.<0D>.<BUILD>").unwrap();
+        let version = Version::parse("2023.12.04.41", &format).unwrap();
+
+        // the same date no longer errors with `NoCalendarChange`
+        let next = version.next(Date::explicit(2023, 12, 4).unwrap()).unwrap();
+        assert_eq!("2023.12.04.42", next.to_string());
+        assert!(version < next);
+
+        // and a new date does not reset the build number
+        let next = next.next(Date::explicit(2024, 1, 1).unwrap()).unwrap();
+        assert_eq!("2024.01.01.43", next.to_string());
+
+        // but a date before is still an error
+        let next = next.next(Date::explicit(2023, 1, 1).unwrap());
+        assert_eq!(Err(NextError::NewDateIsBefore), next);
+    }
+
+    #[test]
+    fn test_calsem_build_next() {
+        let format = CalSem::new_format("<YYYY>.<0M>.<PATCH>-<BUILD>").unwrap();
+        let version = Version::parse("2023.12.3-41", &format).unwrap();
+
+        // a date change resets the patch, but not the build number
+        let date = Date::explicit(2024, 1, 1).unwrap();
+        let next = version.next(date, CalSemLevel::Patch).unwrap();
+        assert_eq!("2024.01.0-42", next.to_string());
+
+        let next = next.next(date, CalSemLevel::Patch).unwrap();
+        assert_eq!("2024.01.1-43", next.to_string());
     }
 
     #[test]
@@ -1128,6 +4404,109 @@ mod tests {
         assert!(cmp.is_none());
     }
 
+    #[test]
+    fn test_value_out_of_range_parse() {
+        let max = SpecValue::MAX.to_string();
+        let too_great = (u64::from(SpecValue::MAX) + 1).to_string();
+
+        let args = [
+            ("<MAJOR>.<MINOR>", format!("1.{max}"), true),
+            ("<MAJOR>.<MINOR>", format!("1.{too_great}"), false),
+            ("<MAJOR>.<MINOR:10>", format!("1.{too_great}"), false),
+            ("<MAJOR><PRE>", format!("1-rc.{max}"), true),
+            ("<MAJOR><PRE>", format!("1-rc.{too_great}"), false),
+        ];
+
+        for (format_str, version_str, ok) in args {
+            let format = Sem::new_format(format_str).unwrap();
+            let actual = Version::parse(&version_str, &format);
+            if ok {
+                assert!(actual.is_ok());
+            } else {
+                assert_eq!(
+                    Err(VersionError::ValueOutOfRange {
+                        version_string: version_str.clone()
+                    }),
+                    actual
+                );
+            }
+        }
+
+        // adjacent values that together are too great can still be split
+        let format = Sem::new_format("<MAJOR><MINOR>").unwrap();
+        assert!(Version::parse(&too_great, &format).is_ok());
+
+        // quarters are at most 4, and days of the year at most 366
+        let args = [
+            ("<YYYY>.Q<Q>", "2024.Q4", true),
+            ("<YYYY>.Q<Q>", "2024.Q5", false),
+            ("<YY>.<DOY>", "24.366", true),
+            ("<YY>.<DOY>", "24.999", false),
+            ("<YY>.<0DOY>", "24.367", false),
+            ("<YY>.<DOY:4>", "24.0367", false),
+        ];
+        for (format_str, version_str, ok) in args {
+            let format = Cal::new_format(format_str).unwrap();
+            let actual = Version::parse(version_str, &format);
+            if ok {
+                assert!(actual.is_ok());
+            } else {
+                assert_eq!(
+                    Err(VersionError::ValueOutOfRange {
+                        version_string: version_str.to_string()
+                    }),
+                    actual
+                );
+            }
+        }
+        let format = CalSem::new_format("<YYYY>.<DOY>.<PATCH>").unwrap();
+        assert_eq!(
+            Err(VersionError::ValueOutOfRange {
+                version_string: "2024.400.0".to_string()
+            }),
+            Version::parse("2024.400.0", &format)
+        );
+    }
+
+    #[test]
+    fn test_value_overflow_next() {
+        let max = SpecValue::MAX;
+        let overflow = |spec: &str| NextError::ValueOverflow {
+            spec: spec.to_string(),
+            value: max,
+        };
+
+        let format = Sem::new_format("<MAJOR>.<MINOR>+<BUILD>").unwrap();
+        let version_str = format!("{max}.{max}+0");
+        let version = Version::parse(&version_str, &format).unwrap();
+        assert_eq!(Err(overflow("<MAJOR>")), version.next(SemLevel::Major));
+        assert_eq!(Err(overflow("<MINOR>")), version.next(SemLevel::Minor));
+
+        let version_str = format!("1.2+{max}");
+        let version = Version::parse(&version_str, &format).unwrap();
+        assert_eq!(Err(overflow("<BUILD>")), version.next(SemLevel::Minor));
+
+        let format = Cal::new_format("<YYYY>.<BUILD>").unwrap();
+        let version_str = format!("2024.{max}");
+        let version = Version::parse(&version_str, &format).unwrap();
+        let date = Date::explicit(2024, 1, 1).unwrap();
+        assert_eq!(Err(overflow("<BUILD>")), version.next(date));
+
+        let format = CalSem::new_format("<YYYY>.<PATCH>").unwrap();
+        let version_str = format!("2024.{max}");
+        let version = Version::parse(&version_str, &format).unwrap();
+        assert_eq!(
+            Err(overflow("<PATCH>")),
+            version.next(date, CalSemLevel::Patch)
+        );
+        // a new date resets the value instead
+        let date = Date::explicit(2025, 1, 1).unwrap();
+        assert_eq!(
+            "2025.0",
+            version.next(date, CalSemLevel::Patch).unwrap().to_string()
+        );
+    }
+
     #[test]
     fn test_sem_next_greater() {
         let format = Sem::new_format("<MAJOR>.<MINOR>.<PATCH>").unwrap();
@@ -1199,6 +4578,267 @@ mod tests {
         assert_eq!("2022.01.01.124", next.to_string());
     }
 
+    #[test]
+    fn test_pre_release_parse() {
+        let args = [
+            ("<MAJOR>.<MINOR><PRE>", "1.2", true),
+            ("<MAJOR>.<MINOR><PRE>", "1.2-alpha.0", true),
+            ("<MAJOR>.<MINOR><PRE>", "1.2-rc.10", true),
+            ("<MAJOR>.<MINOR><PRE>", "1.2-", false), // no label
+            ("<MAJOR>.<MINOR><PRE>", "1.2-rc", false), // no number
+            ("<MAJOR>.<MINOR><PRE>", "1.2-rc.01", false), // zero-padding disallowed
+            ("<MAJOR>.<MINOR><PRE>", "1.2-gamma.1", false), // unknown label
+            ("<MAJOR>.<MINOR><PRE:dev>", "1.2-dev.1", true), // custom label
+            ("<MAJOR>.<MINOR><PRE:dev>", "1.2-rc.1", false), // default label not accepted
+            ("<MAJOR>.<MINOR><PRE>+build", "1.2-beta.3+build", true),
+            ("<MAJOR>.<MINOR><PRE>+build", "1.2+build", true),
+        ];
+
+        for (format_str, version_str, passes) in args {
+            let format = Sem::new_format(format_str).unwrap();
+            let version = Version::parse(version_str, &format);
+            if passes {
+                assert_eq!(version_str, version.unwrap().to_string());
+            } else {
+                assert!(matches!(
+                    version,
+                    Err(VersionError::VersionFormatMismatch { .. })
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn test_sem_pre_release_next() {
+        let format_str = "<MAJOR>.<MINOR>.<PATCH><PRE>";
+        let args = [
+            ("1.2.3", SemLevel::Patch, Ok("1.2.4")),
+            ("1.2.3-rc.1", SemLevel::Major, Ok("2.0.0")),
+            ("1.2.3-alpha.0", SemLevel::PreRelease, Ok("1.2.3-alpha.1")),
+            (
+                "1.2.3-alpha.4",
+                SemLevel::PreReleaseLabel,
+                Ok("1.2.3-beta.0"),
+            ),
+            ("1.2.3-rc.1", SemLevel::FinalRelease, Ok("1.2.3")),
+            ("1.2.3", SemLevel::PreRelease, Err(NextError::NotPreRelease)),
+            (
+                "1.2.3",
+                SemLevel::FinalRelease,
+                Err(NextError::NotPreRelease),
+            ),
+            (
+                "1.2.3-rc.1",
+                SemLevel::PreReleaseLabel,
+                Err(NextError::NoNextPreReleaseLabel {
+                    label: "rc".to_string(),
+                }),
+            ),
+        ];
+
+        for (version_str, level, expected) in args {
+            let format = Sem::new_format(format_str).unwrap();
+            let version = Version::parse(version_str, &format).unwrap();
+            let next = version.next(level);
+            assert_eq!(expected.map(str::to_string), next.map(|v| v.to_string()));
+        }
+    }
+
+    #[test]
+    fn test_sem_next_pre_release() {
+        let format = Sem::new_format("<MAJOR>.<MINOR>.<PATCH><PRE>").unwrap();
+        let args = [
+            ("1.2.3", SemLevel::Patch, Ok("1.2.4-alpha.0")),
+            ("1.2.3-rc.1", SemLevel::Major, Ok("2.0.0-alpha.0")),
+            (
+                "1.2.3-rc.1",
+                SemLevel::PreRelease,
+                Err(NextError::PreReleaseLevel),
+            ),
+            (
+                "1.2.3-rc.1",
+                SemLevel::FinalRelease,
+                Err(NextError::PreReleaseLevel),
+            ),
+        ];
+
+        for (version_str, level, expected) in args {
+            let version = Version::parse(version_str, &format).unwrap();
+            let next = version.next_pre_release(level);
+            assert_eq!(expected.map(str::to_string), next.map(|v| v.to_string()));
+        }
+
+        let format = Sem::new_format("<MAJOR>.<MINOR>.<PATCH>").unwrap();
+        let version = Version::parse("1.2.3", &format).unwrap();
+        assert_eq!(
+            Err(NextError::SemLevelNotInFormat {
+                spec: "<PRE>".to_string()
+            }),
+            version.next_pre_release(SemLevel::Patch)
+        );
+    }
+
+    #[test]
+    fn test_sem_pre_release_not_in_format() {
+        let format = Sem::new_format("<MAJOR>.<MINOR>.<PATCH>").unwrap();
+        let version = Version::parse("1.2.3", &format).unwrap();
+        let next = version.next(SemLevel::PreRelease);
+        assert!(matches!(next, Err(NextError::SemLevelNotInFormat { .. })));
+    }
+
+    #[test]
+    fn test_calsem_pre_release_next() {
+        let format_str = "<YYYY>.<0M>.<PATCH><PRE>";
+        let args = [
+            (
+                "2024.09.3-rc.1",
+                Date::explicit(2024, 10, 1),
+                CalSemLevel::PreRelease,
+                "2024.10.0-alpha.0",
+            ),
+            (
+                "2024.10.3-rc.1",
+                Date::explicit(2024, 10, 1),
+                CalSemLevel::PreRelease,
+                "2024.10.3-rc.2",
+            ),
+            (
+                "2024.10.3-rc.1",
+                Date::explicit(2024, 10, 1),
+                CalSemLevel::FinalRelease,
+                "2024.10.3",
+            ),
+            (
+                "2024.10.3",
+                Date::explicit(2024, 10, 1),
+                CalSemLevel::Patch,
+                "2024.10.4",
+            ),
+            (
+                "2024.09.3-rc.1",
+                Date::explicit(2024, 10, 1),
+                CalSemLevel::FinalRelease,
+                "2024.10.0",
+            ),
+        ];
+
+        for (version_str, date, level, expected_str) in args {
+            let format = CalSem::new_format(format_str).unwrap();
+            let version = Version::parse(version_str, &format).unwrap();
+            let next = version.next(date.unwrap(), level).unwrap();
+            assert_eq!(expected_str, next.to_string());
+        }
+
+        let format = CalSem::new_format(format_str).unwrap();
+        let version = Version::parse("2024.10.3", &format).unwrap();
+        let date = Date::explicit(2024, 10, 1).unwrap();
+        let next = version.next_pre_release(date, CalSemLevel::Patch).unwrap();
+        assert_eq!("2024.10.4-alpha.0", next.to_string());
+        let date = Date::explicit(2024, 11, 1).unwrap();
+        let next = version.next_pre_release(date, CalSemLevel::Patch).unwrap();
+        assert_eq!("2024.11.0-alpha.0", next.to_string());
+    }
+
+    #[test]
+    fn test_pre_release_ordering() {
+        let format = Sem::new_format("<MAJOR>.<MINOR>.<PATCH><PRE>").unwrap();
+        let ascending = [
+            "1.2.3-alpha.0",
+            "1.2.3-alpha.1",
+            "1.2.3-alpha.10",
+            "1.2.3-beta.0",
+            "1.2.3-rc.0",
+            "1.2.3",
+            "1.2.4-alpha.0",
+        ];
+
+        for pair in ascending.windows(2) {
+            let lesser = Version::parse(pair[0], &format).unwrap();
+            let greater = Version::parse(pair[1], &format).unwrap();
+            assert!(lesser < greater);
+        }
+    }
+
+    #[test]
+    fn test_metadata_parse() {
+        let args = [
+            ("<MAJOR>.<MINOR><META>", "1.2", true),
+            ("<MAJOR>.<MINOR><META>", "1.2+ci.42", true),
+            ("<MAJOR>.<MINOR><META>", "1.2+exp.sha-5114f85", true),
+            ("<MAJOR>.<MINOR><META>", "1.2+", false), // empty metadata
+            ("<MAJOR>.<MINOR><META>", "1.2+ci_42", false), // bad character
+            ("<MAJOR>.<MINOR><PRE><META>", "1.2-rc.1+ci.42", true),
+            ("<YYYY>.<0M><META>", "2024.10+20241018", true),
+            ("<YYYY>.<0M>.<PATCH><META>", "2024.10.1+ci.42", true),
+        ];
+
+        for (format_str, version_str, passes) in args {
+            // try the format in all schemes it may be valid in
+            let version = Sem::new_version(format_str, version_str)
+                .map(|v| v.to_string())
+                .or_else(|_| Cal::new_version(format_str, version_str).map(|v| v.to_string()))
+                .or_else(|_| CalSem::new_version(format_str, version_str).map(|v| v.to_string()));
+            if passes {
+                assert_eq!(Ok(version_str.to_string()), version);
+            } else {
+                assert!(version.is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn test_metadata_ignored_in_comparisons() {
+        let format = Sem::new_format("<MAJOR>.<MINOR>.<PATCH><META>").unwrap();
+        let version1 = Version::parse("1.2.3+ci.41", &format).unwrap();
+        let version2 = Version::parse("1.2.3+ci.42", &format).unwrap();
+        let version3 = Version::parse("1.2.3", &format).unwrap();
+        let version4 = Version::parse("1.2.4+ci.40", &format).unwrap();
+
+        assert_eq!(Some(Ordering::Equal), version1.partial_cmp(&version2));
+        assert_eq!(version1, version2);
+        assert_eq!(version1, version3);
+        assert!(version2 < version4);
+    }
+
+    #[test]
+    fn test_with_metadata() {
+        let format = Sem::new_format("<MAJOR>.<MINOR>.<PATCH><META>").unwrap();
+        let version = Version::parse("1.2.3+ci.41", &format).unwrap();
+
+        let next = version.next(SemLevel::Patch).unwrap();
+        assert_eq!("1.2.4", next.to_string());
+
+        let args = [
+            (Some("ci.42"), Ok("1.2.4+ci.42")),
+            (None, Ok("1.2.4")),
+            (
+                Some(""),
+                Err(NextError::InvalidMetadata {
+                    metadata: String::new(),
+                }),
+            ),
+            (
+                Some("ci+42"),
+                Err(NextError::InvalidMetadata {
+                    metadata: "ci+42".to_string(),
+                }),
+            ),
+        ];
+
+        for (metadata, expected) in args {
+            let actual = next.with_metadata(metadata).map(|v| v.to_string());
+            assert_eq!(expected.map(str::to_string), actual);
+        }
+    }
+
+    #[test]
+    fn test_with_metadata_not_in_format() {
+        let format = Sem::new_format("<MAJOR>.<MINOR>.<PATCH>").unwrap();
+        let version = Version::parse("1.2.3", &format).unwrap();
+        let actual = version.with_metadata(Some("ci.42"));
+        assert_eq!(Err(NextError::MetadataNotInFormat), actual);
+    }
+
     #[test]
     fn test_non_greedy_parse() {
         let format_str = "<MAJOR><MINOR><PATCH>";